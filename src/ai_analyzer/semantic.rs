@@ -68,7 +68,10 @@ fn infer_expected_type(violation_type: &ViolationType) -> Option<String> {
         | ViolationType::MissingDocConfig
         | ViolationType::HardcodedVersion
         | ViolationType::MissingChangelogEntry
-        | ViolationType::InvalidChangelogFormat => None,
+        | ViolationType::InvalidChangelogFormat
+        | ViolationType::InvalidSuppression
+        | ViolationType::CompilerDiagnostic { .. }
+        | ViolationType::CustomRule { .. } => None,
     }
 }
 
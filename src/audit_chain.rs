@@ -0,0 +1,422 @@
+//! Hash-chained, append-only audit logs
+//!
+//! Every line of a chained log is a JSON object holding one entry and
+//! `prev_hash`, the SHA-256 of the line before it. A `<log>.head` file next
+//! to the log records how many entries it has and the hash of the last
+//! one, so removing entries from the end is detected as well as editing,
+//! inserting or removing them anywhere else.
+//!
+//! Entries written before logs were chained have no `prev_hash`. They are
+//! accepted at the start of a log and reported as legacy, since nothing
+//! vouches for them.
+
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+/// `prev_hash` of the first entry in a chain
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// An append-only log whose entries are chained by hash
+#[derive(Debug, Clone)]
+pub struct AuditChain {
+    path: PathBuf,
+}
+
+/// A log line: the entry plus the hash of the line before it
+#[derive(Serialize)]
+struct Chained<'a, T> {
+    prev_hash: &'a str,
+    #[serde(flatten)]
+    entry: &'a T,
+}
+
+/// Length and last hash of a chain, kept beside the log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct ChainHead {
+    entries: usize,
+    last_hash: String,
+}
+
+/// Outcome of verifying a chained log
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ChainReport {
+    /// Entries in the log
+    pub entries: usize,
+    /// Leading entries written before the log was chained
+    pub legacy: usize,
+    /// Evidence of modification or truncation, in log order
+    pub problems: Vec<ChainProblem>,
+}
+
+/// A way a chained log fails verification
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ChainProblem {
+    /// The line is not a JSON object
+    Malformed {
+        /// Line number in the log
+        line: usize,
+    },
+    /// An entry without `prev_hash` after chaining began
+    Unchained {
+        /// Line number in the log
+        line: usize,
+    },
+    /// `prev_hash` does not match the line before it
+    BrokenLink {
+        /// Line number in the log
+        line: usize,
+    },
+    /// The log has chained entries but its head file is gone
+    MissingHead,
+    /// The log has fewer entries than the head records
+    Truncated {
+        /// Entries recorded in the head
+        recorded: usize,
+        /// Entries found in the log
+        found: usize,
+    },
+    /// The last entry or the entry count differs from the head
+    HeadMismatch,
+}
+
+impl fmt::Display for ChainProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed { line } => write!(f, "line {} is not a valid entry", line),
+            Self::Unchained { line } => write!(f, "line {} has no previous-entry hash", line),
+            Self::BrokenLink { line } => write!(
+                f,
+                "line {} does not follow the entry before it; an entry was modified, \
+                 inserted or removed",
+                line
+            ),
+            Self::MissingHead => write!(f, "the log's head file is missing"),
+            Self::Truncated { recorded, found } => write!(
+                f,
+                "the log was truncated: {} entries recorded, {} found",
+                recorded, found
+            ),
+            Self::HeadMismatch => write!(
+                f,
+                "the last entry does not match the head; it was modified or entries were \
+                 appended by hand"
+            ),
+        }
+    }
+}
+
+impl ChainReport {
+    /// Whether the log verified without problems
+    pub fn is_intact(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+impl AuditChain {
+    /// A chained log stored at `path`
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Where the log is stored
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append `entry`, linked to the last entry of the log
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry cannot be serialized or the log or
+    /// its head cannot be read or written.
+    pub async fn append<T: Serialize>(&self, entry: &T) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+
+        // The head is the authority on where the chain ends, so an entry
+        // appended after tampering does not hide it
+        let head = match self.read_head().await? {
+            Some(head) => head,
+            None => {
+                let contents = self.read_log().await?;
+                let lines = entry_lines(&contents);
+                ChainHead {
+                    entries: lines.len(),
+                    last_hash: lines
+                        .last()
+                        .map_or_else(|| GENESIS_HASH.to_string(), |(_, l)| hash_line(l)),
+                }
+            }
+        };
+
+        let line = serde_json::to_string(&Chained {
+            prev_hash: &head.last_hash,
+            entry,
+        })
+        .map_err(|e| Error::parse(format!("Failed to serialize audit entry: {}", e)))?;
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|e| Error::io(format!("Failed to open {}: {}", self.path.display(), e)))?;
+        file.write_all(format!("{}\n", line).as_bytes())
+            .await
+            .map_err(|e| Error::io(format!("Failed to write {}: {}", self.path.display(), e)))?;
+
+        self.write_head(&ChainHead {
+            entries: head.entries + 1,
+            last_hash: hash_line(&line),
+        })
+        .await
+    }
+
+    /// Check every link of the chain and the head
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the log or its head cannot be read.
+    pub async fn verify(&self) -> Result<ChainReport> {
+        let contents = self.read_log().await?;
+        let head = self.read_head().await?;
+        Ok(verify_lines(&contents, head.as_ref()))
+    }
+
+    /// Verify the log and copy it to `destination` as JSON lines
+    ///
+    /// The copy keeps every `prev_hash`, so it can be verified again later.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the log does not verify, or it cannot be read or
+    /// the copy cannot be written.
+    pub async fn export(&self, destination: &Path) -> Result<ChainReport> {
+        let contents = self.read_log().await?;
+        let report = verify_lines(&contents, self.read_head().await?.as_ref());
+        if !report.is_intact() {
+            return Err(Error::validation(format!(
+                "{} failed verification; not exporting",
+                self.path.display()
+            )));
+        }
+
+        let mut export = String::new();
+        for (_, line) in entry_lines(&contents) {
+            export.push_str(line);
+            export.push('\n');
+        }
+        fs::write(destination, export)
+            .await
+            .map_err(|e| Error::io(format!("Failed to write {}: {}", destination.display(), e)))?;
+        Ok(report)
+    }
+
+    async fn read_log(&self) -> Result<String> {
+        match fs::read_to_string(&self.path).await {
+            Ok(contents) => Ok(contents),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
+            Err(e) => Err(Error::io(format!(
+                "Failed to read {}: {}",
+                self.path.display(),
+                e
+            ))),
+        }
+    }
+
+    async fn read_head(&self) -> Result<Option<ChainHead>> {
+        let path = self.head_path();
+        match fs::read_to_string(&path).await {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map(Some)
+                .map_err(|e| Error::parse(format!("Failed to parse {}: {}", path.display(), e))),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(Error::io(format!(
+                "Failed to read {}: {}",
+                path.display(),
+                e
+            ))),
+        }
+    }
+
+    async fn write_head(&self, head: &ChainHead) -> Result<()> {
+        let path = self.head_path();
+        let contents = serde_json::to_string_pretty(head)
+            .map_err(|e| Error::parse(format!("Failed to serialize audit head: {}", e)))?;
+        fs::write(&path, contents)
+            .await
+            .map_err(|e| Error::io(format!("Failed to write {}: {}", path.display(), e)))
+    }
+
+    fn head_path(&self) -> PathBuf {
+        let mut name = self.path.file_name().unwrap_or_default().to_os_string();
+        name.push(".head");
+        self.path.with_file_name(name)
+    }
+}
+
+/// Non-blank lines of a log with their 1-based line numbers
+fn entry_lines(contents: &str) -> Vec<(usize, &str)> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| (i + 1, line))
+        .collect()
+}
+
+/// Hex SHA-256 of one log line
+fn hash_line(line: &str) -> String {
+    format!("{:x}", Sha256::digest(line.as_bytes()))
+}
+
+fn verify_lines(contents: &str, head: Option<&ChainHead>) -> ChainReport {
+    let lines = entry_lines(contents);
+    let mut report = ChainReport {
+        entries: lines.len(),
+        ..ChainReport::default()
+    };
+
+    let mut chained = false;
+    let mut prev_hash = GENESIS_HASH.to_string();
+    for (number, line) in &lines {
+        let value: Option<serde_json::Value> = serde_json::from_str(line).ok();
+        match value.as_ref().and_then(|v| v.as_object()) {
+            None => report
+                .problems
+                .push(ChainProblem::Malformed { line: *number }),
+            Some(entry) => match entry.get("prev_hash").and_then(|h| h.as_str()) {
+                None if !chained => report.legacy += 1,
+                None => report
+                    .problems
+                    .push(ChainProblem::Unchained { line: *number }),
+                Some(hash) => {
+                    chained = true;
+                    if hash != prev_hash {
+                        report
+                            .problems
+                            .push(ChainProblem::BrokenLink { line: *number });
+                    }
+                }
+            },
+        }
+        prev_hash = hash_line(line);
+    }
+
+    match head {
+        None if chained => report.problems.push(ChainProblem::MissingHead),
+        None => {}
+        Some(head) if head.entries > lines.len() => report.problems.push(ChainProblem::Truncated {
+            recorded: head.entries,
+            found: lines.len(),
+        }),
+        Some(head) if head.entries != lines.len() || head.last_hash != prev_hash => {
+            report.problems.push(ChainProblem::HeadMismatch);
+        }
+        Some(_) => {}
+    }
+    report
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    async fn chain_of(dir: &TempDir, entries: usize) -> AuditChain {
+        let chain = AuditChain::new(dir.path().join("audit.log"));
+        for i in 0..entries {
+            chain.append(&json!({ "n": i })).await.unwrap();
+        }
+        chain
+    }
+
+    fn edit_log(chain: &AuditChain, edit: impl FnOnce(Vec<String>) -> Vec<String>) {
+        let contents = std::fs::read_to_string(chain.path()).unwrap();
+        let lines = edit(contents.lines().map(String::from).collect());
+        std::fs::write(chain.path(), lines.join("\n") + "\n").unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_intact_chain_verifies_and_exports() {
+        let dir = TempDir::new().unwrap();
+        let chain = chain_of(&dir, 3).await;
+
+        let report = chain.verify().await.unwrap();
+        assert!(report.is_intact());
+        assert_eq!(report.entries, 3);
+
+        let export = dir.path().join("export.jsonl");
+        chain.export(&export).await.unwrap();
+        let exported = std::fs::read_to_string(&export).unwrap();
+        assert_eq!(exported, std::fs::read_to_string(chain.path()).unwrap());
+        assert!(exported.starts_with(&format!("{{\"prev_hash\":\"{}\"", GENESIS_HASH)));
+    }
+
+    #[tokio::test]
+    async fn test_modified_entry_breaks_the_next_link() {
+        let dir = TempDir::new().unwrap();
+        let chain = chain_of(&dir, 3).await;
+        edit_log(&chain, |mut lines| {
+            lines[1] = lines[1].replace("\"n\":1", "\"n\":7");
+            lines
+        });
+
+        let report = chain.verify().await.unwrap();
+        assert_eq!(report.problems, vec![ChainProblem::BrokenLink { line: 3 }]);
+        assert!(chain.export(&dir.path().join("x")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_truncation_is_detected_even_after_a_later_append() {
+        let dir = TempDir::new().unwrap();
+        let chain = chain_of(&dir, 3).await;
+        edit_log(&chain, |lines| lines[..2].to_vec());
+        assert_eq!(
+            chain.verify().await.unwrap().problems,
+            vec![ChainProblem::Truncated {
+                recorded: 3,
+                found: 2
+            }]
+        );
+
+        chain.append(&json!({ "n": 3 })).await.unwrap();
+        assert_eq!(
+            chain.verify().await.unwrap().problems,
+            vec![
+                ChainProblem::BrokenLink { line: 3 },
+                ChainProblem::Truncated {
+                    recorded: 4,
+                    found: 3
+                }
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_legacy_entries_are_chained_onto() {
+        let dir = TempDir::new().unwrap();
+        let chain = AuditChain::new(dir.path().join("audit.log"));
+        std::fs::write(chain.path(), "{\"n\":0}\n{\"n\":1}\n").unwrap();
+        chain.append(&json!({ "n": 2 })).await.unwrap();
+
+        let report = chain.verify().await.unwrap();
+        assert!(report.is_intact());
+        assert_eq!((report.entries, report.legacy), (3, 2));
+
+        std::fs::remove_file(chain.head_path()).unwrap();
+        assert_eq!(
+            chain.verify().await.unwrap().problems,
+            vec![ChainProblem::MissingHead]
+        );
+    }
+}
@@ -106,12 +106,15 @@ pub async fn intercept_publish_command(project_path: &Path) -> Result<()> {
 
 /// Intercept dev commands (build, test, run, check) with tiered blocking:
 /// - Locked settings (edition/version) → ALWAYS block
-/// - Style violations → WARN only (unless `enforce_style` is true)
+/// - Rules set to `error` in the `[rules]` config table → block
+/// - Style violations and rules set to `warning` → WARN only
+/// - Rules set to `info` or `off` → silent
 ///
 /// # Errors
 ///
 /// Returns [`crate::Error::Validation`] if locked settings (edition, rust-version) are
-/// violated. Style violations produce warnings but do not return errors.
+/// violated, or a rule set to `error` is violated. Other style violations
+/// produce warnings but do not return errors.
 pub async fn intercept_dev_command(project_path: &Path) -> Result<()> {
     let interceptor = CargoInterceptor::new();
 
@@ -138,7 +141,8 @@ pub async fn intercept_dev_command(project_path: &Path) -> Result<()> {
 
     // Style violations — warn but don't block during dev (unless bypass disabled)
     if !interceptor.bypass_style {
-        let style_violations = validation::check_style_violations(project_path).await?;
+        let style = validation::check_style_violations(project_path).await?;
+        let style_violations = style.warnings;
         if !style_violations.is_empty() {
             eprintln!(
                 "\n⚠️  Ferrous Forge style warnings ({} violations):",
@@ -163,6 +167,20 @@ pub async fn intercept_dev_command(project_path: &Path) -> Result<()> {
             eprintln!("   (These will block 'cargo publish'. Fix before publishing.)");
             eprintln!("   (Set FERROUS_FORGE_BYPASS=true to suppress these warnings.)\n");
         }
+        if !style.blocking.is_empty() {
+            eprintln!("\n❌ FERROUS FORGE — Violations of rules set to error\n");
+            for v in &style.blocking {
+                eprintln!(
+                    "   {}: {}",
+                    v.location(),
+                    v.message.lines().next().unwrap_or("")
+                );
+            }
+            return Err(crate::Error::validation(format!(
+                "{} violations of rules set to 'error' in [rules] must be fixed before building",
+                style.blocking.len()
+            )));
+        }
     } else {
         tracing::info!(
             "FERROUS_FORGE_BYPASS — style warnings suppressed (locked settings still checked)"
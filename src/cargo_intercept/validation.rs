@@ -1,8 +1,7 @@
 //! Validation functions for cargo publish interception
 
-use crate::config::Config;
 use crate::safety::SafetyPipeline;
-use crate::validation::{RustValidator, Violation, ViolationType};
+use crate::validation::{RuleLevel, RustValidator, Violation, ViolationType};
 use crate::{Error, Result};
 use std::path::Path;
 use toml::Value;
@@ -33,13 +32,14 @@ pub async fn pre_publish_validation(project_path: &Path) -> Result<()> {
 
 /// Check only locked settings (edition, rust-version) — used for dev command blocking
 ///
+/// Violations recorded in the project baseline are ignored.
+///
 /// # Errors
 ///
 /// Returns an error if loading the config or running validation fails.
 pub async fn check_locked_settings(project_path: &Path) -> Result<Vec<Violation>> {
-    let config = Config::load_or_default().await?;
-    let validator = RustValidator::with_config(project_path.to_path_buf(), config)?;
-    let all_violations = validator.validate_project().await?;
+    let validator = RustValidator::for_project(project_path.to_path_buf()).await?;
+    let all_violations = validator.validate_with_baseline().await?.new_violations;
 
     let locked: Vec<Violation> = all_violations
         .into_iter()
@@ -56,34 +56,59 @@ pub async fn check_locked_settings(project_path: &Path) -> Result<Vec<Violation>
     Ok(locked)
 }
 
-/// Check only style violations (file size, function size, underscore bandaid)
-/// Used for dev command warnings (non-blocking)
+/// Style violations found for a dev command, split by how they are handled
+#[derive(Debug, Default)]
+pub struct StyleViolations {
+    /// Violations whose rule is set to `error` in `[rules]`; these block
+    pub blocking: Vec<Violation>,
+    /// Violations reported as warnings without blocking
+    pub warnings: Vec<Violation>,
+}
+
+/// Check style violations for dev command warnings
+///
+/// By default the style tier is file size, function size, underscore
+/// bandaid and missing docs, and only warns. Rules listed in the `[rules]`
+/// config table, or in an `[[overrides]]` block matching the file, join the
+/// tier: set to `error` they block dev commands, set to `warning` they
+/// warn, and set to `info` or `off` they stay silent.
+/// Violations recorded in the project baseline are ignored.
 ///
 /// # Errors
 ///
 /// Returns an error if loading the config or running validation fails.
-pub async fn check_style_violations(project_path: &Path) -> Result<Vec<Violation>> {
-    let config = Config::load_or_default().await?;
-    let validator = RustValidator::with_config(project_path.to_path_buf(), config)?;
-    let all_violations = validator.validate_project().await?;
-
-    let style: Vec<Violation> = all_violations
-        .into_iter()
-        .filter(|v| {
-            matches!(
-                v.violation_type,
-                ViolationType::FileTooLarge
-                    | ViolationType::FunctionTooLarge
-                    | ViolationType::UnderscoreBandaid
-                    | ViolationType::MissingModuleDoc
-                    | ViolationType::MissingDocConfig
-            )
-        })
-        .collect();
+pub async fn check_style_violations(project_path: &Path) -> Result<StyleViolations> {
+    let validator = RustValidator::for_project(project_path.to_path_buf()).await?;
+    let all_violations = validator.validate_with_baseline().await?.new_violations;
+
+    let mut style = StyleViolations::default();
+    for violation in all_violations {
+        match validator.rule_level(&violation) {
+            Some(RuleLevel::Error) => style.blocking.push(violation),
+            Some(RuleLevel::Warning) => style.warnings.push(violation),
+            Some(RuleLevel::Info | RuleLevel::Off) => {}
+            None if is_default_style(&violation.violation_type) => {
+                style.warnings.push(violation);
+            }
+            None => {}
+        }
+    }
 
     Ok(style)
 }
 
+/// Violation types that warn during dev commands without any `[rules]` entry
+fn is_default_style(violation_type: &ViolationType) -> bool {
+    matches!(
+        violation_type,
+        ViolationType::FileTooLarge
+            | ViolationType::FunctionTooLarge
+            | ViolationType::UnderscoreBandaid
+            | ViolationType::MissingModuleDoc
+            | ViolationType::MissingDocConfig
+    )
+}
+
 /// Enforce dogfooding by checking Ferrous Forge usage
 ///
 /// # Errors
@@ -27,12 +27,36 @@ pub struct Cli {
 }
 
 /// Output format options
-#[derive(clap::ValueEnum, Clone, Debug)]
+///
+/// `Json` and `Yaml` emit a schema-versioned document on stdout for the
+/// commands that support structured output; see [`crate::output`].
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum OutputFormat {
     /// Human-readable output
     Human,
     /// JSON output
     Json,
-    /// YAML output  
+    /// YAML output
     Yaml,
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rollback_arguments() {
+        let cli = Cli::try_parse_from(["ferrous-forge", "rollback", "--previous"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            crate::commands::Commands::Rollback {
+                version: None,
+                previous: true,
+                list: false,
+            }
+        ));
+        assert!(Cli::try_parse_from(["ferrous-forge", "rollback"]).is_err());
+        assert!(Cli::try_parse_from(["ferrous-forge", "rollback", "1.0.0", "--previous"]).is_err());
+    }
+}
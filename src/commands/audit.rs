@@ -0,0 +1,62 @@
+//! Verify and export hash-chained audit logs
+
+use crate::audit_chain::AuditChain;
+use crate::{Error, Result, output};
+use console::style;
+use std::path::Path;
+
+/// Handle `--verify` and `--export` for an audit log
+///
+/// Verifies every entry of `chain` and, when the log is intact and
+/// `export` is given, copies it there as JSON lines.
+///
+/// # Errors
+///
+/// Returns an error if the log cannot be read, fails verification, or the
+/// export cannot be written.
+pub async fn handle_verify(command: &str, chain: &AuditChain, export: Option<&Path>) -> Result<()> {
+    let report = chain.verify().await?;
+
+    if output::is_machine_readable() {
+        output::emit(command, &report)?;
+    } else {
+        println!(
+            "{} {}",
+            style("🔐 Verifying").bold(),
+            chain.path().display()
+        );
+        println!("   Entries: {}", report.entries);
+        if report.legacy > 0 {
+            println!(
+                "   {}",
+                style(format!(
+                    "⚠️  {} entries predate hash chaining and cannot be verified",
+                    report.legacy
+                ))
+                .yellow()
+            );
+        }
+        for problem in &report.problems {
+            println!("   {} {}", style("✗").red(), problem);
+        }
+    }
+
+    if !report.is_intact() {
+        output::human(style("❌ Audit log failed verification").red().bold());
+        return Err(Error::validation(format!(
+            "{} has been modified or truncated",
+            chain.path().display()
+        )));
+    }
+    output::human(style("✅ Audit log is intact").green().bold());
+
+    if let Some(destination) = export {
+        chain.export(destination).await?;
+        output::human(format!(
+            "📦 Exported {} entries to {}",
+            report.entries,
+            destination.display()
+        ));
+    }
+    Ok(())
+}
@@ -6,11 +6,13 @@
 
 use crate::commands::ConfigCommand;
 use crate::config::{
-    Config, ConfigValidator, HierarchicalConfig, HierarchicalLockManager, ImportOptions,
+    Config, ConfigValidator, HierarchicalConfig, HierarchicalLockManager, ImportOptions, LockEntry,
     SharedConfig, audit_log, import_shared_config,
 };
-use crate::{Error, Result};
+use crate::{Error, Result, output};
 use console::style;
+use serde::Serialize;
+use std::collections::BTreeMap;
 
 /// Execute the config command with subcommand
 ///
@@ -24,6 +26,7 @@ pub async fn execute_with_subcommand(command: ConfigCommand) -> Result<()> {
         ConfigCommand::List => handle_list().await,
         ConfigCommand::Reset => handle_reset().await,
         ConfigCommand::Sources => show_sources().await,
+        ConfigCommand::Explain { key } => handle_explain(&key).await,
         ConfigCommand::Migrate => migrate_config().await,
         ConfigCommand::Lock {
             key,
@@ -35,7 +38,18 @@ pub async fn execute_with_subcommand(command: ConfigCommand) -> Result<()> {
             handle_unlock(&key, &reason, level.to_config_level()).await
         }
         ConfigCommand::LockStatus => handle_lock_status().await,
-        ConfigCommand::LockAudit { limit } => handle_lock_audit(limit).await,
+        ConfigCommand::LockAudit {
+            limit,
+            verify,
+            export,
+        } => {
+            if verify || export.is_some() {
+                let chain = audit_log::audit_chain()?;
+                super::audit::handle_verify("config audit", &chain, export.as_deref()).await
+            } else {
+                handle_lock_audit(limit).await
+            }
+        }
         ConfigCommand::Export {
             level,
             output,
@@ -50,6 +64,15 @@ pub async fn execute_with_subcommand(command: ConfigCommand) -> Result<()> {
     }
 }
 
+/// Structured result of `config list` for `--format json|yaml`
+#[derive(Debug, Serialize)]
+pub struct ConfigListOutput {
+    /// Effective configuration values
+    pub config: Config,
+    /// Effective locks keyed by configuration key
+    pub locked: BTreeMap<String, LockEntry>,
+}
+
 /// Handle the get command
 async fn handle_get(key: &str) -> Result<()> {
     let config = Config::load_or_default().await?;
@@ -68,7 +91,7 @@ async fn handle_get(key: &str) -> Result<()> {
 
 /// Handle the set command with lock validation
 async fn handle_set(set_value: &str) -> Result<()> {
-    let mut config = Config::load_or_default().await?;
+    let mut config = Config::load_user().await?;
 
     if let Some((key, value)) = set_value.split_once('=') {
         // Validate against locks before setting
@@ -96,6 +119,15 @@ async fn handle_list() -> Result<()> {
     let config = Config::load_or_default().await?;
     let lock_manager = HierarchicalLockManager::load().await?;
 
+    if output::is_machine_readable() {
+        let locked = lock_manager
+            .get_effective_locks()
+            .into_iter()
+            .map(|(key, (_, entry))| (key, entry))
+            .collect();
+        return output::emit("config list", &ConfigListOutput { config, locked });
+    }
+
     println!(
         "{}",
         style("⚙️  Ferrous Forge Configuration:").bold().cyan()
@@ -148,7 +180,7 @@ async fn handle_list() -> Result<()> {
 /// Handle the reset command
 #[allow(clippy::collapsible_if)]
 async fn handle_reset() -> Result<()> {
-    let mut config = Config::load_or_default().await?;
+    let mut config = Config::load_user().await?;
 
     // Check for locked keys before reset
     let lock_manager = HierarchicalLockManager::load().await?;
@@ -329,6 +361,7 @@ pub fn show_help() {
     println!("  ferrous-forge config set <key=value>        # Set a setting");
     println!("  ferrous-forge config reset                   # Reset to defaults");
     println!("  ferrous-forge config sources                 # Show config hierarchy");
+    println!("  ferrous-forge config explain <key>           # Show which layer sets a key");
     println!("  ferrous-forge config migrate                 # Migrate old config");
     println!();
     println!("Locking Commands:");
@@ -346,6 +379,8 @@ pub fn show_help() {
     println!("  1. System: /etc/ferrous-forge/config.toml");
     println!("  2. User: ~/.config/ferrous-forge/config.toml");
     println!("  3. Project: ./.ferrous-forge/config.toml");
+    println!("  4. CLI: the file given with --config");
+    println!("  5. Environment: FERROUS_FORGE_<KEY> (e.g. FERROUS_FORGE_MAX_FILE_LINES=400)");
     println!();
     println!("Lock Levels:");
     println!("  Project-level locks override User-level and System-level locks");
@@ -385,6 +420,38 @@ pub async fn show_sources() -> Result<()> {
     Ok(())
 }
 
+/// Show which layers set `key` and which one wins
+///
+/// # Errors
+///
+/// Returns an error if the hierarchical configuration cannot be loaded or
+/// the key is unknown.
+pub async fn handle_explain(key: &str) -> Result<()> {
+    let hier = HierarchicalConfig::load().await?;
+    let explanation = hier.explain(key);
+    let Some(value) = &explanation.value else {
+        return Err(Error::config(format!("Unknown configuration key: {}", key)));
+    };
+
+    if output::is_machine_readable() {
+        return output::emit("config explain", &explanation);
+    }
+
+    println!("{} = {}", style(key).bold(), value);
+    match &explanation.source {
+        Some(source) => println!("  set by {}", style(source).cyan()),
+        None => println!("  {}", style("default (no layer sets it)").dim()),
+    }
+    if explanation.layers.len() > 1 {
+        println!();
+        println!("Layers, lowest precedence first:");
+        for layer in &explanation.layers {
+            println!("  {}: {}", layer.source, layer.value);
+        }
+    }
+    Ok(())
+}
+
 /// Migrate old configuration to hierarchical system
 ///
 /// # Errors
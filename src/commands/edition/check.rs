@@ -1,11 +1,21 @@
 //! Edition check command
 
-use crate::Result;
-use crate::edition::{check_compliance, get_migration_recommendations};
+use crate::edition::{EditionStatus, check_compliance, get_migration_recommendations};
+use crate::{Result, output};
 use console::style;
 use indicatif::{ProgressBar, ProgressStyle};
+use serde::Serialize;
 use std::path::Path;
 
+/// Structured result of `edition check` for `--format json|yaml`
+#[derive(Debug, Serialize)]
+pub struct EditionCheckOutput {
+    /// Compliance status of the project manifest
+    pub status: EditionStatus,
+    /// Migration recommendations for the project
+    pub recommendations: Vec<String>,
+}
+
 /// Handle edition check command
 ///
 /// # Errors
@@ -14,6 +24,17 @@ use std::path::Path;
 pub async fn handle_check(path: &Path) -> Result<()> {
     let status = run_compliance_check_with_progress(path).await?;
 
+    if output::is_machine_readable() {
+        let recommendations = get_migration_recommendations(&status);
+        return output::emit(
+            "edition check",
+            &EditionCheckOutput {
+                status,
+                recommendations,
+            },
+        );
+    }
+
     display_compliance_header(path, &status);
     display_edition_status(&status);
     display_migration_status(&status);
@@ -23,7 +44,7 @@ pub async fn handle_check(path: &Path) -> Result<()> {
 }
 
 /// Run compliance check with progress indicator
-async fn run_compliance_check_with_progress(path: &Path) -> Result<crate::edition::EditionStatus> {
+async fn run_compliance_check_with_progress(path: &Path) -> Result<EditionStatus> {
     let spinner = ProgressBar::new_spinner();
     spinner.set_style(
         ProgressStyle::default_spinner()
@@ -40,7 +61,7 @@ async fn run_compliance_check_with_progress(path: &Path) -> Result<crate::editio
 }
 
 /// Display compliance check header information
-fn display_compliance_header(path: &Path, status: &crate::edition::EditionStatus) {
+fn display_compliance_header(path: &Path, status: &EditionStatus) {
     println!("📚 Edition Compliance Status\n");
     println!("  Project:  {}", style(path.display()).dim());
     println!(
@@ -51,7 +72,7 @@ fn display_compliance_header(path: &Path, status: &crate::edition::EditionStatus
 }
 
 /// Display current and latest edition status
-fn display_edition_status(status: &crate::edition::EditionStatus) {
+fn display_edition_status(status: &EditionStatus) {
     let current_style = if status.is_latest {
         style(status.current.to_string()).green()
     } else {
@@ -64,7 +85,7 @@ fn display_edition_status(status: &crate::edition::EditionStatus) {
 }
 
 /// Display migration status and path if available
-fn display_migration_status(status: &crate::edition::EditionStatus) {
+fn display_migration_status(status: &EditionStatus) {
     if status.is_latest {
         println!(
             "{}",
@@ -92,7 +113,7 @@ fn display_migration_status(status: &crate::edition::EditionStatus) {
 }
 
 /// Display migration recommendations
-fn display_recommendations(status: &crate::edition::EditionStatus) {
+fn display_recommendations(status: &EditionStatus) {
     println!("\n📋 Recommendations:");
     for recommendation in get_migration_recommendations(status) {
         println!("  • {}", recommendation);
@@ -1,7 +1,7 @@
 //! Edition migrate command
 
-use crate::Result;
 use crate::edition::{Edition, EditionMigrator, migrator::MigrationOptions};
+use crate::{Error, Result};
 use console::style;
 use indicatif::{ProgressBar, ProgressStyle};
 
@@ -10,7 +10,7 @@ use indicatif::{ProgressBar, ProgressStyle};
 /// # Errors
 ///
 /// Returns an error if the edition string is invalid, the current directory
-/// cannot be determined, or the migration process fails.
+/// cannot be determined, or the migration fails (after restoring the backup).
 pub async fn handle_migrate(
     edition_str: &str,
     no_backup: bool,
@@ -93,6 +93,7 @@ fn display_migration_plan(target_edition: &Edition, no_backup: bool, test: bool)
 fn create_migration_options(no_backup: bool, test: bool, idioms: bool) -> MigrationOptions {
     MigrationOptions {
         create_backup: !no_backup,
+        apply_code_migrations: true,
         run_tests: test,
         fix_idioms: idioms,
         ..Default::default()
@@ -130,6 +131,17 @@ fn process_migration_result(result: &crate::edition::migrator::MigrationResult)
     display_migration_status(&result.status)?;
     display_result_messages(result);
     display_backup_info(result);
+
+    if result.status == crate::edition::migrator::MigrationStatus::Failed {
+        return Err(Error::migration(
+            result
+                .errors
+                .first()
+                .cloned()
+                .unwrap_or_else(|| "Edition migration failed".to_string()),
+        ));
+    }
+
     display_next_steps();
     Ok(())
 }
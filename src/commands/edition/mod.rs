@@ -3,7 +3,9 @@
 mod analyze;
 mod check;
 mod migrate;
+mod restore;
 
 pub use analyze::handle_analyze;
 pub use check::handle_check;
 pub use migrate::handle_migrate;
+pub use restore::handle_restore;
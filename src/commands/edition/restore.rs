@@ -0,0 +1,57 @@
+//! Edition restore command
+
+use crate::edition::migrator::{BackupManifest, latest_backup, restore_project};
+use crate::{Error, Result};
+use console::style;
+use std::path::{Path, PathBuf};
+
+/// Handle edition restore command
+///
+/// Restores the project's `Cargo.toml`s and sources from `backup`, or from
+/// the most recent edition migration backup when none is given, and
+/// deletes the files the migration created.
+///
+/// # Errors
+///
+/// Returns an error if no backup exists, the backup fails hash
+/// verification, or files cannot be written.
+pub async fn handle_restore(path: &Path, backup: Option<PathBuf>) -> Result<()> {
+    let backup_dir = match backup {
+        Some(dir) => dir,
+        None => latest_backup(path).await?.ok_or_else(|| {
+            Error::migration(format!(
+                "No edition migration backup found under {}",
+                crate::edition::migrator::backup::backups_root(path).display()
+            ))
+        })?,
+    };
+
+    let manifest = BackupManifest::load(&backup_dir).await?;
+    println!("♻️  Restoring project from edition backup\n");
+    println!("  Project: {}", style(path.display()).dim());
+    println!("  Backup:  {}", style(backup_dir.display()).dim());
+    println!(
+        "  Taken:   {}",
+        manifest.created_at.format("%Y-%m-%d %H:%M:%S UTC")
+    );
+    println!();
+
+    let report = restore_project(path, &backup_dir).await?;
+
+    println!(
+        "{}",
+        style(format!(
+            "✅ Restored {} files (all hashes verified)",
+            report.restored.len()
+        ))
+        .green()
+        .bold()
+    );
+    if !report.removed.is_empty() {
+        println!(
+            "   Removed {} files created by the migration",
+            report.removed.len()
+        );
+    }
+    Ok(())
+}
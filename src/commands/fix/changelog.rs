@@ -0,0 +1,122 @@
+//! Scaffold a CHANGELOG.md section for the current version
+
+use super::edits::{Proposal, TextEdit};
+use crate::validation::Violation;
+
+/// Header of a changelog created from scratch
+const CHANGELOG_HEADER: &str = "# Changelog
+
+All notable changes to this project will be documented in this file.
+
+The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),
+and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).
+
+## [Unreleased]
+
+";
+
+/// Add an empty `## [version] - date` section with the required
+/// subsections, creating the changelog when `source` is empty
+///
+/// The section goes below `## [Unreleased]`, or above the newest release
+/// when there is no such section.
+pub fn fix_missing_changelog_entry(
+    violation: &Violation,
+    source: &str,
+    version: &str,
+    sections: &[String],
+    date: &str,
+) -> Proposal {
+    if source.contains(&format!("## [{}]", version)) {
+        return Err(format!("CHANGELOG.md already has a [{}] section", version));
+    }
+
+    let mut entry = format!("## [{}] - {}\n\n", version, date);
+    for section in sections {
+        entry.push_str(&format!("### {}\n\n", section));
+    }
+    if source.trim().is_empty() {
+        return Ok(vec![TextEdit::replace(
+            &violation.file,
+            0,
+            source.len(),
+            format!("{}{}", CHANGELOG_HEADER, entry),
+        )]);
+    }
+
+    let headings: Vec<usize> = source
+        .match_indices("## ")
+        .map(|(i, _)| i)
+        .filter(|&i| i == 0 || source[..i].ends_with('\n'))
+        .collect();
+    let unreleased = headings
+        .iter()
+        .position(|&i| source[i..].starts_with("## [Unreleased]"));
+    let next = match unreleased {
+        Some(index) => headings.get(index + 1),
+        None => headings.first(),
+    };
+
+    match next {
+        Some(&at) => Ok(vec![TextEdit::insert(&violation.file, at, entry)]),
+        None => {
+            let separator = match source {
+                s if s.ends_with("\n\n") => "",
+                s if s.ends_with('\n') => "\n",
+                _ => "\n\n",
+            };
+            let entry = format!("{}{}", separator, entry.trim_end());
+            Ok(vec![TextEdit::insert(
+                &violation.file,
+                source.len(),
+                entry + "\n",
+            )])
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::commands::fix::edits::apply_edits;
+    use crate::validation::{Severity, ViolationType};
+    use std::path::PathBuf;
+
+    fn scaffold(source: &str) -> String {
+        let violation = Violation::new(
+            ViolationType::MissingChangelogEntry,
+            PathBuf::from("CHANGELOG.md"),
+            1,
+            String::new(),
+            Severity::Error,
+        );
+        let sections = vec!["Added".to_string(), "Fixed".to_string()];
+        let edits =
+            fix_missing_changelog_entry(&violation, source, "1.1.0", &sections, "2026-01-02")
+                .unwrap();
+        apply_edits(source, &edits.iter().collect::<Vec<_>>()).unwrap()
+    }
+
+    #[test]
+    fn test_scaffold_below_unreleased() {
+        let source = "# Changelog\n\n## [Unreleased]\n\n- wip\n\n## [1.0.0] - 2025-01-01\n";
+        assert_eq!(
+            scaffold(source),
+            "# Changelog\n\n## [Unreleased]\n\n- wip\n\n\
+             ## [1.1.0] - 2026-01-02\n\n### Added\n\n### Fixed\n\n\
+             ## [1.0.0] - 2025-01-01\n"
+        );
+    }
+
+    #[test]
+    fn test_scaffold_new_changelog() {
+        let fixed = scaffold("");
+        assert!(fixed.starts_with("# Changelog\n"));
+        assert!(
+            fixed.ends_with(
+                "## [Unreleased]\n\n## [1.1.0] - 2026-01-02\n\n### Added\n\n### Fixed\n\n"
+            )
+        );
+    }
+}
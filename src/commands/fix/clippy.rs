@@ -0,0 +1,126 @@
+//! Machine-applicable clippy suggestions for the fix command
+
+use super::edits::{EditPlan, TextEdit};
+use super::file_processing::{preview_all_files, process_all_files};
+use super::types::{FilterOptions, FixStats, Verification};
+use crate::Result;
+use crate::validation::{CompilerDiagnostic, RustValidator};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name that selects clippy suggestions in `--only` / `--skip`
+const CLIPPY_FILTER: &str = "CLIPPY";
+
+/// Whether the filters select clippy suggestions
+pub fn wants_clippy_fixes(filter_options: &FilterOptions) -> bool {
+    filter_options
+        .only_types
+        .as_ref()
+        .is_none_or(|only| only.contains(CLIPPY_FILTER))
+        && filter_options
+            .skip_types
+            .as_ref()
+            .is_none_or(|skip| !skip.contains(CLIPPY_FILTER))
+}
+
+/// Run clippy and apply its machine-applicable suggestions
+///
+/// The suggestions are applied like any other fix: one lint at a time,
+/// reverting those that break the build.
+///
+/// # Errors
+///
+/// Returns an error if clippy cannot be run, a fixed file cannot be
+/// written, or cargo cannot be run to verify the fixes.
+pub async fn fix_clippy_suggestions(
+    validator: &RustValidator,
+    dry_run: bool,
+    verification: Verification,
+) -> Result<FixStats> {
+    println!("🔧 Running clippy for machine-applicable suggestions...");
+    let project_root = validator.project_root();
+    let clippy_result = validator.run_clippy().await?;
+    let plan = plan_suggestions(&clippy_result.diagnostics, project_root);
+
+    if plan.fixed.is_empty() {
+        println!("   ✅ No machine-applicable clippy suggestions");
+        return Ok(FixStats::default());
+    }
+    if dry_run {
+        let sources: HashMap<PathBuf, String> = plan
+            .files()
+            .into_keys()
+            .filter_map(|file| Some((file.clone(), fs::read_to_string(file).ok()?)))
+            .collect();
+        Ok(preview_all_files(
+            &plan,
+            |file| sources.get(file).map(String::as_str),
+            project_root,
+        ))
+    } else {
+        process_all_files(&plan, project_root, verification).await
+    }
+}
+
+/// One fix per diagnostic with machine-applicable suggestions, each a
+/// violation of its lint
+fn plan_suggestions(diagnostics: &[CompilerDiagnostic], project_root: &Path) -> EditPlan {
+    let mut plan = EditPlan::default();
+    for diagnostic in diagnostics {
+        let edits: Vec<TextEdit> = diagnostic
+            .machine_applicable()
+            .map(|s| {
+                TextEdit::replace(
+                    &project_root.join(&s.file),
+                    s.byte_start,
+                    s.byte_end,
+                    s.replacement.clone(),
+                )
+            })
+            .collect();
+        if let Some(violation) = diagnostic.to_violation(project_root)
+            && !edits.is_empty()
+        {
+            plan.add(violation, edits);
+        }
+    }
+    plan
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::validation::ViolationType;
+    use crate::validation::diagnostics::parse_cargo_messages;
+
+    fn diagnostic(code: &str, start: usize, end: usize, replacement: &str) -> String {
+        format!(
+            r#"{{"reason":"compiler-message","message":{{"message":"lint","code":{{"code":"{code}","explanation":null}},"level":"warning","spans":[{{"file_name":"src/lib.rs","byte_start":{start},"byte_end":{end},"line_start":1,"line_end":1,"column_start":1,"column_end":2,"is_primary":true,"suggested_replacement":null,"suggestion_applicability":null}}],"children":[{{"message":"fix","code":null,"level":"help","spans":[{{"file_name":"src/lib.rs","byte_start":{start},"byte_end":{end},"line_start":1,"line_end":1,"column_start":1,"column_end":2,"is_primary":true,"suggested_replacement":"{replacement}","suggestion_applicability":"MachineApplicable"}}],"children":[],"rendered":null}}],"rendered":null}}}}"#
+        )
+    }
+
+    #[test]
+    fn test_plan_suggestions_one_fix_per_lint_diagnostic() {
+        let output = [
+            diagnostic("clippy::needless_return", 0, 6, "y"),
+            diagnostic("clippy::other", 2, 4, "z"),
+            diagnostic("clippy::redundant_clone", 10, 18, ""),
+        ]
+        .join("\n");
+        let root = Path::new("/project");
+        let plan = plan_suggestions(&parse_cargo_messages(&output), root);
+
+        assert_eq!(plan.fixed.len(), 2);
+        assert_eq!(plan.fixed[0].edits[0].file, root.join("src/lib.rs"));
+        assert_eq!(
+            plan.fixed[1].violation.violation_type,
+            ViolationType::CompilerDiagnostic {
+                code: "clippy::redundant_clone".to_string()
+            }
+        );
+        // The overlapping suggestion waits for the next run
+        assert_eq!(plan.conflicts.len(), 1);
+    }
+}
@@ -6,7 +6,6 @@ const OPEN_BRACE: char = '{';
 const CLOSE_BRACE: char = '}';
 
 /// Analyze a file's content to understand its context
-#[allow(dead_code)]
 pub fn analyze_file_context(content: &str) -> FileContext {
     let is_test_file = content.contains("#[test]")
         || content.contains("#[cfg(test)]")
@@ -40,7 +39,6 @@ pub fn analyze_file_context(content: &str) -> FileContext {
 }
 
 /// Parse a function signature that may span multiple lines
-#[allow(dead_code)]
 pub fn parse_function_signature_multiline(
     lines: &[&str],
     start_idx: usize,
@@ -60,7 +58,6 @@ pub fn parse_function_signature_multiline(
 }
 
 /// Collect signature lines until the opening brace is found
-#[allow(dead_code)]
 fn collect_signature_lines(lines: &[&str], start_idx: usize) -> Option<(String, usize)> {
     let mut brace_line = start_idx;
     let mut signature_lines = vec![lines[start_idx].to_string()];
@@ -79,7 +76,6 @@ fn collect_signature_lines(lines: &[&str], start_idx: usize) -> Option<(String,
 }
 
 /// Extract the function name from the full signature
-#[allow(dead_code)]
 fn extract_function_name(full_signature: &str) -> Option<String> {
     let name_start = full_signature.find("fn ")?;
     let name_part = &full_signature[name_start + 3..];
@@ -88,7 +84,6 @@ fn extract_function_name(full_signature: &str) -> Option<String> {
 }
 
 /// Check what types the function returns
-#[allow(dead_code)]
 fn check_return_types(full_signature: &str) -> (bool, bool) {
     let returns_result = full_signature.contains("-> Result")
         || full_signature.contains("-> anyhow::Result")
@@ -139,9 +134,8 @@ fn find_function_end(lines: &[&str], brace_line: usize, start_idx: usize) -> usi
     line_end
 }
 
-/// Check if the ? operator can be used in this context
-#[allow(dead_code)]
-pub fn check_can_use_question_mark(context: &FileContext) -> bool {
+/// Check if the ? operator can be used at 1-based `line`
+pub fn check_can_use_question_mark(context: &FileContext, line: usize) -> bool {
     // Don't use ? in test functions
     if context.is_test_file {
         return false;
@@ -163,11 +157,11 @@ pub fn check_can_use_question_mark(context: &FileContext) -> bool {
         return false;
     }
 
-    // For other cases, check if we're in a function that returns Result or Option
-    // This is a simplified check - in reality we'd need to know which function we're in
-    !context.function_signatures.is_empty()
-        && context
-            .function_signatures
-            .iter()
-            .any(|sig| sig.returns_result || sig.returns_option)
+    // Otherwise the innermost function around the line must return Result or Option
+    context
+        .function_signatures
+        .iter()
+        .filter(|sig| sig.line_start <= line && line <= sig.line_end)
+        .max_by_key(|sig| sig.line_start)
+        .is_some_and(|sig| sig.returns_result || sig.returns_option)
 }
@@ -0,0 +1,271 @@
+//! Line diffs for fix previews and for turning reformatted files into edits
+
+use console::style;
+
+/// Lines of context around each change in a unified diff
+const CONTEXT_LINES: usize = 3;
+
+/// Largest number of line pairs compared exactly; bigger changes are
+/// reported as one replaced block
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// One step of a line diff
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffOp {
+    /// Line kept, by index in the old and new text
+    Equal(usize, usize),
+    /// Old line removed
+    Delete(usize),
+    /// New line added
+    Insert(usize),
+}
+
+/// A block of old lines replaced by new text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangedRegion {
+    /// First replaced line (0-based)
+    pub old_start: usize,
+    /// Line after the last replaced line (0-based, exclusive)
+    pub old_end: usize,
+    /// Text replacing the lines, including line endings
+    pub replacement: String,
+}
+
+/// Split `text` into lines that keep their `\n`, so a missing final
+/// newline is a change too
+fn lines(text: &str) -> Vec<&str> {
+    text.split_inclusive('\n').collect()
+}
+
+/// Diff two line lists with a longest common subsequence
+fn diff_lines(old: &[&str], new: &[&str]) -> Vec<DiffOp> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let (old_mid, new_mid) = (
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+    );
+
+    let mut ops: Vec<DiffOp> = (0..prefix).map(|i| DiffOp::Equal(i, i)).collect();
+    if old_mid.len().saturating_mul(new_mid.len()) > MAX_DIFF_CELLS {
+        ops.extend((0..old_mid.len()).map(|i| DiffOp::Delete(prefix + i)));
+        ops.extend((0..new_mid.len()).map(|j| DiffOp::Insert(prefix + j)));
+    } else {
+        ops.extend(lcs_ops(old_mid, new_mid).into_iter().map(|op| match op {
+            DiffOp::Equal(i, j) => DiffOp::Equal(prefix + i, prefix + j),
+            DiffOp::Delete(i) => DiffOp::Delete(prefix + i),
+            DiffOp::Insert(j) => DiffOp::Insert(prefix + j),
+        }));
+    }
+    let (old_tail, new_tail) = (old.len() - suffix, new.len() - suffix);
+    ops.extend((0..suffix).map(|k| DiffOp::Equal(old_tail + k, new_tail + k)));
+    ops
+}
+
+fn lcs_ops(old: &[&str], new: &[&str]) -> Vec<DiffOp> {
+    let width = new.len() + 1;
+    // lengths[i * width + j] = LCS length of old[i..] and new[j..]
+    let mut lengths = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i * width + j] = if old[i] == new[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut ops = Vec::new();
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            ops.push(DiffOp::Equal(i, j));
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            ops.push(DiffOp::Delete(i));
+            i += 1;
+        } else {
+            ops.push(DiffOp::Insert(j));
+            j += 1;
+        }
+    }
+    ops.extend((i..old.len()).map(DiffOp::Delete));
+    ops.extend((j..new.len()).map(DiffOp::Insert));
+    ops
+}
+
+/// The blocks of `old` that differ from `new`, each with its replacement
+pub fn changed_regions(old: &str, new: &str) -> Vec<ChangedRegion> {
+    let (old_lines, new_lines) = (lines(old), lines(new));
+    let mut regions: Vec<ChangedRegion> = Vec::new();
+    let mut old_line = 0;
+    let mut open = false;
+    for op in diff_lines(&old_lines, &new_lines) {
+        match op {
+            DiffOp::Equal(i, _) => {
+                open = false;
+                old_line = i + 1;
+            }
+            DiffOp::Delete(i) | DiffOp::Insert(i) => {
+                if !open {
+                    regions.push(ChangedRegion {
+                        old_start: old_line,
+                        old_end: old_line,
+                        replacement: String::new(),
+                    });
+                    open = true;
+                }
+                let Some(region) = regions.last_mut() else {
+                    continue;
+                };
+                if matches!(op, DiffOp::Delete(_)) {
+                    old_line = i + 1;
+                    region.old_end = old_line;
+                } else {
+                    region.replacement.push_str(new_lines[i]);
+                }
+            }
+        }
+    }
+    regions
+}
+
+/// Render the change from `old` to `new` as a unified diff of `path`
+///
+/// Returns an empty string when the texts are equal.
+pub fn unified_diff(path: &str, old: &str, new: &str) -> String {
+    let (old_lines, new_lines) = (lines(old), lines(new));
+    let ops = diff_lines(&old_lines, &new_lines);
+    let changes: Vec<usize> = ops
+        .iter()
+        .enumerate()
+        .filter(|(_, op)| !matches!(op, DiffOp::Equal(..)))
+        .map(|(k, _)| k)
+        .collect();
+    let Some(&first) = changes.first() else {
+        return String::new();
+    };
+
+    // Group changes whose context would touch into hunks of op indices
+    let mut hunks = vec![(first.saturating_sub(CONTEXT_LINES), first)];
+    for &k in &changes {
+        let Some(last) = hunks.last_mut() else { break };
+        if k <= last.1 + 2 * CONTEXT_LINES + 1 {
+            last.1 = k;
+        } else {
+            hunks.push((k.saturating_sub(CONTEXT_LINES), k));
+        }
+    }
+
+    let mut out = format!("--- a/{}\n+++ b/{}\n", path, path);
+    for (start, last_change) in hunks {
+        let end = (last_change + CONTEXT_LINES + 1).min(ops.len());
+        let hunk = &ops[start..end];
+        let (old_start, new_start) = hunk_start(&ops[..start]);
+        let old_count = hunk
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Insert(_)))
+            .count();
+        let new_count = hunk
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Delete(_)))
+            .count();
+        out.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            old_start + usize::from(old_count > 0),
+            old_count,
+            new_start + usize::from(new_count > 0),
+            new_count
+        ));
+        for op in hunk {
+            let (marker, line) = match *op {
+                DiffOp::Equal(i, _) => (' ', old_lines[i]),
+                DiffOp::Delete(i) => ('-', old_lines[i]),
+                DiffOp::Insert(j) => ('+', new_lines[j]),
+            };
+            out.push(marker);
+            out.push_str(line);
+            if !line.ends_with('\n') {
+                out.push_str("\n\\ No newline at end of file\n");
+            }
+        }
+    }
+    out
+}
+
+/// Print a unified diff with added and removed lines colored
+pub fn print_diff(diff: &str) {
+    for line in diff.lines() {
+        let styled = if line.starts_with("+++") || line.starts_with("---") {
+            style(line).bold()
+        } else if line.starts_with('+') {
+            style(line).green()
+        } else if line.starts_with('-') {
+            style(line).red()
+        } else if line.starts_with("@@") {
+            style(line).cyan()
+        } else {
+            style(line)
+        };
+        println!("{}", styled);
+    }
+}
+
+/// Old and new line counts before a hunk
+fn hunk_start(before: &[DiffOp]) -> (usize, usize) {
+    before.iter().fold((0, 0), |(old, new), op| match op {
+        DiffOp::Equal(..) => (old + 1, new + 1),
+        DiffOp::Delete(_) => (old + 1, new),
+        DiffOp::Insert(_) => (old, new + 1),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changed_regions() {
+        let old = "a\nb\nc\nd\n";
+        let new = "a\nB\nc\nd\ne\n";
+        let regions = changed_regions(old, new);
+        assert_eq!(
+            regions,
+            vec![
+                ChangedRegion {
+                    old_start: 1,
+                    old_end: 2,
+                    replacement: "B\n".to_string(),
+                },
+                ChangedRegion {
+                    old_start: 4,
+                    old_end: 4,
+                    replacement: "e\n".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let diff = unified_diff("src/lib.rs", "a\nb\nc\n", "a\nx\nc\n");
+        assert_eq!(
+            diff,
+            "--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,3 +1,3 @@\n a\n-b\n+x\n c\n"
+        );
+        assert!(unified_diff("src/lib.rs", "same\n", "same\n").is_empty());
+    }
+
+    #[test]
+    fn test_unified_diff_of_new_file() {
+        let diff = unified_diff("CHANGELOG.md", "", "# Changelog\n");
+        assert!(diff.contains("@@ -0,0 +1,1 @@\n+# Changelog\n"));
+    }
+}
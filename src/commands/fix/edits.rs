@@ -0,0 +1,196 @@
+//! Span-based text edits and conflict detection for the fix command
+
+use crate::validation::Violation;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Edits fixing one violation, or why it needs manual attention
+pub type Proposal = Result<Vec<TextEdit>, String>;
+
+/// Replacement of a byte range in one file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    /// File the edit applies to; it may not exist yet
+    pub file: PathBuf,
+    /// Byte offset where the replaced text starts
+    pub byte_start: usize,
+    /// Byte offset where the replaced text ends (exclusive)
+    pub byte_end: usize,
+    /// Text to put in place of the range
+    pub replacement: String,
+}
+
+impl TextEdit {
+    /// Insert `text` at byte offset `at`
+    pub fn insert(file: &Path, at: usize, text: impl Into<String>) -> Self {
+        Self::replace(file, at, at, text)
+    }
+
+    /// Replace the bytes `start..end` with `text`
+    pub fn replace(file: &Path, start: usize, end: usize, text: impl Into<String>) -> Self {
+        Self {
+            file: file.to_path_buf(),
+            byte_start: start,
+            byte_end: end,
+            replacement: text.into(),
+        }
+    }
+
+    /// Whether applying both edits would touch the same text, or insert
+    /// at the same point in an undefined order
+    fn conflicts_with(&self, other: &TextEdit) -> bool {
+        self.file == other.file
+            && ((self.byte_start < other.byte_end && other.byte_start < self.byte_end)
+                || self.byte_start == other.byte_start)
+    }
+}
+
+/// A violation and the edits accepted to fix it
+#[derive(Debug, Clone)]
+pub struct PlannedFix {
+    /// Violation the edits fix
+    pub violation: Violation,
+    /// Edits that together fix the violation
+    pub edits: Vec<TextEdit>,
+}
+
+/// Edits accepted for a fix run, and the violations that were not fixed
+#[derive(Debug, Default)]
+pub struct EditPlan {
+    /// Accepted fixes, in the order they were planned
+    pub fixed: Vec<PlannedFix>,
+    /// Violations whose edits overlap edits accepted for an earlier one
+    pub conflicts: Vec<Violation>,
+    /// Violations that need manual attention, with the reason
+    pub manual: Vec<(Violation, String)>,
+}
+
+impl EditPlan {
+    /// Accept all of `edits` for `violation`, or none of them when one
+    /// conflicts with an edit already accepted
+    ///
+    /// Edits identical to an accepted one are not conflicts; they are
+    /// applied once, so two violations may share a fix.
+    pub fn add(&mut self, violation: Violation, edits: Vec<TextEdit>) -> bool {
+        let conflicts = edits.iter().any(|edit| {
+            self.fixed
+                .iter()
+                .flat_map(|fix| &fix.edits)
+                .any(|taken| taken != edit && taken.conflicts_with(edit))
+        });
+        if conflicts {
+            self.conflicts.push(violation);
+            return false;
+        }
+
+        self.fixed.push(PlannedFix { violation, edits });
+        true
+    }
+
+    /// Record that `violation` needs manual attention
+    pub fn skip(&mut self, violation: Violation, reason: impl Into<String>) {
+        self.manual.push((violation, reason.into()));
+    }
+
+    /// Accepted edits grouped by file, sorted by position
+    pub fn files(&self) -> BTreeMap<&PathBuf, Vec<&TextEdit>> {
+        edits_by_file(&self.fixed)
+    }
+}
+
+/// Edits of `fixes` grouped by file, sorted by position, with edits shared
+/// by several fixes listed once
+pub fn edits_by_file<'a>(
+    fixes: impl IntoIterator<Item = &'a PlannedFix>,
+) -> BTreeMap<&'a PathBuf, Vec<&'a TextEdit>> {
+    let mut files: BTreeMap<&PathBuf, Vec<&TextEdit>> = BTreeMap::new();
+    for edit in fixes.into_iter().flat_map(|fix| &fix.edits) {
+        let file_edits = files.entry(&edit.file).or_default();
+        if !file_edits.contains(&edit) {
+            file_edits.push(edit);
+        }
+    }
+    for edits in files.values_mut() {
+        edits.sort_by_key(|e| (e.byte_start, e.byte_end));
+    }
+    files
+}
+
+/// Apply sorted, non-overlapping edits to `source`
+///
+/// Returns `None` when an edit does not fit the source, which means the
+/// file changed since the edits were planned.
+pub fn apply_edits(source: &str, edits: &[&TextEdit]) -> Option<String> {
+    let mut fixed = String::with_capacity(source.len());
+    let mut cursor = 0;
+    for edit in edits {
+        if edit.byte_start < cursor || edit.byte_end < edit.byte_start {
+            return None;
+        }
+        fixed.push_str(source.get(cursor..edit.byte_start)?);
+        fixed.push_str(&edit.replacement);
+        cursor = edit.byte_end;
+    }
+    fixed.push_str(source.get(cursor..)?);
+    Some(fixed)
+}
+
+/// Byte offset where each line of `source` starts, plus the source length
+///
+/// Entry `i` is the start of 0-based line `i`; the last entry lets a range
+/// of lines end at the end of the file.
+pub fn line_offsets(source: &str) -> Vec<usize> {
+    let mut offsets = vec![0];
+    offsets.extend(source.match_indices('\n').map(|(i, _)| i + 1));
+    if offsets.last() != Some(&source.len()) {
+        offsets.push(source.len());
+    }
+    offsets
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::validation::{Severity, ViolationType};
+
+    fn violation(line: usize) -> Violation {
+        Violation::new(
+            ViolationType::HardcodedVersion,
+            PathBuf::from("src/lib.rs"),
+            line,
+            String::new(),
+            Severity::Error,
+        )
+    }
+
+    #[test]
+    fn test_overlapping_edits_conflict() {
+        let file = Path::new("src/lib.rs");
+        let mut plan = EditPlan::default();
+        assert!(plan.add(violation(1), vec![TextEdit::replace(file, 0, 5, "a")]));
+        assert!(!plan.add(violation(2), vec![TextEdit::replace(file, 3, 8, "b")]));
+        assert!(!plan.add(violation(3), vec![TextEdit::insert(file, 0, "c")]));
+        assert!(plan.add(violation(4), vec![TextEdit::insert(file, 5, "d")]));
+        // The same edit proposed twice is applied once
+        assert!(plan.add(violation(5), vec![TextEdit::replace(file, 0, 5, "a")]));
+
+        assert_eq!(plan.fixed.len(), 3);
+        assert_eq!(plan.conflicts.len(), 2);
+        let files = plan.files();
+        let edits = files.get(&file.to_path_buf()).unwrap();
+        assert_eq!(apply_edits("hello world", edits).unwrap(), "ad world");
+    }
+
+    #[test]
+    fn test_apply_edits_rejects_stale_ranges() {
+        let edit = TextEdit::replace(Path::new("a.rs"), 4, 20, "x");
+        assert_eq!(apply_edits("short", &[&edit]), None);
+    }
+
+    #[test]
+    fn test_line_offsets() {
+        assert_eq!(line_offsets("a\nbc\n"), vec![0, 2, 5]);
+        assert_eq!(line_offsets("a\nbc"), vec![0, 2, 4]);
+    }
+}
@@ -0,0 +1,114 @@
+//! Turn violations into a conflict-free plan of text edits
+
+use super::changelog::fix_missing_changelog_entry;
+use super::edits::{EditPlan, Proposal};
+use super::manifest_fixes::{fix_missing_doc_config, fix_package_field};
+use super::source_fixes::{fix_hardcoded_version, fix_line, fix_missing_module_doc};
+use crate::config::HierarchicalLockManager;
+use crate::validation::{RustValidator, VersionConsistencyValidator, Violation, ViolationType};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Plans fixes against the files as they are on disk
+pub struct FixEngine<'a> {
+    validator: &'a RustValidator,
+    /// Locks that keep the engine from editing a setting itself
+    locks: Option<HierarchicalLockManager>,
+    /// File contents read so far; missing files are empty
+    sources: HashMap<PathBuf, Result<String, String>>,
+}
+
+impl<'a> FixEngine<'a> {
+    /// Create an engine using the configuration and workspace of
+    /// `validator`
+    pub async fn new(validator: &'a RustValidator) -> Self {
+        Self {
+            validator,
+            locks: HierarchicalLockManager::load().await.ok(),
+            sources: HashMap::new(),
+        }
+    }
+
+    /// Propose edits for every violation, accepting them in file and line
+    /// order and setting aside the ones that conflict
+    pub fn plan(&mut self, mut violations: Vec<Violation>) -> EditPlan {
+        violations.sort_by(|a, b| (&a.file, a.line, a.column).cmp(&(&b.file, b.line, b.column)));
+
+        let mut plan = EditPlan::default();
+        for violation in violations {
+            match self.propose(&violation) {
+                Ok(edits) if !edits.is_empty() => {
+                    plan.add(violation, edits);
+                }
+                Ok(_) => plan.skip(violation, "Nothing to change"),
+                Err(reason) => plan.skip(violation, reason),
+            }
+        }
+        plan
+    }
+
+    /// Content of `file` the plan was made against
+    pub fn source(&self, file: &Path) -> Option<&str> {
+        self.sources.get(file)?.as_deref().ok()
+    }
+
+    fn propose(&mut self, violation: &Violation) -> Proposal {
+        self.load(&violation.file);
+        let source = match self.sources.get(&violation.file) {
+            Some(Ok(source)) => source.as_str(),
+            Some(Err(reason)) => return Err(reason.clone()),
+            None => "",
+        };
+        let config = self.validator.config_for(&violation.file);
+
+        match violation.violation_type {
+            ViolationType::UnwrapInProduction | ViolationType::UnderscoreBandaid => {
+                fix_line(violation, source)
+            }
+            ViolationType::MissingModuleDoc => fix_missing_module_doc(violation, source),
+            ViolationType::HardcodedVersion => fix_hardcoded_version(violation, source),
+            ViolationType::MissingDocConfig => fix_missing_doc_config(violation, source),
+            ViolationType::WrongEdition => fix_package_field(
+                violation,
+                source,
+                "edition",
+                ("required_edition", &config.required_edition),
+                self.locks.as_ref(),
+            ),
+            ViolationType::OldRustVersion => fix_package_field(
+                violation,
+                source,
+                "rust-version",
+                ("required_rust_version", &config.required_rust_version),
+                self.locks.as_ref(),
+            ),
+            ViolationType::MissingChangelogEntry => {
+                let root = violation.file.parent().unwrap_or(Path::new("."));
+                let version = VersionConsistencyValidator::new(root.to_path_buf(), config.clone())
+                    .map_err(|e| e.to_string())?
+                    .source_version()
+                    .to_string();
+                let sections = config
+                    .validation
+                    .changelog_required_sections
+                    .clone()
+                    .unwrap_or_default();
+                let date = chrono::Local::now().format("%Y-%m-%d").to_string();
+                fix_missing_changelog_entry(violation, source, &version, &sections, &date)
+            }
+            _ => Err("No automatic fix for this rule".to_string()),
+        }
+    }
+
+    /// Read `file` once; a file that does not exist reads as empty
+    fn load(&mut self, file: &Path) {
+        self.sources
+            .entry(file.to_path_buf())
+            .or_insert_with(|| match fs::read_to_string(file) {
+                Ok(content) => Ok(content),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
+                Err(e) => Err(format!("Cannot read {}: {}", file.display(), e)),
+            });
+    }
+}
@@ -1,13 +1,15 @@
 //! Fix command execution logic
 
-use super::file_processing::process_all_files;
-use super::types::{FilterOptions, FixStats};
-use super::utils::{filter_violations, group_violations_by_file};
+use super::clippy::{fix_clippy_suggestions, wants_clippy_fixes};
+use super::engine::FixEngine;
+use super::file_processing::{preview_all_files, process_all_files};
+use super::transaction::FixTransaction;
+use super::types::{FilterOptions, FixStats, Verification};
+use super::utils::filter_violations;
 use crate::Result;
 use crate::ai_analyzer;
-use crate::validation::{RustValidator, Violation};
+use crate::validation::{RustValidator, Violation, ViolationType};
 use console::style;
-use std::collections::HashSet;
 use std::path::Path;
 
 /// Execute the main fix process
@@ -16,54 +18,140 @@ pub async fn execute_fix_process(
     dry_run: bool,
     filter_options: FilterOptions,
     ai_mode: bool,
+    verification: Verification,
 ) -> Result<()> {
     // Validate project and get violations
-    let violations = validate_project(_project_path).await?;
+    let validator = load_validator(_project_path).await?;
+    if !dry_run {
+        recover_interrupted_run(validator.project_root())?;
+    }
+    let violations = validator.validate_project().await?;
+
+    // Custom rules are never auto-fixed, but the user should still see them
+    print_custom_rule_violations(&violations);
 
     // Filter violations based on user preferences
-    let filtered_violations =
-        validate_and_filter_violations(violations, &filter_options, _project_path).await?;
+    let filtered_violations = validate_and_filter_violations(violations, &filter_options);
 
+    let mut stats = FixStats::default();
+    let mut fixes_ran = false;
     if filtered_violations.is_empty() {
         println!("✅ No violations found that can be auto-fixed!");
-        return Ok(());
+    } else {
+        stats = fix_violations(
+            &validator,
+            filtered_violations,
+            dry_run,
+            ai_mode,
+            verification,
+        )
+        .await?;
+        fixes_ran = true;
     }
 
+    // Clippy runs last so its byte offsets match the files as left by the
+    // edits above
+    if wants_clippy_fixes(&filter_options) {
+        println!();
+        stats.merge(fix_clippy_suggestions(&validator, dry_run, verification).await?);
+        fixes_ran = true;
+    }
+
+    if fixes_ran {
+        print_final_summary(stats, dry_run);
+    }
+    Ok(())
+}
+
+/// Plan edits for the filtered violations and apply them
+async fn fix_violations(
+    validator: &RustValidator,
+    filtered_violations: Vec<Violation>,
+    dry_run: bool,
+    ai_mode: bool,
+    verification: Verification,
+) -> Result<FixStats> {
+    let project_path = validator.project_root();
     print_violations_summary(&filtered_violations);
 
     // Run AI analysis if requested
     if ai_mode {
-        run_ai_analysis(_project_path, &filtered_violations).await;
+        run_ai_analysis(project_path, &filtered_violations).await;
     }
 
     if !dry_run {
         print_warning_banner();
     }
 
-    // Group violations by file for processing
-    let violations_by_file = group_violations_by_file(&filtered_violations);
-
-    // Process all files
-    let stats = process_all_files(violations_by_file, dry_run);
+    // Every edit is planned against the original files, so overlapping
+    // fixes are caught before anything is written
+    let mut engine = FixEngine::new(validator).await;
+    let plan = engine.plan(filtered_violations);
+    let stats = if dry_run {
+        preview_all_files(&plan, |file| engine.source(file), project_path)
+    } else {
+        process_all_files(&plan, project_path, verification).await?
+    };
 
-    print_final_summary(stats, dry_run);
+    Ok(stats)
+}
 
+/// Undo the edits of a fix run that was killed before it finished
+fn recover_interrupted_run(project_path: &Path) -> Result<()> {
+    let restored = FixTransaction::recover(project_path)?;
+    if restored > 0 {
+        println!(
+            "{}",
+            style(format!(
+                "↩️  Restored {} files left half-fixed by an interrupted fix run",
+                restored
+            ))
+            .yellow()
+        );
+    }
     Ok(())
 }
 
-/// Validate project and return violations
-async fn validate_project(project_path: &Path) -> Result<Vec<Violation>> {
-    let validator = RustValidator::new(project_path.to_path_buf())?;
-    validator.validate_project().await
+/// Create a validator with the user's and workspace members' configuration
+async fn load_validator(project_path: &Path) -> Result<RustValidator> {
+    RustValidator::for_project(project_path.to_path_buf()).await
 }
 
-/// Validate and filter violations based on fix capabilities
-async fn validate_and_filter_violations(
+/// Print custom rule violations that need manual attention
+fn print_custom_rule_violations(violations: &[Violation]) {
+    let custom: Vec<&Violation> = violations
+        .iter()
+        .filter(|v| matches!(v.violation_type, ViolationType::CustomRule { .. }))
+        .collect();
+
+    if custom.is_empty() {
+        return;
+    }
+
+    println!();
+    println!(
+        "{}",
+        style(format!(
+            "📐 {} custom rule violations require manual fixes:",
+            custom.len()
+        ))
+        .yellow()
+    );
+    for violation in custom {
+        println!(
+            "   • {}:{} - {}",
+            violation.file.display(),
+            violation.line,
+            violation.message
+        );
+    }
+}
+
+/// Keep the violations the fix engine handles and the filters select
+fn validate_and_filter_violations(
     violations: Vec<Violation>,
     filter_options: &FilterOptions,
-    _project_path: &Path,
-) -> Result<Vec<Violation>> {
-    // Filter violations that we can potentially fix
+) -> Vec<Violation> {
     let fixable_violations = filter_violations(
         &violations,
         &filter_options.only_types,
@@ -73,27 +161,9 @@ async fn validate_and_filter_violations(
 
     if fixable_violations.is_empty() {
         println!("ℹ️  No fixable violations found with current filters.");
-        return Ok(vec![]);
-    }
-
-    // For now, we only support specific violation types
-    let supported_types =
-        HashSet::from(["LINETOOLONG", "UNDERSCORE_BANDAID", "UNWRAP_IN_PRODUCTION"]);
-
-    let filtered: Vec<_> = fixable_violations
-        .into_iter()
-        .filter(|v| {
-            let violation_str = format!("{:?}", v.violation_type).to_uppercase();
-            supported_types.contains(violation_str.as_str())
-        })
-        .collect();
-
-    if filtered.is_empty() {
-        println!("ℹ️  No violations of supported types found for auto-fixing.");
-        println!("    Supported types: LINETOOLONG, UNDERSCORE_BANDAID, UNWRAP_IN_PRODUCTION");
     }
 
-    Ok(filtered)
+    fixable_violations
 }
 
 /// Print summary of violations to be fixed
@@ -165,6 +235,9 @@ fn print_final_summary(stats: FixStats, dry_run: bool) {
     } else {
         println!("   {} violations fixed", stats.total_fixed);
         println!("   {} violations skipped", stats.total_skipped);
+        if stats.total_reverted > 0 {
+            println!("   {} fixes reverted", stats.total_reverted);
+        }
         println!("   {} files modified", stats.files_modified);
 
         if stats.total_fixed > 0 {
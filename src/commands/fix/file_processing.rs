@@ -1,148 +1,175 @@
 //! File processing functionality for fix command
 
-use super::types::FixStats;
+use super::diff::{print_diff, unified_diff};
+use super::edits::{EditPlan, apply_edits};
+use super::transaction::{FixTransaction, TransactionReport};
+use super::types::{FixStats, Verification};
+use crate::Result;
 use crate::validation::Violation;
-use crate::{Error, Result};
-use std::collections::HashMap;
-use std::fs;
+use console::style;
+use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
 
-/// Process all files and return fix statistics
-pub fn process_all_files(
-    violations_by_file: HashMap<PathBuf, Vec<Violation>>,
-    dry_run: bool,
+/// Print the accepted edits of `plan` as a diff per file and return fix
+/// statistics
+///
+/// `source` gives the content each file had when the plan was made.
+pub fn preview_all_files<'s>(
+    plan: &EditPlan,
+    source: impl Fn(&Path) -> Option<&'s str>,
+    project_root: &Path,
 ) -> FixStats {
     let mut stats = FixStats {
         total_fixed: 0,
-        total_skipped: 0,
+        total_skipped: plan.manual.len() + plan.conflicts.len(),
+        total_reverted: 0,
         files_modified: 0,
     };
 
-    for (file_path, file_violations) in violations_by_file {
-        process_single_file(&file_path, &file_violations, dry_run, &mut stats);
+    for (file_path, edits) in plan.files() {
+        let fixed_count = fixes_in(plan.fixed.iter().map(|f| &f.violation), file_path);
+        let display = file_path.strip_prefix(project_root).unwrap_or(file_path);
+        let original = source(file_path).unwrap_or_default();
+        println!("🔧 Processing: {}", display.display());
+
+        match apply_edits(original, &edits) {
+            Some(fixed) => {
+                print_diff(&unified_diff(&display.to_string_lossy(), original, &fixed));
+                println!("   ✅ Would fix {} violations", fixed_count);
+                stats.files_modified += 1;
+                stats.total_fixed += fixed_count;
+            }
+            None => {
+                eprintln!("   ❌ Failed to fix file: file changed while fixes were planned");
+                stats.total_skipped += fixed_count;
+            }
+        }
     }
 
+    print_unfixed(plan, project_root);
     stats
 }
 
-/// Process a single file's violations
-fn process_single_file(
-    file_path: &Path,
-    file_violations: &[Violation],
-    dry_run: bool,
-    stats: &mut FixStats,
-) {
-    println!("🔧 Processing: {}", file_path.display());
-
-    match fix_file_violations(file_path, file_violations, dry_run) {
-        Ok(fixed_count) if fixed_count > 0 => {
-            handle_successful_fix(file_path, fixed_count, dry_run, stats);
-        }
-        Ok(_) => {
-            println!("   ⚠️  No fixes applied");
-            stats.total_skipped += file_violations.len();
-        }
-        Err(e) => {
-            eprintln!("   ❌ Failed to fix file: {}", e);
-            stats.total_skipped += file_violations.len();
-        }
+/// Apply the accepted edits of `plan`, reverting fixes that break the
+/// build, and return fix statistics
+///
+/// # Errors
+///
+/// Returns an error if a file cannot be read or written, or cargo cannot
+/// be run; touched files are restored first.
+pub async fn process_all_files(
+    plan: &EditPlan,
+    project_root: &Path,
+    verification: Verification,
+) -> Result<FixStats> {
+    if verification != Verification::Off {
+        println!("🔨 Applying fixes in batches and checking that the project still builds...");
     }
-}
-
-/// Handle successful fix results
-fn handle_successful_fix(
-    _file_path: &Path,
-    fixed_count: usize,
-    dry_run: bool,
-    stats: &mut FixStats,
-) {
-    if dry_run {
-        println!("   ✅ Would fix {} violations", fixed_count);
-    } else {
-        println!("   ✅ Fixed {} violations", fixed_count);
-        stats.files_modified += 1;
+    let transaction = FixTransaction::begin(project_root, plan, verification)?;
+    let report = transaction.commit(plan).await?;
+
+    let files: BTreeSet<&PathBuf> = report.kept.iter().map(|v| &v.file).collect();
+    for file_path in files {
+        let display = file_path.strip_prefix(project_root).unwrap_or(file_path);
+        println!("🔧 Processing: {}", display.display());
+        println!(
+            "   ✅ Fixed {} violations",
+            fixes_in(report.kept.iter(), file_path)
+        );
     }
-    stats.total_fixed += fixed_count;
+    print_reverted(&report, project_root);
+    print_unfixed(plan, project_root);
+
+    Ok(FixStats {
+        total_fixed: report.kept.len(),
+        total_skipped: plan.manual.len() + plan.conflicts.len(),
+        total_reverted: report.reverted.len(),
+        files_modified: report.files_modified,
+    })
 }
 
-/// Fix violations in a specific file
-fn fix_file_violations(file_path: &Path, violations: &[Violation], dry_run: bool) -> Result<usize> {
-    let content = fs::read_to_string(file_path).map_err(|e| {
-        Error::validation(format!(
-            "Failed to read file {}: {}",
-            file_path.display(),
-            e
-        ))
-    })?;
-
-    let mut lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
-    let preserve_trailing_newline = content.ends_with('\n');
-
-    let fixed_count = apply_fixes_to_lines(&mut lines, violations);
+/// Number of `violations` in `file`
+fn fixes_in<'a>(violations: impl Iterator<Item = &'a Violation>, file: &Path) -> usize {
+    violations.filter(|v| v.file == file).count()
+}
 
-    if fixed_count > 0 && !dry_run {
-        write_fixed_content(file_path, &lines, preserve_trailing_newline)?;
+/// Print fixes undone because they broke the build, or why no fix was
+/// verified
+fn print_reverted(report: &TransactionReport, project_root: &Path) {
+    if let Some(error) = &report.unverified {
+        println!("   {}", style(error).dim());
+    }
+    if report.reverted.is_empty() {
+        return;
     }
 
-    Ok(fixed_count)
+    println!();
+    println!(
+        "{}",
+        style(format!(
+            "↩️  {} fixes were reverted because they broke the build or tests:",
+            report.reverted.len()
+        ))
+        .yellow()
+    );
+    for (violation, error) in &report.reverted {
+        let file = violation
+            .file
+            .strip_prefix(project_root)
+            .unwrap_or(&violation.file);
+        println!(
+            "   • {}:{} [{}] - {}",
+            file.display(),
+            violation.line,
+            violation.violation_type.rule_name(),
+            error
+        );
+    }
 }
 
-/// Apply fixes to the lines of code
-fn apply_fixes_to_lines(lines: &mut [String], violations: &[Violation]) -> usize {
-    let mut fixed_count = 0;
-
-    // Sort violations by line number in descending order to avoid offset issues
-    let mut sorted_violations = violations.to_vec();
-    sorted_violations.sort_by_key(|b| std::cmp::Reverse(b.line));
-
-    for violation in sorted_violations {
-        if violation.line > 0 && violation.line <= lines.len() {
-            let line_index = violation.line - 1;
-            let original_line = &lines[line_index];
+/// Print violations left for the user, and fixes deferred by a conflict
+fn print_unfixed(plan: &EditPlan, project_root: &Path) {
+    let location = |file: &Path, line: usize| {
+        let file = file.strip_prefix(project_root).unwrap_or(file);
+        format!("{}:{}", file.display(), line)
+    };
 
-            // For now, use a simple fix approach
-            if let Some(fixed_line) = try_simple_fix(original_line, &violation)
-                && fixed_line != *original_line
-            {
-                lines[line_index] = fixed_line;
-                fixed_count += 1;
-            }
+    if !plan.manual.is_empty() {
+        println!();
+        println!(
+            "{}",
+            style(format!(
+                "📝 {} violations need manual attention:",
+                plan.manual.len()
+            ))
+            .yellow()
+        );
+        for (violation, reason) in &plan.manual {
+            println!(
+                "   • {} [{}] - {}",
+                location(&violation.file, violation.line),
+                violation.violation_type.rule_name(),
+                reason
+            );
         }
     }
 
-    fixed_count
-}
-
-/// Simple fix attempt for basic violations
-fn try_simple_fix(_line: &str, violation: &Violation) -> Option<String> {
-    match violation.violation_type {
-        crate::validation::ViolationType::LineTooLong => {
-            // For now, don't auto-fix line too long
-            None
+    if !plan.conflicts.is_empty() {
+        println!();
+        println!(
+            "{}",
+            style(format!(
+                "⚠️  {} fixes overlap an earlier fix; run fix again to apply them:",
+                plan.conflicts.len()
+            ))
+            .yellow()
+        );
+        for violation in &plan.conflicts {
+            println!(
+                "   • {} [{}]",
+                location(&violation.file, violation.line),
+                violation.violation_type.rule_name()
+            );
         }
-        _ => None,
     }
 }
-
-/// Write the fixed content back to the file
-fn write_fixed_content(
-    file_path: &Path,
-    lines: &[String],
-    preserve_trailing_newline: bool,
-) -> Result<()> {
-    let new_content = lines.join("\n");
-    let final_content = if preserve_trailing_newline {
-        format!("{}\n", new_content)
-    } else {
-        new_content
-    };
-
-    fs::write(file_path, final_content).map_err(|e| {
-        Error::validation(format!(
-            "Failed to write file {}: {}",
-            file_path.display(),
-            e
-        ))
-    })?;
-    Ok(())
-}
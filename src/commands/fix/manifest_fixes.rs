@@ -0,0 +1,160 @@
+//! Edits for violations in Cargo.toml
+
+use super::edits::{Proposal, TextEdit, line_offsets};
+use crate::config::locking::HierarchicalLockManager;
+use crate::validation::Violation;
+
+/// The rustdoc lints `ferrous-forge init --project` configures
+const RUSTDOC_LINTS: &str = "[lints.rustdoc]
+broken_intra_doc_links = \"deny\"
+invalid_html_tags = \"deny\"
+missing_crate_level_docs = \"warn\"
+bare_urls = \"warn\"
+redundant_explicit_links = \"warn\"
+unescaped_backticks = \"warn\"
+";
+
+/// Append the `[lints.rustdoc]` table to a manifest
+pub fn fix_missing_doc_config(violation: &Violation, source: &str) -> Proposal {
+    let manifest: toml::Value = toml::from_str(source).map_err(|e| e.to_string())?;
+    if manifest.get("package").is_none() {
+        return Err(
+            "Virtual manifest: add [workspace.lints.rustdoc] and `lints.workspace = true` \
+             to the members"
+                .to_string(),
+        );
+    }
+
+    let separator = match source {
+        "" => "",
+        s if s.ends_with("\n\n") => "",
+        s if s.ends_with('\n') => "\n",
+        _ => "\n\n",
+    };
+    Ok(vec![TextEdit::insert(
+        &violation.file,
+        source.len(),
+        format!("{}{}", separator, RUSTDOC_LINTS),
+    )])
+}
+
+/// Set a `[package]` field to the value the configuration requires
+///
+/// `key` is the manifest field and `lock_key` the configuration key whose
+/// lock means a person has to make the change.
+pub fn fix_package_field(
+    violation: &Violation,
+    source: &str,
+    key: &str,
+    (lock_key, required): (&str, &str),
+    locks: Option<&HierarchicalLockManager>,
+) -> Proposal {
+    if !violation.file.ends_with("Cargo.toml") {
+        return Err("Update the installed Rust toolchain".to_string());
+    }
+    if let Some((level, _)) = locks.and_then(|l| l.is_locked(lock_key)) {
+        return Err(format!(
+            "`{}` is locked at {} level; update `{}` by hand",
+            lock_key,
+            level.display_name(),
+            key
+        ));
+    }
+    if required.is_empty() {
+        return Err(format!("No `{}` is configured", lock_key));
+    }
+
+    let offsets = line_offsets(source);
+    if violation.line == 0 {
+        // The field is missing: add it right below the [package] header
+        let header = source
+            .lines()
+            .position(|l| l.trim() == "[package]")
+            .ok_or("Manifest has no [package] table")?;
+        let at = offsets.get(header + 1).copied().unwrap_or(source.len());
+        let newline = if source[..at].ends_with('\n') {
+            ""
+        } else {
+            "\n"
+        };
+        let field = format!("{}{} = \"{}\"\n", newline, key, required);
+        return Ok(vec![TextEdit::insert(&violation.file, at, field)]);
+    }
+
+    let start = *offsets
+        .get(violation.line - 1)
+        .ok_or("Line no longer exists in the file")?;
+    let line = source[start..].lines().next().unwrap_or_default();
+    let value = line
+        .split_once('=')
+        .filter(|(name, _)| name.trim() == key)
+        .map(|(name, value)| (name.len() + 1, value))
+        .and_then(|(offset, value)| {
+            let open = value.find('"')?;
+            let close = value[open + 1..].find('"')?;
+            Some((offset + open + 1, offset + open + 1 + close))
+        })
+        .ok_or_else(|| format!("`{}` is not a plain string on this line", key))?;
+
+    Ok(vec![TextEdit::replace(
+        &violation.file,
+        start + value.0,
+        start + value.1,
+        required,
+    )])
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::commands::fix::edits::apply_edits;
+    use crate::validation::{Severity, ViolationType};
+    use std::path::PathBuf;
+
+    fn violation(violation_type: ViolationType, line: usize) -> Violation {
+        Violation::new(
+            violation_type,
+            PathBuf::from("Cargo.toml"),
+            line,
+            String::new(),
+            Severity::Error,
+        )
+    }
+
+    fn apply(source: &str, proposal: Proposal) -> String {
+        let edits = proposal.unwrap();
+        apply_edits(source, &edits.iter().collect::<Vec<_>>()).unwrap()
+    }
+
+    #[test]
+    fn test_fix_package_field() {
+        let manifest = "[package]\nname = \"demo\"\nedition = \"2021\"\n";
+        let v = violation(ViolationType::WrongEdition, 3);
+        let fixed = fix_package_field(&v, manifest, "edition", ("required_edition", "2024"), None);
+        assert_eq!(
+            apply(manifest, fixed),
+            "[package]\nname = \"demo\"\nedition = \"2024\"\n"
+        );
+
+        let manifest = "[package]\nname = \"demo\"\n";
+        let v = violation(ViolationType::WrongEdition, 0);
+        let fixed = fix_package_field(&v, manifest, "edition", ("required_edition", "2024"), None);
+        assert_eq!(
+            apply(manifest, fixed),
+            "[package]\nedition = \"2024\"\nname = \"demo\"\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_missing_doc_config() {
+        let manifest = "[package]\nname = \"demo\"\n";
+        let v = violation(ViolationType::MissingDocConfig, 0);
+        let fixed = apply(manifest, fix_missing_doc_config(&v, manifest));
+        assert!(fixed.starts_with("[package]\nname = \"demo\"\n\n[lints.rustdoc]\n"));
+        assert!(toml::from_str::<toml::Value>(&fixed).is_ok());
+
+        let virtual_manifest = "[workspace]\nmembers = [\"a\"]\n";
+        assert!(fix_missing_doc_config(&v, virtual_manifest).is_err());
+    }
+}
@@ -4,15 +4,25 @@
 //! This module implements intelligent auto-fixing for common Rust anti-patterns.
 //! It analyzes code context to ensure fixes are safe and won't break compilation.
 
+mod changelog;
+mod clippy;
 mod context;
+pub(crate) mod diff;
+mod edits;
+mod engine;
 mod execution;
 mod file_processing;
+mod manifest_fixes;
+mod source_fixes;
 mod strategies;
+mod transaction;
 mod types;
 mod utils;
 
 use execution::execute_fix_process;
-pub use types::{FileContext, FilterOptions, FixConfig, FixResult, FunctionSignature};
+pub use types::{
+    FileContext, FilterOptions, FixConfig, FixResult, FunctionSignature, Verification,
+};
 
 use crate::Result;
 use console::style;
@@ -48,13 +58,48 @@ pub async fn execute_with_ai(
     dry_run: bool,
     _limit: Option<usize>,
     ai_analysis: bool,
+) -> Result<()> {
+    execute_with_verification(
+        path,
+        only,
+        skip,
+        dry_run,
+        _limit,
+        ai_analysis,
+        Verification::default(),
+    )
+    .await
+}
+
+/// Execute the fix command, checking after each batch of edits that the
+/// project still builds as `verification` asks
+///
+/// # Errors
+///
+/// Returns an error if the fix process encounters an I/O error or cargo
+/// cannot be run to verify the fixes.
+pub async fn execute_with_verification(
+    path: Option<PathBuf>,
+    only: Option<String>,
+    skip: Option<String>,
+    dry_run: bool,
+    _limit: Option<usize>,
+    ai_analysis: bool,
+    verification: Verification,
 ) -> Result<()> {
     let project_path = path.unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
 
     print_startup_banner(&project_path, dry_run);
 
     let filter_options = parse_filter_options(only, skip);
-    execute_fix_process(&project_path, dry_run, filter_options, ai_analysis).await
+    execute_fix_process(
+        &project_path,
+        dry_run,
+        filter_options,
+        ai_analysis,
+        verification,
+    )
+    .await
 }
 
 /// Print startup banner with project information
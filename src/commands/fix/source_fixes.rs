@@ -0,0 +1,185 @@
+//! Edits for violations in Rust source files
+
+use super::context::analyze_file_context;
+use super::edits::{Proposal, TextEdit, line_offsets};
+use super::strategies::fix_violation_in_line;
+use super::types::FixResult;
+use crate::validation::Violation;
+use std::fs;
+use std::path::Path;
+
+/// Replacement for a hardcoded copy of the package version
+const PKG_VERSION: &str = r#"env!("CARGO_PKG_VERSION")"#;
+
+/// Byte range of 1-based `line` without its line ending
+fn line_span(source: &str, line: usize) -> Option<(usize, usize)> {
+    let offsets = line_offsets(source);
+    let start = *offsets.get(line.checked_sub(1)?)?;
+    let end = *offsets.get(line)?;
+    let text = source.get(start..end)?;
+    let text = text.strip_suffix('\n').unwrap_or(text);
+    let text = text.strip_suffix('\r').unwrap_or(text);
+    Some((start, start + text.len()))
+}
+
+/// Apply the line-based unwrap and underscore strategies
+pub fn fix_line(violation: &Violation, source: &str) -> Proposal {
+    let (start, end) =
+        line_span(source, violation.line).ok_or("Line no longer exists in the file")?;
+    let context = analyze_file_context(source);
+    match fix_violation_in_line(&source[start..end], violation, &context) {
+        // An emptied line is removed along with its line ending
+        FixResult::Fixed(line) if line.is_empty() => {
+            let offsets = line_offsets(source);
+            let next = offsets.get(violation.line).copied().unwrap_or(end);
+            Ok(vec![TextEdit::replace(&violation.file, start, next, "")])
+        }
+        FixResult::Fixed(line) => Ok(vec![TextEdit::replace(&violation.file, start, end, line)]),
+        FixResult::Skipped(reason) => Err(reason),
+        FixResult::NotApplicable => Err("No automatic fix for this pattern".to_string()),
+    }
+}
+
+/// Add a stub `//!` doc comment at the top of a module root
+pub fn fix_missing_module_doc(violation: &Violation, source: &str) -> Proposal {
+    let file = &violation.file;
+    let (name, kind) = match file.file_name().and_then(|n| n.to_str()) {
+        // src/lib.rs is documented as the crate
+        Some("lib.rs") => (package_name(file), "crate"),
+        _ => (
+            file.parent()
+                .and_then(Path::file_name)
+                .map(|n| n.to_string_lossy().into_owned()),
+            "module",
+        ),
+    };
+    let name = name
+        .map(|n| n.replace('-', "_"))
+        .ok_or("Cannot name the module from its path")?;
+
+    let separator = if source.starts_with('\n') { "" } else { "\n" };
+    let stub = format!("//! The `{}` {}.\n{}", name, kind, separator);
+    Ok(vec![TextEdit::insert(file, 0, stub)])
+}
+
+/// `[package].name` from the nearest `Cargo.toml` above `file`
+fn package_name(file: &Path) -> Option<String> {
+    let manifest = file
+        .ancestors()
+        .skip(1)
+        .map(|dir| dir.join("Cargo.toml"))
+        .find(|manifest| manifest.is_file())?;
+    let manifest: toml::Value = fs::read_to_string(manifest).ok()?.parse().ok()?;
+    manifest
+        .get("package")?
+        .get("name")?
+        .as_str()
+        .map(str::to_string)
+}
+
+/// Replace a quoted hardcoded version with `env!("CARGO_PKG_VERSION")`
+pub fn fix_hardcoded_version(violation: &Violation, source: &str) -> Proposal {
+    let (start, end) =
+        line_span(source, violation.line).ok_or("Line no longer exists in the file")?;
+    let line = &source[start..end];
+    let version_start = line
+        .char_indices()
+        .nth(violation.column.saturating_sub(1))
+        .map(|(i, _)| i)
+        .ok_or("Version is no longer on this line")?;
+    let version_len = line[version_start..]
+        .find(|c: char| !(c.is_ascii_alphanumeric() || ".-+".contains(c)))
+        .unwrap_or(line.len() - version_start);
+    let version_end = version_start + version_len;
+
+    let before = &line[..version_start];
+    let quoted = before.ends_with('"') && line[version_end..].starts_with('"');
+    // An even number of quotes up to the literal means its opening quote
+    // is escaped inside a larger string, e.g. embedded TOML
+    let embedded = before.matches('"').count().is_multiple_of(2);
+    let assigned = before[..before.len().saturating_sub(1)]
+        .trim_end()
+        .ends_with(['=', ':', '(', ',']);
+    if !quoted || embedded || !assigned {
+        return Err("Version is not a standalone string literal".to_string());
+    }
+
+    Ok(vec![TextEdit::replace(
+        &violation.file,
+        start + version_start - 1,
+        start + version_end + 1,
+        PKG_VERSION,
+    )])
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::commands::fix::edits::apply_edits;
+    use crate::validation::{Severity, ViolationType};
+    use std::path::PathBuf;
+
+    fn violation(violation_type: ViolationType, file: &str, line: usize) -> Violation {
+        Violation::new(
+            violation_type,
+            PathBuf::from(file),
+            line,
+            String::new(),
+            Severity::Error,
+        )
+    }
+
+    fn apply(source: &str, proposal: Proposal) -> String {
+        let edits = proposal.unwrap();
+        apply_edits(source, &edits.iter().collect::<Vec<_>>()).unwrap()
+    }
+
+    #[test]
+    fn test_fix_hardcoded_version() {
+        let source = "fn a() {}\nconst VERSION: &str = \"1.2.3\";\n";
+        let v = violation(ViolationType::HardcodedVersion, "src/lib.rs", 2).with_column(24);
+        assert_eq!(
+            apply(source, fix_hardcoded_version(&v, source)),
+            "fn a() {}\nconst VERSION: &str = env!(\"CARGO_PKG_VERSION\");\n"
+        );
+
+        let embedded = "let toml = \"version = \\\"1.2.3\\\"\";\n";
+        let v = violation(ViolationType::HardcodedVersion, "src/lib.rs", 1).with_column(25);
+        assert!(fix_hardcoded_version(&v, embedded).is_err());
+    }
+
+    #[test]
+    fn test_fix_missing_module_doc() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            "[package]\nname = \"my-crate\"\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+        let source = "pub mod api;\n";
+        let lib = dir.path().join("src/lib.rs");
+        let v = violation(ViolationType::MissingModuleDoc, &lib.to_string_lossy(), 1);
+        assert_eq!(
+            apply(source, fix_missing_module_doc(&v, source)),
+            "//! The `my_crate` crate.\n\npub mod api;\n"
+        );
+
+        let v = violation(ViolationType::MissingModuleDoc, "src/handlers/mod.rs", 1);
+        assert!(
+            apply(source, fix_missing_module_doc(&v, source))
+                .starts_with("//! The `handlers` module.\n")
+        );
+    }
+
+    #[test]
+    fn test_fix_missing_module_doc_with_relative_path() {
+        // The crate's own manifest is found from a path relative to it
+        let source = "pub mod api;\n";
+        let v = violation(ViolationType::MissingModuleDoc, "./src/lib.rs", 1);
+        assert!(
+            apply(source, fix_missing_module_doc(&v, source))
+                .starts_with("//! The `ferrous_forge` crate.\n")
+        );
+    }
+}
@@ -6,7 +6,6 @@ use super::types::{FileContext, FixResult};
 use crate::validation::{Violation, ViolationType};
 
 /// Fix a violation in a line of code
-#[allow(dead_code)]
 pub fn fix_violation_in_line(
     line: &str,
     violation: &Violation,
@@ -20,7 +19,6 @@ pub fn fix_violation_in_line(
 }
 
 /// Fix unwrap violations in a line
-#[allow(dead_code)]
 fn fix_unwrap_in_line(line: &str, violation: &Violation, context: &FileContext) -> FixResult {
     // Skip test files
     if context.is_test_file {
@@ -32,24 +30,23 @@ fn fix_unwrap_in_line(line: &str, violation: &Violation, context: &FileContext)
     }
 
     if line.contains(".unwrap()") {
-        fix_unwrap_call(line, context)
+        fix_unwrap_call(line, violation.line, context)
     } else if line.contains(".expect(") {
-        fix_expect_call(line, context)
+        fix_expect_call(line, violation.line, context)
     } else {
         FixResult::NotApplicable
     }
 }
 
 /// Fix `.unwrap()` calls in a line
-#[allow(dead_code)]
-fn fix_unwrap_call(line: &str, context: &FileContext) -> FixResult {
+fn fix_unwrap_call(line: &str, line_number: usize, context: &FileContext) -> FixResult {
     // Don't fix if it's in a string literal
     if line.contains(r#"".unwrap()""#) || line.contains(r#"'.unwrap()'"#) {
         return FixResult::Skipped("String literal, not actual code".into());
     }
 
     // Check if we're in a function that can use ?
-    let can_use_question_mark = check_can_use_question_mark(context);
+    let can_use_question_mark = check_can_use_question_mark(context, line_number);
 
     if can_use_question_mark {
         // Safe to replace with ?
@@ -68,10 +65,9 @@ fn fix_unwrap_call(line: &str, context: &FileContext) -> FixResult {
 }
 
 /// Fix `.expect()` calls in a line
-#[allow(dead_code)]
-fn fix_expect_call(line: &str, context: &FileContext) -> FixResult {
+fn fix_expect_call(line: &str, line_number: usize, context: &FileContext) -> FixResult {
     // For expect, we can potentially replace with ? if the context allows
-    if !check_can_use_question_mark(context) {
+    if !check_can_use_question_mark(context, line_number) {
         return FixResult::Skipped(
             "Cannot use ? operator - function doesn't return Result/Option".to_string(),
         );
@@ -90,7 +86,6 @@ fn fix_expect_call(line: &str, context: &FileContext) -> FixResult {
 }
 
 /// Replace `.expect()` call with `?` operator at the given position
-#[allow(dead_code)]
 fn replace_expect_with_question_mark(line: &str, start: usize) -> Option<String> {
     let before = &line[..start];
     let after_expect = &line[start + 8..];
@@ -102,7 +97,6 @@ fn replace_expect_with_question_mark(line: &str, start: usize) -> Option<String>
 }
 
 /// Find the matching closing parenthesis for an `.expect()` call
-#[allow(dead_code)]
 fn find_matching_paren(text: &str) -> Option<usize> {
     let mut paren_count = 1;
     let mut in_string = false;
@@ -139,7 +133,6 @@ fn find_matching_paren(text: &str) -> Option<usize> {
 }
 
 /// Fix underscore parameter violations
-#[allow(dead_code)]
 fn fix_underscore_in_line(line: &str, violation: &Violation, context: &FileContext) -> FixResult {
     // Skip test files
     if context.is_test_file {
@@ -175,6 +168,13 @@ fn fix_underscore_in_line(line: &str, violation: &Violation, context: &FileConte
 pub fn can_potentially_auto_fix(violation: &Violation) -> bool {
     matches!(
         violation.violation_type,
-        ViolationType::UnwrapInProduction | ViolationType::UnderscoreBandaid
+        ViolationType::UnwrapInProduction
+            | ViolationType::UnderscoreBandaid
+            | ViolationType::MissingModuleDoc
+            | ViolationType::MissingDocConfig
+            | ViolationType::WrongEdition
+            | ViolationType::OldRustVersion
+            | ViolationType::HardcodedVersion
+            | ViolationType::MissingChangelogEntry
     )
 }
@@ -0,0 +1,396 @@
+//! Apply planned fixes in batches, keeping only those that still build
+
+use super::edits::{EditPlan, PlannedFix, apply_edits, edits_by_file};
+use super::types::Verification;
+use crate::safety::checks::command;
+use crate::validation::Violation;
+use crate::{Error, Result};
+use std::collections::{BTreeMap, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tokio::process::Command;
+
+/// Snapshot of the touched files, kept on disk while fixes are applied so
+/// a run that is killed can be undone by the next one
+pub const JOURNAL_FILE: &str = ".ferrous-forge/fix-journal.json";
+
+/// Fixes kept and undone by a transaction
+#[derive(Debug, Default)]
+pub struct TransactionReport {
+    /// Fixes left in place
+    pub kept: Vec<Violation>,
+    /// Fixes undone, with the first error they caused
+    pub reverted: Vec<(Violation, String)>,
+    /// Files whose content changed
+    pub files_modified: usize,
+    /// Why the fixes could not be verified, when they were not
+    pub unverified: Option<String>,
+}
+
+/// Snapshot of the files a plan touches, restored if fixing fails
+pub struct FixTransaction<'a> {
+    project_root: &'a Path,
+    verification: Verification,
+    /// Content of every touched file before fixing; `None` if it did not
+    /// exist
+    originals: BTreeMap<PathBuf, Option<String>>,
+}
+
+impl<'a> FixTransaction<'a> {
+    /// Snapshot every file `plan` edits
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a touched file exists but cannot be read.
+    pub fn begin(
+        project_root: &'a Path,
+        plan: &EditPlan,
+        verification: Verification,
+    ) -> Result<Self> {
+        let mut originals = BTreeMap::new();
+        for file in plan.files().into_keys() {
+            let content = match fs::read_to_string(file) {
+                Ok(content) => Some(content),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+                Err(e) => {
+                    return Err(Error::io(format!(
+                        "Failed to read {}: {}",
+                        file.display(),
+                        e
+                    )));
+                }
+            };
+            originals.insert(file.clone(), content);
+        }
+        let transaction = Self {
+            project_root,
+            verification,
+            originals,
+        };
+        transaction.save_journal()?;
+        Ok(transaction)
+    }
+
+    /// Restore the files left behind by a run that did not finish, returning
+    /// how many there were
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the journal cannot be read or a file cannot be
+    /// restored.
+    pub fn recover(project_root: &'a Path) -> Result<usize> {
+        let journal = project_root.join(JOURNAL_FILE);
+        let content = match fs::read_to_string(&journal) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => {
+                return Err(Error::io(format!(
+                    "Failed to read {}: {}",
+                    journal.display(),
+                    e
+                )));
+            }
+        };
+        let originals: BTreeMap<PathBuf, Option<String>> = serde_json::from_str(&content)
+            .map_err(|e| Error::parse(format!("Invalid {}: {}", journal.display(), e)))?;
+        let transaction = Self {
+            project_root,
+            verification: Verification::Off,
+            originals,
+        };
+        transaction.restore()?;
+        transaction.finish()?;
+        Ok(transaction.originals.len())
+    }
+
+    /// Apply the fixes of `plan` one batch per rule, bisecting a batch
+    /// that breaks the build to find and revert the fixes responsible
+    ///
+    /// On error or Ctrl-C every touched file is restored from the snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file cannot be written, cargo cannot be run, or
+    /// the run is interrupted.
+    pub async fn commit(&self, plan: &EditPlan) -> Result<TransactionReport> {
+        let result = tokio::select! {
+            result = self.apply_batches(plan) => result,
+            _ = tokio::signal::ctrl_c() => {
+                Err(Error::process("Interrupted; fixed files were restored"))
+            }
+        };
+        if result.is_err() {
+            self.restore()?;
+        }
+        self.finish()?;
+        result
+    }
+
+    async fn apply_batches(&self, plan: &EditPlan) -> Result<TransactionReport> {
+        let mut report = TransactionReport::default();
+        let mut verify = self.verification != Verification::Off;
+        if verify && let Some(error) = self.build_error().await? {
+            println!("   ⚠️  Project does not build before fixing; fixes are not verified");
+            report.unverified = Some(error);
+            verify = false;
+        }
+
+        let mut kept: Vec<&PlannedFix> = Vec::new();
+        let mut pending: VecDeque<Vec<&PlannedFix>> = batches_by_rule(plan).into();
+        while let Some(batch) = pending.pop_front() {
+            self.write(kept.iter().chain(&batch).copied())?;
+            let error = if verify {
+                self.build_error().await?
+            } else {
+                None
+            };
+
+            match error {
+                None => kept.extend(batch),
+                Some(error) if batch.len() == 1 => {
+                    report
+                        .reverted
+                        .extend(batch.iter().map(|f| (f.violation.clone(), error.clone())));
+                }
+                Some(_) => {
+                    // Try each half on its own; the left half goes first so
+                    // fixes keep their planned order
+                    let (left, right) = batch.split_at(batch.len() / 2);
+                    pending.push_front(right.to_vec());
+                    pending.push_front(left.to_vec());
+                }
+            }
+        }
+
+        report.files_modified = self.write(kept.iter().copied())?;
+        report.kept = kept.into_iter().map(|f| f.violation.clone()).collect();
+        Ok(report)
+    }
+
+    /// Write every touched file as its snapshot plus `fixes`, returning
+    /// how many differ from the snapshot
+    fn write<'f>(&self, fixes: impl IntoIterator<Item = &'f PlannedFix>) -> Result<usize> {
+        let edits = edits_by_file(fixes);
+        let mut modified = 0;
+        for (file, original) in &self.originals {
+            let source = original.as_deref().unwrap_or_default();
+            let content = match edits.get(file) {
+                Some(file_edits) => apply_edits(source, file_edits).ok_or_else(|| {
+                    Error::validation(format!(
+                        "{} changed while fixes were planned",
+                        file.display()
+                    ))
+                })?,
+                None => source.to_string(),
+            };
+            if content != source {
+                modified += 1;
+            }
+            if original.is_none() && content.is_empty() {
+                remove_if_present(file)?;
+            } else if fs::read_to_string(file).ok().as_deref() != Some(content.as_str()) {
+                fs::write(file, content)
+                    .map_err(|e| Error::io(format!("Failed to write {}: {}", file.display(), e)))?;
+            }
+        }
+        Ok(modified)
+    }
+
+    /// Put every touched file back as it was before fixing
+    fn restore(&self) -> Result<()> {
+        self.write(std::iter::empty()).map(|_| ())
+    }
+
+    /// Write the snapshot to the journal before any file is changed
+    fn save_journal(&self) -> Result<()> {
+        let journal = self.project_root.join(JOURNAL_FILE);
+        if let Some(dir) = journal.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| Error::io(format!("Failed to create {}: {}", dir.display(), e)))?;
+        }
+        let content = serde_json::to_string(&self.originals)
+            .map_err(|e| Error::parse(format!("Failed to serialize fix journal: {}", e)))?;
+        fs::write(&journal, content)
+            .map_err(|e| Error::io(format!("Failed to write {}: {}", journal.display(), e)))
+    }
+
+    /// Drop the journal once the files are in their final state
+    fn finish(&self) -> Result<()> {
+        remove_if_present(&self.project_root.join(JOURNAL_FILE))
+    }
+
+    /// First error from `cargo check` (and `cargo test`), or `None` when
+    /// the project builds
+    async fn build_error(&self) -> Result<Option<String>> {
+        let mut runs = vec![vec!["check", "--all-targets", "--message-format", "short"]];
+        if self.verification == Verification::Test {
+            runs.push(vec!["test", "--quiet"]);
+        }
+
+        for args in runs {
+            // Stopped along with its children if the run is interrupted
+            let output = command::output(
+                Command::new("cargo")
+                    .args(&args)
+                    .current_dir(self.project_root)
+                    .env("CARGO_TERM_COLOR", "never"),
+            )
+            .await
+            .map_err(|e| Error::process(format!("Failed to run cargo {}: {}", args[0], e)))?;
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                return Ok(Some(first_error(&stderr, &stdout, args[0])));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Group fixes into batches of one rule each, in planned order
+fn batches_by_rule(plan: &EditPlan) -> Vec<Vec<&PlannedFix>> {
+    let mut batches: Vec<Vec<&PlannedFix>> = Vec::new();
+    for fix in &plan.fixed {
+        match batches
+            .iter_mut()
+            .find(|b| b[0].violation.violation_type == fix.violation.violation_type)
+        {
+            Some(batch) => batch.push(fix),
+            None => batches.push(vec![fix]),
+        }
+    }
+    batches
+}
+
+/// The line describing the first failure in cargo's output
+fn first_error(stderr: &str, stdout: &str, command: &str) -> String {
+    stderr
+        .lines()
+        .chain(stdout.lines())
+        .map(str::trim)
+        .find(|line| {
+            line.starts_with("error") || line.contains(": error") || line.ends_with("FAILED")
+        })
+        .map_or_else(|| format!("cargo {} failed", command), str::to_string)
+}
+
+fn remove_if_present(file: &Path) -> Result<()> {
+    match fs::remove_file(file) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(Error::io(format!(
+            "Failed to remove {}: {}",
+            file.display(),
+            e
+        ))),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::commands::fix::edits::TextEdit;
+    use crate::validation::{Severity, ViolationType};
+    use tempfile::TempDir;
+
+    fn fix(file: &Path, violation_type: ViolationType, at: usize, text: &str) -> PlannedFix {
+        PlannedFix {
+            violation: Violation::new(
+                violation_type,
+                file.to_path_buf(),
+                1,
+                String::new(),
+                Severity::Error,
+            ),
+            edits: vec![TextEdit::insert(file, at, text)],
+        }
+    }
+
+    #[test]
+    fn test_batches_by_rule_keep_planned_order() {
+        let file = Path::new("src/lib.rs");
+        let plan = EditPlan {
+            fixed: vec![
+                fix(file, ViolationType::HardcodedVersion, 0, "a"),
+                fix(file, ViolationType::MissingModuleDoc, 1, "b"),
+                fix(file, ViolationType::HardcodedVersion, 2, "c"),
+            ],
+            ..EditPlan::default()
+        };
+        let batches = batches_by_rule(&plan);
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].len(), 2);
+        assert_eq!(batches[1][0].edits[0].replacement, "b");
+    }
+
+    #[tokio::test]
+    async fn test_unverified_commit_writes_and_restore_undoes() {
+        let dir = TempDir::new().unwrap();
+        let existing = dir.path().join("lib.rs");
+        let created = dir.path().join("CHANGELOG.md");
+        fs::write(&existing, "fn a() {}\n").unwrap();
+        let plan = EditPlan {
+            fixed: vec![
+                fix(&existing, ViolationType::MissingModuleDoc, 0, "//! Docs.\n"),
+                fix(
+                    &created,
+                    ViolationType::MissingChangelogEntry,
+                    0,
+                    "# Changelog\n",
+                ),
+            ],
+            ..EditPlan::default()
+        };
+
+        let transaction = FixTransaction::begin(dir.path(), &plan, Verification::Off).unwrap();
+        assert!(dir.path().join(JOURNAL_FILE).exists());
+        let report = transaction.commit(&plan).await.unwrap();
+        assert!(!dir.path().join(JOURNAL_FILE).exists());
+        assert_eq!(report.kept.len(), 2);
+        assert_eq!(report.files_modified, 2);
+        assert_eq!(
+            fs::read_to_string(&existing).unwrap(),
+            "//! Docs.\nfn a() {}\n"
+        );
+
+        transaction.restore().unwrap();
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "fn a() {}\n");
+        assert!(!created.exists());
+    }
+
+    #[test]
+    fn test_recover_restores_an_interrupted_run() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("lib.rs");
+        fs::write(&file, "fn a() {}\n").unwrap();
+        let plan = EditPlan {
+            fixed: vec![fix(
+                &file,
+                ViolationType::MissingModuleDoc,
+                0,
+                "//! Docs.\n",
+            )],
+            ..EditPlan::default()
+        };
+
+        // A run killed after writing leaves the journal behind
+        let transaction = FixTransaction::begin(dir.path(), &plan, Verification::Off).unwrap();
+        transaction.write(&plan.fixed).unwrap();
+        drop(transaction);
+
+        assert_eq!(FixTransaction::recover(dir.path()).unwrap(), 1);
+        assert_eq!(fs::read_to_string(&file).unwrap(), "fn a() {}\n");
+        assert!(!dir.path().join(JOURNAL_FILE).exists());
+        assert_eq!(FixTransaction::recover(dir.path()).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_first_error() {
+        let stderr = "    Checking demo v0.1.0\nsrc/lib.rs:3:5: error[E0277]: the `?` operator can only be used in a function\nerror: could not compile `demo`\n";
+        assert_eq!(
+            first_error(stderr, "", "check"),
+            "src/lib.rs:3:5: error[E0277]: the `?` operator can only be used in a function"
+        );
+        assert_eq!(first_error("", "", "test"), "cargo test failed");
+    }
+}
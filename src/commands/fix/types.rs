@@ -63,9 +63,36 @@ pub struct FilterOptions {
 }
 
 /// Statistics for fix operations
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct FixStats {
+    /// Violations fixed (or that would be in dry-run mode)
     pub total_fixed: usize,
+    /// Violations left for manual attention
     pub total_skipped: usize,
+    /// Fixes undone because they broke the build or the tests
+    pub total_reverted: usize,
+    /// Files changed (or that would be in dry-run mode)
     pub files_modified: usize,
 }
+
+impl FixStats {
+    /// Add the counts of another fix pass
+    pub fn merge(&mut self, other: FixStats) {
+        self.total_fixed += other.total_fixed;
+        self.total_skipped += other.total_skipped;
+        self.total_reverted += other.total_reverted;
+        self.files_modified += other.files_modified;
+    }
+}
+
+/// How `fix` checks that applied edits keep the project building
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Verification {
+    /// Apply edits without building
+    Off,
+    /// Run `cargo check` after each batch of edits
+    #[default]
+    Check,
+    /// Run `cargo check` and `cargo test` after each batch of edits
+    Test,
+}
@@ -2,8 +2,7 @@
 
 use super::strategies::can_potentially_auto_fix;
 use crate::validation::Violation;
-use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
+use std::collections::HashSet;
 
 /// Filter violations based on user options
 pub fn filter_violations(
@@ -20,18 +19,22 @@ pub fn filter_violations(
                 return false;
             }
 
-            let violation_type_str = format!("{:?}", v.violation_type).to_uppercase();
+            // Types match by variant (`LINETOOLONG`) or rule name (`LINE_TOO_LONG`)
+            let names = [
+                format!("{:?}", v.violation_type).to_uppercase(),
+                v.violation_type.rule_name().to_uppercase(),
+            ];
 
             // Apply only filter
             if let Some(only) = only_types
-                && !only.contains(&violation_type_str)
+                && !names.iter().any(|name| only.contains(name))
             {
                 return false;
             }
 
             // Apply skip filter
             if let Some(skip) = skip_types
-                && skip.contains(&violation_type_str)
+                && names.iter().any(|name| skip.contains(name))
             {
                 return false;
             }
@@ -48,23 +51,3 @@ pub fn filter_violations(
 
     filtered
 }
-
-/// Group violations by file path
-pub fn group_violations_by_file(violations: &[Violation]) -> HashMap<PathBuf, Vec<Violation>> {
-    let mut grouped = HashMap::new();
-
-    for violation in violations {
-        grouped
-            .entry(violation.file.clone())
-            .or_insert_with(Vec::new)
-            .push(violation.clone());
-    }
-
-    // Sort violations in each file by line number (descending)
-    // so we fix from bottom to top and don't mess up line numbers
-    for violations in grouped.values_mut() {
-        violations.sort_by_key(|b| std::cmp::Reverse(b.line));
-    }
-
-    grouped
-}
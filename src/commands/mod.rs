@@ -49,11 +49,56 @@ pub enum Commands {
         /// Only check locked settings (edition, rust-version) — exits 1 if any locked violation
         #[arg(long)]
         locked_only: bool,
+        /// Also write the violations as a SARIF 2.1.0 log to this file
+        #[arg(long, value_name = "PATH")]
+        sarif: Option<std::path::PathBuf>,
+        /// Record all current violations in .ferrous-forge/baseline.json so
+        /// that only new violations fail later runs
+        #[arg(long, conflicts_with_all = ["locked_only", "ai_report", "changed_only"])]
+        write_baseline: bool,
+        /// List every `// ferrous-forge: allow(...)` suppression with its
+        /// reason and how many violations it allowed, then exit
+        #[arg(
+            long,
+            conflicts_with_all = ["locked_only", "ai_report", "sarif", "write_baseline"]
+        )]
+        list_suppressions: bool,
+        /// Only validate Rust files changed since the last commit (staged,
+        /// unstaged or untracked), as reported by git
+        #[arg(long)]
+        changed_only: bool,
+        /// Number of worker threads for file validation (default: the
+        /// `validation_jobs` setting, or one per CPU)
+        #[arg(short, long, value_name = "N")]
+        jobs: Option<usize>,
+        /// Show the slowest files and checks after validating
+        #[arg(long)]
+        timings: bool,
+        /// Validate only this workspace member (repeatable)
+        #[arg(short, long = "package", value_name = "NAME")]
+        package: Vec<String>,
+        /// Validate every member of the workspace containing the project
+        #[arg(long)]
+        workspace: bool,
+        /// Leave this member out of --workspace (repeatable)
+        #[arg(long, value_name = "NAME", requires = "workspace")]
+        exclude: Vec<String>,
     },
-    /// Rollback to a previous version
+    /// Rollback to a previously installed version
     Rollback {
         /// Version to rollback to
-        version: String,
+        #[arg(
+            id = "target_version",
+            value_name = "VERSION",
+            required_unless_present_any = ["previous", "list"]
+        )]
+        version: Option<String>,
+        /// Roll back to the most recently retained previous version
+        #[arg(long, conflicts_with = "target_version")]
+        previous: bool,
+        /// List the versions available for rollback
+        #[arg(long, conflicts_with_all = ["target_version", "previous"])]
+        list: bool,
     },
     /// Uninstall Ferrous Forge from the system
     Uninstall {
@@ -89,7 +134,8 @@ pub enum Commands {
     Fix {
         /// Path to the project to fix (defaults to current directory)
         path: Option<std::path::PathBuf>,
-        /// Only fix specific violation types (comma-separated)
+        /// Only fix specific violation types (comma-separated; `clippy`
+        /// selects clippy's machine-applicable suggestions)
         #[arg(long)]
         only: Option<String>,
         /// Skip specific violation types (comma-separated)
@@ -104,6 +150,12 @@ pub enum Commands {
         /// Enable AI-powered analysis for complex violations
         #[arg(long)]
         ai_analysis: bool,
+        /// Also run `cargo test` after each batch of fixes
+        #[arg(long, conflicts_with = "no_verify")]
+        test: bool,
+        /// Apply fixes without checking that the project still builds
+        #[arg(long)]
+        no_verify: bool,
     },
 }
 
@@ -225,6 +277,15 @@ pub enum EditionCommand {
         #[arg(default_value = "2024")]
         edition: String,
     },
+    /// Restore a project from an edition migration backup
+    Restore {
+        /// Project path
+        #[arg(default_value = ".")]
+        path: std::path::PathBuf,
+        /// Backup directory to restore (defaults to the most recent backup)
+        #[arg(long)]
+        backup: Option<std::path::PathBuf>,
+    },
 }
 
 /// Safety pipeline management subcommands
@@ -255,6 +316,20 @@ pub enum SafetyCommand {
         /// Show verbose output
         #[arg(short, long)]
         verbose: bool,
+        /// Also write failed check errors as a SARIF 2.1.0 log to this file
+        #[arg(long, value_name = "PATH")]
+        sarif: Option<std::path::PathBuf>,
+        /// Run only this check instead of the stage's list (repeatable;
+        /// e.g. clippy, custom:deny)
+        #[arg(long, value_name = "NAME")]
+        check: Vec<String>,
+        /// Run only the stage's user-defined checks
+        #[arg(long)]
+        custom_only: bool,
+        /// Check the staged contents (the git index) in a temporary
+        /// snapshot instead of the working tree
+        #[arg(long)]
+        staged: bool,
     },
     /// Test individual safety checks
     Test {
@@ -268,29 +343,56 @@ pub enum SafetyCommand {
     /// Requires explicit justification. Bypasses expire after 24 hours.
     Bypass {
         /// Pipeline stage to bypass (pre-commit, pre-push, publish)
-        #[arg(long, value_enum)]
-        stage: SafetyBypassStage,
+        #[arg(long, value_enum, required_unless_present_any = ["list", "revoke"])]
+        stage: Option<SafetyBypassStage>,
         /// Reason for bypass (required)
-        #[arg(long)]
-        reason: String,
+        #[arg(long, required_unless_present_any = ["list", "revoke"])]
+        reason: Option<String>,
         /// Bypass duration in hours (default: 24)
         #[arg(long, default_value = "24")]
         duration: u64,
         /// User creating the bypass (defaults to current user)
         #[arg(long)]
         user: Option<String>,
+        /// Project whose repository the bypass applies to
+        #[arg(long, default_value = ".")]
+        path: std::path::PathBuf,
+        /// Only bypass checks on the current branch
+        #[arg(long)]
+        branch: bool,
+        /// Only bypass checks while HEAD is the current commit
+        #[arg(long)]
+        commit: bool,
+        /// Use the bypass up on the first check it skips
+        #[arg(long)]
+        once: bool,
+        /// List active bypasses
+        #[arg(long, conflicts_with_all = ["stage", "reason", "revoke"])]
+        list: bool,
+        /// Revoke the active bypass with this id
+        #[arg(long, value_name = "ID", conflicts_with_all = ["stage", "reason"])]
+        revoke: Option<String>,
     },
     /// View bypass audit log
     Audit {
         /// Number of entries to show
         #[arg(short, long, default_value = "20")]
         limit: usize,
+        /// Check that no entry was modified or removed
+        #[arg(long)]
+        verify: bool,
+        /// Verify the log, then write it to this file as JSON lines
+        #[arg(long, value_name = "FILE")]
+        export: Option<std::path::PathBuf>,
     },
     /// Check if a bypass is active (used by git hooks)
     CheckBypass {
         /// Pipeline stage to check
         #[arg(long, value_enum)]
         stage: SafetyBypassStage,
+        /// Project to check for a bypass of its repository
+        #[arg(long, default_value = ".")]
+        path: std::path::PathBuf,
     },
     /// Uninstall git hooks
     Uninstall {
@@ -374,6 +476,12 @@ pub enum ConfigCommand {
     Reset,
     /// Show configuration sources from hierarchy
     Sources,
+    /// Show which configuration layer sets a value
+    Explain {
+        /// The configuration key to explain (e.g. `max_file_lines`,
+        /// `rules.line_too_long`)
+        key: String,
+    },
     /// Migrate old configuration to hierarchical system
     Migrate,
     /// Lock a configuration value to prevent changes
@@ -404,10 +512,17 @@ pub enum ConfigCommand {
     /// Show lock status for all configuration values
     LockStatus,
     /// View lock audit log
+    #[command(visible_alias = "audit")]
     LockAudit {
         /// Number of entries to show
         #[arg(short, long, default_value = "20")]
         limit: usize,
+        /// Check that no entry was modified or removed
+        #[arg(long)]
+        verify: bool,
+        /// Verify the log, then write it to this file as JSON lines
+        #[arg(long, value_name = "FILE")]
+        export: Option<std::path::PathBuf>,
     },
     /// Export configuration for sharing
     Export {
@@ -459,6 +574,8 @@ impl ConfigLevelArg {
     }
 }
 
+/// Audit log verification and export handlers.
+pub mod audit;
 /// Configuration management command handlers.
 pub mod config;
 /// Edition management command handlers.
@@ -1,32 +1,103 @@
 //! Rollback command implementation
 
-use crate::Result;
+use crate::updater::{RetainedBinary, UpdateChannel, UpdateManager, VersionStore};
+use crate::{Error, Result};
 use console::style;
+use semver::Version;
 
 /// Execute the rollback command
 ///
+/// Restores a binary retained by a previous `ferrous-forge update`, either an
+/// explicit `version` or the most recent `previous` one. With `list`, only
+/// prints the available rollback targets.
+///
 /// # Errors
 ///
-/// This function currently always succeeds. Errors will be returned once
-/// rollback logic is implemented.
-pub async fn execute(version: String) -> Result<()> {
+/// Returns an error if the version string is invalid, no matching retained
+/// binary exists, its checksum does not match, or the binary swap fails.
+pub async fn execute(version: Option<String>, previous: bool, list: bool) -> Result<()> {
+    let store = VersionStore::load_default().await?;
+    let manager = UpdateManager::new(UpdateChannel::Stable)?;
+
+    if list {
+        display_targets(&store, &manager.current_version);
+        return Ok(());
+    }
+
+    let target = select_target(&store, &manager.current_version, version, previous)?;
+
     println!(
         "{}",
-        style(&format!("🔄 Rolling back to version {}...", version))
+        style(&format!("🔄 Rolling back to version {}...", target.version))
             .bold()
             .yellow()
     );
 
-    // TODO: Implement actual rollback logic
-    println!("  ⚠️  Rollback not yet implemented");
-    println!("  This feature will be available in a future release");
-    println!();
-    println!("Manual rollback options:");
+    manager.rollback(target).await?;
+
     println!(
-        "  1. Reinstall specific version: cargo install ferrous-forge --version {}",
-        version
+        "{}",
+        style(&format!(
+            "✅ Rolled back from {} to {} (SHA-256 verified)",
+            manager.current_version, target.version
+        ))
+        .green()
     );
-    println!("  2. Restore from backup if available");
+    println!("🔄 Please restart Ferrous Forge to use the restored version.");
 
     Ok(())
 }
+
+/// Pick the retained binary to restore
+fn select_target<'a>(
+    store: &'a VersionStore,
+    current: &Version,
+    version: Option<String>,
+    previous: bool,
+) -> Result<&'a RetainedBinary> {
+    if previous {
+        return store.previous(current).ok_or_else(|| {
+            Error::update("No previous version retained. Versions are retained on update.")
+        });
+    }
+
+    let requested = version.ok_or_else(|| Error::cli("Specify a version or --previous"))?;
+    let requested = Version::parse(requested.trim_start_matches('v'))
+        .map_err(|e| Error::cli(format!("Invalid version '{}': {}", requested, e)))?;
+
+    store.find(&requested).ok_or_else(|| {
+        Error::update(format!(
+            "Version {} is not retained. Run 'ferrous-forge rollback --list' to see available versions, \
+             or reinstall it with: cargo install ferrous-forge --version {}",
+            requested, requested
+        ))
+    })
+}
+
+/// Print the versions available for rollback
+fn display_targets(store: &VersionStore, current: &Version) {
+    println!("{}", style("📦 Rollback targets").bold().cyan());
+    println!("  Current version: {}", current);
+    println!();
+
+    if store.entries().is_empty() {
+        println!("  No previous versions retained yet.");
+        println!("  Versions are retained automatically by 'ferrous-forge update'.");
+        return;
+    }
+
+    for entry in store.entries() {
+        let marker = if &entry.version == current {
+            " (current)"
+        } else {
+            ""
+        };
+        println!(
+            "  {}{}  installed {}  sha256 {}",
+            style(&entry.version).bold(),
+            marker,
+            entry.installed_at.format("%Y-%m-%d %H:%M UTC"),
+            &entry.sha256[..12.min(entry.sha256.len())]
+        );
+    }
+}
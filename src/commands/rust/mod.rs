@@ -12,17 +12,34 @@ pub mod display;
 pub mod utils;
 
 use crate::rust_version::{
-    VersionManager,
-    rustup::{RustupManager, ToolchainChannel},
+    RustVersion, UpdateRecommendation, VersionManager,
+    rustup::{RustupManager, ToolchainChannel, VersionCheckResult},
 };
-use crate::{Error, Result};
+use crate::{Error, Result, output};
 use console::style;
 use display::{
     display_recommendation, display_recommendation_details, display_recommendation_header,
     display_releases_list, display_version_status,
 };
+use semver::Version;
+use serde::Serialize;
 use utils::{create_spinner, fetch_latest_version};
 
+/// Structured result of `rust check` for `--format json|yaml`
+#[derive(Debug, Serialize)]
+pub struct RustCheckOutput {
+    /// Installed Rust version
+    pub current: RustVersion,
+    /// Latest stable release (absent if it could not be fetched)
+    pub latest: Option<Version>,
+    /// Whether the installed version is the latest stable
+    pub up_to_date: Option<bool>,
+    /// Locked version requirement check, when rustup is available or enforced
+    pub requirements: Option<VersionCheckResult>,
+    /// Update recommendation (absent if release data could not be fetched)
+    pub recommendation: Option<UpdateRecommendation>,
+}
+
 /// Handle rust version check command
 ///
 /// Checks current Rust version and optionally enforces locked requirements.
@@ -39,7 +56,13 @@ pub async fn handle_check(verbose: bool, enforce: bool) -> Result<()> {
     let current = manager.check_current().await?;
     spinner.set_message("Fetching latest release information...");
 
-    let latest = match fetch_latest_version(&manager, &spinner, &current).await {
+    let latest = fetch_latest_version(&manager, &spinner, &current).await;
+
+    if output::is_machine_readable() {
+        return emit_check(&manager, &rustup, current, latest, enforce).await;
+    }
+
+    let latest = match latest {
         Some(release) => release,
         None => return Ok(()), // Error already handled in fetch_latest_version.clone()
     };
@@ -86,6 +109,43 @@ pub async fn handle_check(verbose: bool, enforce: bool) -> Result<()> {
     Ok(())
 }
 
+/// Emit the `rust check` result as a structured document
+async fn emit_check(
+    manager: &VersionManager,
+    rustup: &RustupManager,
+    current: RustVersion,
+    latest: Option<Version>,
+    enforce: bool,
+) -> Result<()> {
+    let requirements = if enforce || rustup.is_available() {
+        Some(rustup.check_version_requirements().await?)
+    } else {
+        None
+    };
+    let recommendation = match latest {
+        Some(_) => Some(manager.get_recommendation().await?),
+        None => None,
+    };
+
+    let result = RustCheckOutput {
+        up_to_date: latest.as_ref().map(|l| current.version == *l),
+        current,
+        latest,
+        requirements,
+        recommendation,
+    };
+    output::emit("rust check", &result)?;
+
+    match &result.requirements {
+        Some(check) if enforce && !check.meets_requirements => Err(Error::validation(format!(
+            "Current Rust version {} does not meet locked requirements ({})",
+            check.current,
+            check.requirements.description()
+        ))),
+        _ => Ok(()),
+    }
+}
+
 /// Handle rust recommendation command
 ///
 /// # Errors
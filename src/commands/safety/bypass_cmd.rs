@@ -6,28 +6,67 @@
 use crate::{
     Result,
     commands::SafetyBypassStage,
-    safety::{PipelineStage, bypass::BypassManager, config::BypassConfig},
+    output,
+    safety::{
+        PipelineStage,
+        bypass::{BypassManager, BypassScope},
+        config::BypassConfig,
+    },
 };
 use console::style;
+use std::path::PathBuf;
+
+/// Where a new bypass applies
+#[derive(Debug)]
+pub struct ScopeOptions {
+    /// Project whose repository is bypassed
+    pub path: PathBuf,
+    /// Limit the bypass to the current branch
+    pub branch: bool,
+    /// Limit the bypass to the current HEAD commit
+    pub commit: bool,
+    /// Use the bypass up on the first check it skips
+    pub once: bool,
+}
 
 /// Handle the bypass command
 ///
-/// Creates an emergency bypass for safety checks with audit logging.
+/// Creates an emergency bypass for safety checks in one repository, with
+/// audit logging.
 ///
 /// # Errors
 ///
 /// Returns an error if the bypass system is disabled, the reason is empty,
-/// the daily bypass limit is reached, or the bypass cannot be saved.
+/// the daily bypass limit is reached, the scope cannot be determined, or
+/// the bypass cannot be saved.
 pub async fn handle_bypass(
     stage: SafetyBypassStage,
     reason: String,
     duration: u64,
     user: Option<String>,
+    scope: ScopeOptions,
 ) -> Result<()> {
     let pipeline_stage = stage.to_pipeline_stage();
     let user = user.unwrap_or_else(whoami::username);
+    let location = BypassScope::locate(&scope.path).await?;
+    if scope.branch && location.branch.is_none() {
+        return Err(crate::Error::safety(
+            "--branch needs a checked-out branch; HEAD is detached",
+        ));
+    }
+    if scope.commit && location.commit.is_none() {
+        return Err(crate::Error::safety(
+            "--commit needs a git repository with at least one commit",
+        ));
+    }
+    let bypass_scope = location.narrowed(scope.branch, scope.commit);
 
     print_bypass_header(&pipeline_stage, &reason, &user, duration);
+    println!("   Scope:    {}", style(&bypass_scope).yellow());
+    if scope.once {
+        println!("   Uses:     {}", style("one check").yellow());
+    }
+    println!();
 
     // Load bypass configuration
     let config = load_bypass_config().await?;
@@ -42,7 +81,14 @@ pub async fn handle_bypass(
     // Create bypass manager and bypass
     let manager = BypassManager::new(&config)?;
     let bypass = manager
-        .create_bypass(pipeline_stage, reason.clone(), user.clone(), duration)
+        .create_bypass(
+            pipeline_stage,
+            reason,
+            user,
+            duration,
+            bypass_scope,
+            scope.once,
+        )
         .await?;
 
     // Print success message
@@ -87,8 +133,11 @@ pub async fn handle_audit(limit: usize) -> Result<()> {
             style("✗ FAILED").red()
         };
 
-        println!("{}. {}", i + 1, status);
+        println!("{}. {} {}", i + 1, status, style(entry.action.name()).dim());
         println!("   Stage:    {}", style(entry.stage.display_name()).cyan());
+        if let Some(scope) = &entry.scope {
+            println!("   Scope:    {}", scope);
+        }
         println!("   User:     {}", style(&entry.user).yellow());
         println!(
             "   Time:     {}",
@@ -103,6 +152,86 @@ pub async fn handle_audit(limit: usize) -> Result<()> {
     Ok(())
 }
 
+/// Handle `safety audit --verify` and `--export`
+///
+/// # Errors
+///
+/// Returns an error if the audit log cannot be read, fails verification,
+/// or cannot be exported.
+pub async fn handle_verify_audit(export: Option<&std::path::Path>) -> Result<()> {
+    let config = load_bypass_config().await?;
+    let chain = BypassManager::new(&config)?.audit_chain()?;
+    crate::commands::audit::handle_verify("safety audit", &chain, export).await
+}
+
+/// Handle `safety bypass --list`
+///
+/// Shows every bypass that has not expired, in any repository.
+///
+/// # Errors
+///
+/// Returns an error if the bypasses cannot be read.
+pub async fn handle_list() -> Result<()> {
+    let config = load_bypass_config().await?;
+    let manager = BypassManager::new(&config)?;
+    let bypasses = manager.list_bypasses().await?;
+
+    if output::is_machine_readable() {
+        return output::emit("safety bypass", &bypasses);
+    }
+
+    println!("{}", style("🚨 Active Safety Bypasses").bold());
+    println!("{}", "=".repeat(60));
+    if bypasses.is_empty() {
+        println!("\n{}", style("No active bypasses").dim());
+        return Ok(());
+    }
+
+    for bypass in &bypasses {
+        println!();
+        println!(
+            "{}  {}{}",
+            style(&bypass.id).bold(),
+            style(bypass.stage.display_name()).cyan(),
+            if bypass.one_shot { " (one check)" } else { "" }
+        );
+        println!("   Scope:    {}", bypass.scope);
+        println!("   User:     {}", style(&bypass.user).yellow());
+        println!(
+            "   Expires:  {}",
+            style(bypass.expires_at.format("%Y-%m-%d %H:%M:%S UTC")).dim()
+        );
+        println!("   Reason:   {}", bypass.reason);
+    }
+    println!();
+    println!(
+        "Revoke with: {}",
+        style("ferrous-forge safety bypass --revoke <ID>").green()
+    );
+    Ok(())
+}
+
+/// Handle `safety bypass --revoke <id>`
+///
+/// # Errors
+///
+/// Returns an error if no active bypass has that id or the bypasses cannot
+/// be updated.
+pub async fn handle_revoke(id: &str, user: Option<String>) -> Result<()> {
+    let user = user.unwrap_or_else(whoami::username);
+    let config = load_bypass_config().await?;
+    let manager = BypassManager::new(&config)?;
+    let bypass = manager.revoke_bypass(id, &user).await?;
+
+    println!(
+        "{} {} bypass for {}",
+        style("✅ Revoked").green().bold(),
+        style(bypass.stage.display_name()).cyan(),
+        bypass.scope
+    );
+    Ok(())
+}
+
 /// Load bypass configuration
 async fn load_bypass_config() -> Result<BypassConfig> {
     // Try to load from safety config file, use defaults if not found
@@ -135,10 +264,7 @@ fn print_bypass_success(bypass: &crate::safety::bypass::ActiveBypass, duration:
     println!();
     println!("{}", style("✅ Bypass created successfully").green().bold());
     println!();
-    println!(
-        "   Bypass ID:   {}",
-        style(format!("{:?}", bypass.created_at.timestamp())).dim()
-    );
+    println!("   Bypass ID:   {}", style(&bypass.id).dim());
     println!(
         "   Expires:     {}",
         style(bypass.expires_at.format("%Y-%m-%d %H:%M:%S UTC")).yellow()
@@ -153,10 +279,14 @@ fn print_bypass_success(bypass: &crate::safety::bypass::ActiveBypass, duration:
         "   2. You can now run: {}",
         style(get_command_for_stage(bypass.stage)).green()
     );
-    println!(
-        "   3. Bypass will expire in {} hours",
-        style(duration).yellow()
-    );
+    if bypass.one_shot {
+        println!("   3. Bypass is used up by the next check it skips");
+    } else {
+        println!(
+            "   3. Bypass will expire in {} hours",
+            style(duration).yellow()
+        );
+    }
 }
 
 /// Get the command to run for a given stage
@@ -42,6 +42,18 @@ pub async fn handle_config_show() -> Result<()> {
     print_stage_config("Pre-Push", &config.pre_push);
     print_stage_config("Publish", &config.publish);
 
+    // Custom checks
+    if !config.custom_checks.is_empty() {
+        println!("{}", style("Custom Checks:").cyan().bold());
+        for (name, check) in &config.custom_checks {
+            println!("  {}: {}", style(name).yellow(), check.command_line());
+            if let Some(description) = &check.description {
+                println!("    {}", style(description).dim());
+            }
+        }
+        println!();
+    }
+
     // Bypass configuration
     println!("{}", style("Bypass Settings:").cyan().bold());
     println!(
@@ -159,6 +171,10 @@ fn print_stage_config(name: &str, config: &StageConfig) {
         "    Timeout:  {} seconds",
         style(config.timeout_seconds).yellow()
     );
+    println!(
+        "    Continue on warning: {}",
+        format_bool(config.continue_on_warning)
+    );
     println!(
         "    Checks:   {}",
         config
@@ -189,10 +205,16 @@ fn print_available_keys() {
         "parallel_checks",
         "pre_commit.enabled",
         "pre_commit.timeout_seconds",
+        "pre_commit.continue_on_warning",
+        "pre_commit.checks",
         "pre_push.enabled",
         "pre_push.timeout_seconds",
+        "pre_push.continue_on_warning",
+        "pre_push.checks",
         "publish.enabled",
         "publish.timeout_seconds",
+        "publish.continue_on_warning",
+        "publish.checks",
         "bypass.enabled",
     ];
 
@@ -176,23 +176,20 @@ if [ "$BYPASS_CHECK" = "active" ]; then
     exit 0
 fi
 
-# Run Ferrous Forge validation
+# Run the configured pre-commit checks against the staged snapshot, so
+# unstaged edits cannot change the outcome
 echo ""
-echo "🔍 Running mandatory validation checks..."
+echo "🔍 Running pre-commit safety checks on staged changes..."
 echo ""
 
-if ! ferrous-forge validate --quiet 2>&1; then
+if ! ferrous-forge safety check --stage=pre-commit --staged --verbose 2>&1; then
     echo ""
     echo "═══════════════════════════════════════════════════"
     echo "🛡️  FERROUS FORGE BLOCKED COMMIT"
     echo "═══════════════════════════════════════════════════"
     echo ""
-    echo "Validation failed. Fix the issues above before committing."
-    echo ""
-    echo "How to fix:"
-    echo "  1. Run 'ferrous-forge validate' to see all errors"
-    echo "  2. Fix the reported violations"
-    echo "  3. Try committing again"
+    echo "Safety checks failed on the staged changes. Fix the issues above,"
+    echo "stage the fixes with 'git add', and try committing again."
     echo ""
     echo "To bypass (requires reason):"
     echo "  ferrous-forge safety bypass --stage=pre-commit --reason=\"WIP commit\""
@@ -203,22 +200,6 @@ if ! ferrous-forge validate --quiet 2>&1; then
     exit 1
 fi
 
-# Run clippy checks
-echo "📎 Running clippy checks..."
-if ! cargo clippy -- -D warnings 2>/dev/null; then
-    echo ""
-    echo "═══════════════════════════════════════════════════"
-    echo "🛡️  FERROUS FORGE BLOCKED COMMIT"
-    echo "═══════════════════════════════════════════════════"
-    echo ""
-    echo "Clippy checks failed. Fix the warnings before committing."
-    echo ""
-    echo "How to fix:"
-    echo "  Run 'cargo clippy -- -D warnings' to see all issues"
-    echo ""
-    exit 1
-fi
-
 echo ""
 echo "✅ All safety checks passed! Commit allowed."
 echo ""
@@ -284,7 +265,7 @@ fi
 
 # Run full validation
 echo "🔍 Running validation..."
-if ! ferrous-forge validate --quiet 2>&1; then
+if ! ferrous-forge validate 2>&1; then
     echo ""
     echo "═══════════════════════════════════════════════════"
     echo "🛡️  FERROUS FORGE BLOCKED PUSH"
@@ -303,6 +284,22 @@ if ! ferrous-forge validate --quiet 2>&1; then
     exit 1
 fi
 
+# Run user-defined checks from the safety config
+echo "🧩 Running custom checks..."
+if ! ferrous-forge safety check --stage=pre-push --custom-only --verbose 2>&1; then
+    echo ""
+    echo "═══════════════════════════════════════════════════"
+    echo "🛡️  FERROUS FORGE BLOCKED PUSH"
+    echo "═══════════════════════════════════════════════════"
+    echo ""
+    echo "Custom checks failed. Fix the issues above before pushing."
+    echo ""
+    echo "How to fix:"
+    echo "  Run 'ferrous-forge safety check --stage=pre-push --custom-only' to see all issues"
+    echo ""
+    exit 1
+fi
+
 # Run security audit
 echo "🔒 Running security audit..."
 if command -v cargo-audit >/dev/null 2>&1; then
@@ -16,11 +16,16 @@ pub mod stats_cmd;
 /// Safety status display command implementation.
 pub mod status;
 
-use crate::Result;
 use crate::commands::SafetyBypassStage;
-use crate::safety::{PipelineStage, SafetyPipeline, bypass::BypassManager, config::BypassConfig};
+use crate::output::sarif::{self, SarifBuilder};
+use crate::safety::{
+    CheckType, PipelineStage, SafetyPipeline,
+    bypass::{BypassManager, BypassScope},
+    config::BypassConfig,
+};
+use crate::{Result, output};
 use console::style;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Handle safety install command
 ///
@@ -44,22 +49,85 @@ pub async fn handle_install(force: bool, project_path: &Path, install_cargo: boo
     Ok(())
 }
 
+/// Flags of the safety check command
+#[derive(Debug, Default)]
+pub struct CheckOptions {
+    /// Show verbose output
+    pub verbose: bool,
+    /// Also write a SARIF log to this path
+    pub sarif: Option<PathBuf>,
+    /// Run only these checks instead of the stage's list
+    pub checks: Vec<String>,
+    /// Run only the user-defined checks
+    pub custom_only: bool,
+    /// Check the staged snapshot instead of the working tree
+    pub staged: bool,
+}
+
 /// Handle safety check command
 ///
+/// Exits with status 1 when the checks would block the operation, so git
+/// hooks can run it directly.
+///
 /// # Errors
 ///
-/// Returns an error if the stage string cannot be parsed, the safety pipeline
-/// fails to initialize, or the checks fail to run.
-pub async fn handle_check(stage_str: &str, project_path: &Path, verbose: bool) -> Result<()> {
+/// Returns an error if the stage string or a check name cannot be parsed,
+/// the safety pipeline fails to initialize, the checks fail to run, or the
+/// SARIF log cannot be written.
+pub async fn handle_check(
+    stage_str: &str,
+    project_path: &Path,
+    options: CheckOptions,
+) -> Result<()> {
     let stage = stage_str.parse::<PipelineStage>()?;
 
-    println!("🧪 Testing Safety Pipeline - {}", stage.display_name());
-    println!("{}", "=".repeat(50));
+    output::human(format!(
+        "🧪 Testing Safety Pipeline - {}",
+        stage.display_name()
+    ));
+    output::human("=".repeat(50));
 
-    let pipeline = SafetyPipeline::new(project_path).await?;
-    let report = pipeline.run_checks(stage).await?;
+    let pipeline = if options.staged {
+        output::human("📸 Checking the staged snapshot (unstaged changes are ignored)");
+        SafetyPipeline::new_staged(project_path).await?
+    } else {
+        SafetyPipeline::new(project_path).await?
+    };
+    let mut checks = if options.checks.is_empty() {
+        pipeline.config().get_stage_config(stage).checks.clone()
+    } else {
+        pipeline
+            .config()
+            .parse_check_list(&options.checks.join(","))?
+    };
+    if options.custom_only {
+        checks.retain(CheckType::is_custom);
+        if checks.is_empty() {
+            output::human(format!(
+                "No custom checks configured for {}",
+                stage.display_name()
+            ));
+            return Ok(());
+        }
+    }
+    let report = pipeline.run_checks_with(stage, &checks).await?;
 
-    if verbose {
+    if let Some(sarif_path) = &options.sarif {
+        let mut builder = SarifBuilder::new(project_path);
+        builder.add_safety_report(&report);
+        sarif::write_log(&builder.build(), sarif_path).await?;
+        output::human(format!("📄 SARIF log written to {}", sarif_path.display()));
+    }
+
+    if output::is_machine_readable() {
+        output::emit("safety check", &report)?;
+        if !report.passed {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if options.verbose {
         report.print_detailed();
     } else {
         report.print_summary();
@@ -73,6 +141,7 @@ pub async fn handle_check(stage_str: &str, project_path: &Path, verbose: bool) -
                 .bold()
         );
         println!("Fix the issues above before committing/pushing/publishing");
+        std::process::exit(1);
     } else {
         println!(
             "\n{}",
@@ -108,12 +177,13 @@ pub async fn test_individual_checks(project_path: &Path) -> Result<()> {
 
 /// Handle check-bypass command (used by git hooks)
 ///
-/// Returns silently if no bypass is active, or prints "active" and exits 0 if bypass exists.
+/// Exits with status 1 if no bypass applies to the repository, branch and
+/// commit of `project_path`, or prints "active" and exits 0 if one does.
 ///
 /// # Errors
 ///
 /// Returns an error if the bypass check fails to execute.
-pub async fn handle_check_bypass(stage: SafetyBypassStage) -> Result<()> {
+pub async fn handle_check_bypass(stage: SafetyBypassStage, project_path: &Path) -> Result<()> {
     let pipeline_stage = stage.to_pipeline_stage();
 
     // Load bypass configuration
@@ -125,8 +195,12 @@ pub async fn handle_check_bypass(stage: SafetyBypassStage) -> Result<()> {
     }
 
     let manager = BypassManager::new(&config)?;
+    let location = BypassScope::locate(project_path).await?;
 
-    match manager.check_active_bypass(pipeline_stage).await? {
+    match manager
+        .check_active_bypass(pipeline_stage, &location)
+        .await?
+    {
         Some(_) => {
             // Bypass is active
             println!("active");
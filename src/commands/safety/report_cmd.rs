@@ -168,8 +168,11 @@ fn print_audit_entry(index: usize, entry: &BypassLogEntry) {
         style("✗ FAILED").red()
     };
 
-    println!("{}. {}", index, status);
+    println!("{}. {} {}", index, status, style(entry.action.name()).dim());
     println!("   Stage:    {}", style(entry.stage.display_name()).cyan());
+    if let Some(scope) = &entry.scope {
+        println!("   Scope:    {}", scope);
+    }
     println!("   User:     {}", style(&entry.user).yellow());
     println!(
         "   Time:     {}",
@@ -6,7 +6,7 @@
 //! @epic T014
 
 use crate::{
-    Error, Result,
+    Error, Result, output,
     safety::PipelineStage,
     safety::bypass::BypassManager,
     safety::config::{BypassConfig, SafetyConfig},
@@ -14,12 +14,13 @@ use crate::{
 };
 use chrono::{Duration, Utc};
 use console::style;
-use std::collections::HashMap;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 use tokio::fs;
 
 /// Safety pipeline statistics
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize)]
 pub struct SafetyStats {
     /// Total number of reports
     pub total_reports: usize,
@@ -28,19 +29,21 @@ pub struct SafetyStats {
     /// Number of failed reports
     pub failed_reports: usize,
     /// Reports by stage
-    pub reports_by_stage: HashMap<String, usize>,
+    pub reports_by_stage: BTreeMap<String, usize>,
     /// Pass rate by stage (0.0 - 1.0)
-    pub pass_rate_by_stage: HashMap<String, f64>,
+    pub pass_rate_by_stage: BTreeMap<String, f64>,
     /// Total bypass count
     pub total_bypasses: usize,
     /// Bypasses by stage
-    pub bypasses_by_stage: HashMap<String, usize>,
+    pub bypasses_by_stage: BTreeMap<String, usize>,
     /// Bypasses by user
-    pub bypasses_by_user: HashMap<String, usize>,
+    pub bypasses_by_user: BTreeMap<String, usize>,
     /// Bypasses in last 24 hours
     pub bypasses_last_24h: usize,
     /// Bypasses in last 7 days
     pub bypasses_last_7d: usize,
+    /// Failed or timed-out runs by check (`custom:<name>` for custom checks)
+    pub failures_by_check: BTreeMap<String, usize>,
     /// Average checks per report
     pub avg_checks_per_report: f64,
     /// Average report duration
@@ -49,6 +52,15 @@ pub struct SafetyStats {
     pub trend_7d: f64,
 }
 
+/// Structured result of the stats command for `--format json|yaml`
+#[derive(Debug, Serialize)]
+struct StatsOutput<'a> {
+    /// Number of days of history included
+    window_days: u32,
+    /// Computed statistics
+    stats: &'a SafetyStats,
+}
+
 /// Handle stats command
 ///
 /// Displays comprehensive safety pipeline statistics.
@@ -57,12 +69,22 @@ pub struct SafetyStats {
 ///
 /// Returns an error if statistics cannot be computed.
 pub async fn handle_stats(days: u32) -> Result<()> {
+    let stats = compute_stats(days).await?;
+
+    if output::is_machine_readable() {
+        return output::emit(
+            "safety stats",
+            &StatsOutput {
+                window_days: days,
+                stats: &stats,
+            },
+        );
+    }
+
     println!("{}", style("📈 Safety Pipeline Statistics").bold());
     println!("{}", "=".repeat(60));
     println!();
 
-    let stats = compute_stats(days).await?;
-
     // Overall Statistics
     println!("{}", style("Overall Statistics:").cyan().bold());
     println!(
@@ -119,6 +141,21 @@ pub async fn handle_stats(days: u32) -> Result<()> {
     }
     println!();
 
+    // Most failing checks
+    if !stats.failures_by_check.is_empty() {
+        println!("{}", style("Most Failing Checks:").cyan().bold());
+        let mut check_counts: Vec<_> = stats.failures_by_check.iter().collect();
+        check_counts.sort_by(|a, b| b.1.cmp(a.1));
+        for (check, count) in check_counts.iter().take(5) {
+            println!(
+                "  {:20} {} failures",
+                style(check).cyan(),
+                style(count).red()
+            );
+        }
+        println!();
+    }
+
     // Performance Metrics
     println!("{}", style("Performance Metrics:").cyan().bold());
     println!("  Avg Checks/Report:  {:.1}", stats.avg_checks_per_report);
@@ -264,6 +301,12 @@ async fn compute_stats(days: u32) -> Result<SafetyStats> {
             .or_insert(0) += 1;
 
         total_checks += report.checks.len();
+        for check in report.checks.iter().filter(|c| !c.passed) {
+            *stats
+                .failures_by_check
+                .entry(check.check_type.to_string())
+                .or_insert(0) += 1;
+        }
         total_duration_secs += report.total_duration.as_secs_f64();
     }
 
@@ -34,9 +34,9 @@ fn display_safety_config_found(config: &crate::safety::SafetyConfig) {
     println!(
         "   Blocking: {}",
         if config.strict_mode {
-            "🛡️  MANDATORY (blocks on failure)"
+            "🛡️  MANDATORY (blocks on failures and warnings)"
         } else {
-            "⚠️  Warning only"
+            "⚠️  Blocks on failures only"
         }
     );
 
@@ -1,7 +1,38 @@
 //! Status command implementation
 
-use crate::{Result, config::Config};
+use crate::{Result, config::Config, output};
 use console::style;
+use serde::Serialize;
+
+/// Structured result of the status command for `--format json|yaml`
+#[derive(Debug, Serialize)]
+pub struct StatusOutput {
+    /// Whether Ferrous Forge has been initialized
+    pub initialized: bool,
+    /// Installed Ferrous Forge version
+    pub version: &'static str,
+    /// Minimum supported Rust version
+    pub min_rust_version: &'static str,
+    /// Edition enforced by Ferrous Forge
+    pub required_edition: &'static str,
+    /// Active configuration (absent when not initialized)
+    pub config: Option<Config>,
+    /// System integration checks (absent when not initialized)
+    pub integration: Option<IntegrationStatus>,
+}
+
+/// Installation state of each system integration
+///
+/// `None` means the state could not be determined (no home directory).
+#[derive(Debug, Serialize)]
+pub struct IntegrationStatus {
+    /// Cargo wrapper in `~/.local/bin/cargo`
+    pub cargo_hijacking: Option<bool>,
+    /// Global clippy configuration in `~/.clippy.toml`
+    pub clippy_config: Option<bool>,
+    /// Ferrous Forge PATH setup in a shell rc file
+    pub shell_integration: Option<bool>,
+}
 
 /// Execute the status command
 ///
@@ -9,25 +40,54 @@ use console::style;
 ///
 /// Returns an error if the configuration cannot be loaded.
 pub async fn execute() -> Result<()> {
-    println!("{}", style("🔨 Ferrous Forge Status").bold().cyan());
-    println!();
-
     // Load configuration
     let config = Config::load_or_default().await?;
 
-    // Basic status
-    if config.is_initialized() {
-        println!("{}", style("✅ Status: Initialized").green());
+    let initialized = config.is_initialized();
+    let integration = if initialized {
+        Some(IntegrationStatus {
+            cargo_hijacking: check_cargo_hijacking(),
+            clippy_config: check_clippy_config(),
+            shell_integration: check_shell_integration().await,
+        })
     } else {
+        None
+    };
+
+    let status = StatusOutput {
+        initialized,
+        version: crate::VERSION,
+        min_rust_version: crate::MIN_RUST_VERSION,
+        required_edition: crate::REQUIRED_EDITION,
+        config: initialized.then_some(config),
+        integration,
+    };
+
+    if output::is_machine_readable() {
+        return output::emit("status", &status);
+    }
+
+    display_status(&status);
+    Ok(())
+}
+
+/// Print the human-readable status report
+fn display_status(status: &StatusOutput) {
+    println!("{}", style("🔨 Ferrous Forge Status").bold().cyan());
+    println!();
+
+    // Basic status
+    let (Some(config), Some(integration)) = (&status.config, &status.integration) else {
         println!("{}", style("❌ Status: Not initialized").red());
         println!("Run 'ferrous-forge init' to set up system-wide standards.");
-        return Ok(());
-    }
+        return;
+    };
+    println!("{}", style("✅ Status: Initialized").green());
 
     // Version information
-    println!("📦 Version: {}", crate::VERSION);
-    println!("🦀 Min Rust Version: {}", crate::MIN_RUST_VERSION);
-    println!("📐 Required Edition: {}", crate::REQUIRED_EDITION);
+    println!("📦 Version: {}", status.version);
+    println!("🦀 Min Rust Version: {}", status.min_rust_version);
+    println!("📐 Required Edition: {}", status.required_edition);
     println!();
 
     // Configuration
@@ -47,83 +107,54 @@ pub async fn execute() -> Result<()> {
 
     // Check system integration
     println!("{}", style("🔗 System Integration:").bold());
-    check_cargo_hijacking().await;
-    check_clippy_config().await;
-    check_shell_integration().await;
+    display_integration("Cargo hijacking", integration.cargo_hijacking);
+    display_integration("Clippy config", integration.clippy_config);
+    display_integration("Shell integration", integration.shell_integration);
+}
 
-    Ok(())
+/// Print one integration line
+fn display_integration(name: &str, installed: Option<bool>) {
+    match installed {
+        Some(true) => println!("  ✅ {}: Installed", name),
+        Some(false) => println!("  ❌ {}: Not installed", name),
+        None => println!("  ❌ {}: Cannot find home directory", name),
+    }
 }
 
 /// Check if cargo wrapper hijacking is installed
 ///
 /// Verifies whether the Ferrous Forge cargo wrapper is present in ~/.local/bin/cargo
 /// to intercept cargo commands and enforce standards.
-async fn check_cargo_hijacking() {
-    let home_dir = match dirs::home_dir() {
-        Some(dir) => dir,
-        None => {
-            println!("  ❌ Cargo hijacking: Cannot find home directory");
-            return;
-        }
-    };
-
-    let cargo_wrapper = home_dir.join(".local").join("bin").join("cargo");
-    if cargo_wrapper.exists() {
-        println!("  ✅ Cargo hijacking: Installed");
-    } else {
-        println!("  ❌ Cargo hijacking: Not installed");
-    }
+fn check_cargo_hijacking() -> Option<bool> {
+    let home_dir = dirs::home_dir()?;
+    Some(home_dir.join(".local").join("bin").join("cargo").exists())
 }
 
 /// Check if global clippy configuration is installed
 ///
 /// Verifies whether the Ferrous Forge clippy configuration file exists
 /// at ~/.clippy.toml for enforcing strict linting rules.
-async fn check_clippy_config() {
-    let home_dir = match dirs::home_dir() {
-        Some(dir) => dir,
-        None => {
-            println!("  ❌ Clippy config: Cannot find home directory");
-            return;
-        }
-    };
-
-    let clippy_config = home_dir.join(".clippy.toml");
-    if clippy_config.exists() {
-        println!("  ✅ Clippy config: Installed");
-    } else {
-        println!("  ❌ Clippy config: Not installed");
-    }
+fn check_clippy_config() -> Option<bool> {
+    let home_dir = dirs::home_dir()?;
+    Some(home_dir.join(".clippy.toml").exists())
 }
 
 /// Check if shell integration is configured
 ///
 /// Scans common shell configuration files (.bashrc, .zshrc, .profile)
 /// to verify if Ferrous Forge PATH modifications are present.
-async fn check_shell_integration() {
-    let home_dir = match dirs::home_dir() {
-        Some(dir) => dir,
-        None => {
-            println!("  ❌ Shell integration: Cannot find home directory");
-            return;
-        }
-    };
+async fn check_shell_integration() -> Option<bool> {
+    let home_dir = dirs::home_dir()?;
 
-    let mut found = false;
     for shell_file in &[".bashrc", ".zshrc", ".profile"] {
         let shell_path = home_dir.join(shell_file);
         if shell_path.exists()
             && let Ok(contents) = tokio::fs::read_to_string(&shell_path).await
             && contents.contains("Ferrous Forge")
         {
-            found = true;
-            break;
+            return Some(true);
         }
     }
 
-    if found {
-        println!("  ✅ Shell integration: Installed");
-    } else {
-        println!("  ❌ Shell integration: Not installed");
-    }
+    Some(false)
 }
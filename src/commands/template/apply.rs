@@ -0,0 +1,429 @@
+//! Apply a newer version of a template to a project generated from it
+
+use super::creation::{LoadedTemplate, load_template};
+use super::merge::{common_base, merge3};
+use super::variables::{VariableSources, resolve_variables};
+use crate::commands::fix::diff::{print_diff, unified_diff};
+use crate::templates::{TemplateEngine, TemplateLock};
+use crate::{Error, Result};
+use chrono::Utc;
+use console::style;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+
+/// What applying the template does to one file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    /// New file from the template
+    Added,
+    /// Unchanged in the project, so replaced by the template's version
+    Updated,
+    /// Template changes merged into project changes
+    Merged,
+    /// Merged with this many conflicting blocks
+    Conflicts(usize),
+    /// Dropped by the template and unchanged in the project
+    Removed,
+    /// Left alone because one side deleted the file and the other changed it
+    Kept(&'static str),
+}
+
+/// A file the template changes
+struct FileChange {
+    path: String,
+    before: Option<String>,
+    after: Option<String>,
+    outcome: Outcome,
+}
+
+impl FileChange {
+    fn needs_attention(&self) -> bool {
+        matches!(self.outcome, Outcome::Conflicts(_) | Outcome::Kept(_))
+    }
+}
+
+/// Merge the current version of `template_name` into the project at
+/// `project`
+///
+/// The template is rendered with the variables recorded in the project's
+/// template lock, plus values for any variables it has gained. Each file is
+/// merged three ways against the rendering recorded in the lock, so changes
+/// made in the project are kept. Without a lock, lines that only the
+/// project or only the template has are kept and lines they both changed
+/// conflict. The lock is rewritten for the next update.
+///
+/// # Errors
+///
+/// Returns an error if the template cannot be loaded or rendered, a file
+/// cannot be read or written, or files are left with conflicts.
+pub async fn apply_template(
+    template_name: &str,
+    project: &Path,
+    sources: &VariableSources,
+    dry_run: bool,
+) -> Result<()> {
+    if !project.is_dir() {
+        return Err(Error::template(format!(
+            "Project path does not exist: {}",
+            project.display()
+        )));
+    }
+    println!(
+        "{}",
+        style(format!(
+            "🔄 Applying template '{}' to {}",
+            template_name,
+            project.display()
+        ))
+        .cyan()
+        .bold()
+    );
+    println!();
+
+    let template = load_template(template_name)?;
+    let lock = TemplateLock::load(project)?.filter(|lock| {
+        let same = lock.template == template_name;
+        if !same {
+            println!(
+                "{}",
+                style(format!(
+                    "⚠️  Project was generated from '{}'; ignoring its template lock",
+                    lock.template
+                ))
+                .yellow()
+            );
+        }
+        same
+    });
+
+    let known = match &lock {
+        Some(lock) => lock.variables.clone().into_iter().collect(),
+        None => package_name(project)
+            .map(|name| HashMap::from([("project_name".to_string(), name)]))
+            .unwrap_or_default(),
+    };
+    let variables = resolve_variables(&template.manifest, sources, known)?;
+    let rendered = render(&template, variables.clone())?;
+
+    let version = &template.manifest.version;
+    let base = match lock {
+        Some(lock) if !lock.files.is_empty() => {
+            println!("   Base: v{} recorded in the template lock", lock.version);
+            Some(lock.files)
+        }
+        Some(lock) if &lock.version == version => {
+            println!(
+                "   Base: v{} rendered with the recorded variables",
+                lock.version
+            );
+            Some(render(&template, lock.variables.into_iter().collect())?)
+        }
+        Some(lock) => {
+            println!(
+                "   {}",
+                style(format!(
+                    "Base: none, v{} is no longer available; lines changed on both sides will conflict",
+                    lock.version
+                ))
+                .yellow()
+            );
+            None
+        }
+        None => {
+            println!(
+                "   {}",
+                style("Base: none, no template lock; lines changed on both sides will conflict")
+                    .yellow()
+            );
+            None
+        }
+    };
+    println!("   Template: v{}", version);
+    println!();
+
+    let label = format!("template {} {}", template_name, version);
+    let mut paths: BTreeSet<&String> = rendered.keys().collect();
+    if let Some(base) = &base {
+        paths.extend(base.keys());
+    }
+    let mut changes = Vec::new();
+    for path in paths {
+        let Some(current) = read_project_file(project, path)? else {
+            println!("   {} {} (not UTF-8, skipped)", style("!").yellow(), path);
+            continue;
+        };
+        let base_text = base.as_ref().and_then(|b| b.get(path)).map(String::as_str);
+        let template_text = rendered.get(path).map(String::as_str);
+        if let Some(change) = merge_file(path, base_text, current, template_text, &label) {
+            changes.push(change);
+        }
+    }
+
+    print_changes(&changes);
+    if !dry_run {
+        write_changes(project, &changes)?;
+        TemplateLock {
+            template: template_name.to_string(),
+            version: version.clone(),
+            generated_at: Utc::now(),
+            variables: variables.into_iter().collect(),
+            files: rendered,
+        }
+        .save(project)?;
+    }
+
+    let attention = changes.iter().filter(|c| c.needs_attention()).count();
+    if dry_run {
+        println!(
+            "{}",
+            style(format!(
+                "🔍 Dry run: {} file(s) would change, {} need attention",
+                changes.len(),
+                attention
+            ))
+            .cyan()
+        );
+        return Ok(());
+    }
+    if attention > 0 {
+        println!(
+            "{}",
+            style(format!("⚠️  {} file(s) need attention", attention))
+                .yellow()
+                .bold()
+        );
+        return Err(Error::template(format!(
+            "{} file(s) have conflicts; resolve the conflict markers and review the changes",
+            attention
+        )));
+    }
+    if !changes.is_empty() {
+        println!(
+            "{}",
+            style(format!("✅ Applied template to {} file(s)", changes.len()))
+                .green()
+                .bold()
+        );
+    }
+    Ok(())
+}
+
+/// Render every file of `template` with `variables`
+fn render(
+    template: &LoadedTemplate,
+    variables: HashMap<String, String>,
+) -> Result<BTreeMap<String, String>> {
+    let mut engine = TemplateEngine::new(template.manifest.clone(), template.source_dir.clone());
+    engine.set_variables(variables)?;
+    engine.render_files()
+}
+
+/// The package name from the project's `Cargo.toml`, if any
+fn package_name(project: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(project.join("Cargo.toml")).ok()?;
+    let manifest: toml::Table = toml::from_str(&content).ok()?;
+    manifest
+        .get("package")?
+        .get("name")?
+        .as_str()
+        .map(str::to_string)
+}
+
+/// The project's copy of `path`: `Some(None)` when it does not exist,
+/// `None` when it is not UTF-8
+fn read_project_file(project: &Path, path: &str) -> Result<Option<Option<String>>> {
+    match std::fs::read(project.join(path)) {
+        Ok(bytes) => Ok(String::from_utf8(bytes).ok().map(Some)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Some(None)),
+        Err(e) => Err(Error::template(format!("Failed to read {}: {}", path, e))),
+    }
+}
+
+/// Decide what happens to `path`, or `None` if it stays as it is
+fn merge_file(
+    path: &str,
+    base: Option<&str>,
+    current: Option<String>,
+    template: Option<&str>,
+    label: &str,
+) -> Option<FileChange> {
+    let (after, outcome) = match (current.as_deref(), template) {
+        (current, template) if current == template => return None,
+        (None, Some(template)) => match base {
+            None => (Some(template.to_string()), Outcome::Added),
+            Some(base) if base == template => return None,
+            Some(_) => (
+                None,
+                Outcome::Kept("deleted in the project, changed by the template"),
+            ),
+        },
+        (Some(current), None) => match base {
+            Some(base) if base == current => (None, Outcome::Removed),
+            _ => (
+                Some(current.to_string()),
+                Outcome::Kept("removed by the template, changed in the project"),
+            ),
+        },
+        (Some(current), Some(template)) => match base {
+            Some(base) if base == current => (Some(template.to_string()), Outcome::Updated),
+            Some(base) if base == template => return None,
+            _ => {
+                let base = base.map_or_else(|| common_base(current, template), str::to_string);
+                let merged = merge3(&base, current, template, label);
+                if merged.text == current {
+                    return None;
+                }
+                let outcome = match merged.conflicts {
+                    0 => Outcome::Merged,
+                    n => Outcome::Conflicts(n),
+                };
+                (Some(merged.text), outcome)
+            }
+        },
+        (None, None) => return None,
+    };
+    Some(FileChange {
+        path: path.to_string(),
+        before: current,
+        after,
+        outcome,
+    })
+}
+
+/// Print a status line and a diff for every change
+fn print_changes(changes: &[FileChange]) {
+    if changes.is_empty() {
+        println!(
+            "{}",
+            style("Project is up to date with the template").green()
+        );
+        println!();
+        return;
+    }
+    for change in changes {
+        let status = match change.outcome {
+            Outcome::Added => style("added".to_string()).green(),
+            Outcome::Updated => style("updated".to_string()).green(),
+            Outcome::Merged => style("merged".to_string()).cyan(),
+            Outcome::Removed => style("removed".to_string()).red(),
+            Outcome::Conflicts(n) => style(format!("{} conflict(s)", n)).red().bold(),
+            Outcome::Kept(reason) => style(format!("kept, {}", reason)).yellow(),
+        };
+        println!("{} {}", style(&change.path).bold(), status);
+        if !matches!(change.outcome, Outcome::Kept(_)) {
+            print_diff(&unified_diff(
+                &change.path,
+                change.before.as_deref().unwrap_or_default(),
+                change.after.as_deref().unwrap_or_default(),
+            ));
+        }
+        println!();
+    }
+}
+
+/// Write the merged files and delete the removed ones
+fn write_changes(project: &Path, changes: &[FileChange]) -> Result<()> {
+    for change in changes {
+        let path = project.join(&change.path);
+        match (&change.outcome, &change.after) {
+            (Outcome::Kept(_), _) => {}
+            (_, Some(text)) => {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(&path, text)?;
+            }
+            (_, None) => std::fs::remove_file(&path)?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn outcome(
+        base: Option<&str>,
+        current: Option<&str>,
+        template: Option<&str>,
+    ) -> Option<(Outcome, Option<String>)> {
+        merge_file("f", base, current.map(str::to_string), template, "template")
+            .map(|change| (change.outcome, change.after))
+    }
+
+    #[test]
+    fn test_file_outcomes() {
+        let text = |s: &str| Some(s.to_string());
+        assert_eq!(
+            outcome(None, None, Some("a\n")),
+            Some((Outcome::Added, text("a\n")))
+        );
+        assert_eq!(
+            outcome(Some("a\n"), Some("a\n"), Some("b\n")),
+            Some((Outcome::Updated, text("b\n")))
+        );
+        assert_eq!(outcome(Some("a\n"), Some("b\n"), Some("a\n")), None);
+        assert_eq!(outcome(Some("a\n"), None, Some("a\n")), None);
+        assert_eq!(
+            outcome(Some("a\n"), Some("a\n"), None),
+            Some((Outcome::Removed, None))
+        );
+        assert!(matches!(
+            outcome(Some("a\n"), Some("b\n"), None),
+            Some((Outcome::Kept(_), _))
+        ));
+        assert!(matches!(
+            outcome(Some("a\n"), None, Some("b\n")),
+            Some((Outcome::Kept(_), None))
+        ));
+        assert!(matches!(
+            outcome(Some("a\nb\n"), Some("x\nb\n"), Some("y\nb\n")),
+            Some((Outcome::Conflicts(1), _))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_apply_merges_template_changes_and_rewrites_lock() {
+        let project = tempfile::TempDir::new().unwrap();
+        let sources = VariableSources {
+            defaults: true,
+            ..VariableSources::default()
+        };
+        let template = load_template("library").unwrap();
+        let variables = HashMap::from([("project_name".to_string(), "svc".to_string())]);
+        let files = render(&template, variables.clone()).unwrap();
+
+        // The project was generated from an older Cargo.toml and has
+        // gained a dependency since
+        let mut old_files = files.clone();
+        let old_manifest = old_files.get_mut("Cargo.toml").unwrap();
+        *old_manifest = old_manifest.replacen('\n', "\nold-template-line = true\n", 1);
+        for (path, content) in &old_files {
+            let path = project.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        let edited = format!("{}# project notes\n", old_files["Cargo.toml"]);
+        std::fs::write(project.path().join("Cargo.toml"), &edited).unwrap();
+        TemplateLock {
+            template: "library".to_string(),
+            version: "0.1.0".to_string(),
+            generated_at: Utc::now(),
+            variables: variables.into_iter().collect(),
+            files: old_files,
+        }
+        .save(project.path())
+        .unwrap();
+
+        apply_template("library", project.path(), &sources, false)
+            .await
+            .unwrap();
+        let merged = std::fs::read_to_string(project.path().join("Cargo.toml")).unwrap();
+        assert_eq!(merged, format!("{}# project notes\n", files["Cargo.toml"]));
+        let lock = TemplateLock::load(project.path()).unwrap().unwrap();
+        assert_eq!(lock.files, files);
+        assert_eq!(lock.version, template.manifest.version);
+    }
+}
@@ -1,58 +1,125 @@
 //! Template creation functionality
 
-use crate::templates::{TemplateEngine, TemplateRegistry};
+use super::variables::{VariableSources, resolve_variables};
+use crate::templates::repository::TemplateRepository;
+use crate::templates::{TemplateEngine, TemplateLock, TemplateManifest, TemplateRegistry};
 use crate::{Error, Result};
+use chrono::Utc;
 use console::style;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// A template whose files are on disk, ready for the engine
+pub struct LoadedTemplate {
+    /// Template manifest
+    pub manifest: TemplateManifest,
+    /// Directory holding the template files
+    pub source_dir: PathBuf,
+    /// Keeps the files of a built-in template until the template is dropped
+    _files: Option<TempDir>,
+}
+
+/// Find a built-in or cached template by name
+///
+/// Built-in templates are written to a temporary directory; cached
+/// templates are used from the cache.
+///
+/// # Errors
+///
+/// Returns an error if no template has that name or a built-in template's
+/// files cannot be written.
+pub fn load_template(name: &str) -> Result<LoadedTemplate> {
+    let registry = TemplateRegistry::new();
+    if let Some(template) = registry.get_builtin(name) {
+        let files = TempDir::new()
+            .map_err(|e| Error::template(format!("Failed to create temporary directory: {}", e)))?;
+        for (file_path, content) in &template.files {
+            let full_path = files.path().join(file_path);
+            if let Some(parent) = full_path.parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| Error::template(format!("Failed to create directory: {}", e)))?;
+            }
+            std::fs::write(&full_path, content)
+                .map_err(|e| Error::template(format!("Failed to write file: {}", e)))?;
+        }
+        return Ok(LoadedTemplate {
+            manifest: template.manifest.clone(),
+            source_dir: files.path().to_path_buf(),
+            _files: Some(files),
+        });
+    }
+
+    let repository = TemplateRepository::new()?;
+    let cached = repository
+        .get_cached(name)
+        .ok_or_else(|| Error::template(format!("Template '{}' not found", name)))?;
+    Ok(LoadedTemplate {
+        manifest: cached.manifest.clone(),
+        source_dir: cached.cache_path.clone(),
+        _files: None,
+    })
+}
 
 /// Create a new project from template
 ///
+/// `project_name` fills the template's `project_name` variable unless a
+/// value is given for it. The template, its variables and the rendered
+/// files are recorded in the project's template lock for `template apply`.
+///
 /// # Errors
 ///
-/// Returns an error if the template is not found, variables are invalid,
-/// temporary files cannot be created, or the project generation fails.
+/// Returns an error if the template is not found, variables are missing or
+/// invalid, or the project generation fails.
 pub async fn create_from_template(
     template_name: &str,
+    project_name: &str,
     output_dir: &Path,
-    variables: &[String],
+    sources: &VariableSources,
 ) -> Result<()> {
     display_creation_header(template_name);
 
-    let registry = TemplateRegistry::new();
-    let template = get_template(&registry, template_name)?;
-
-    // Setup template files in temporary directory
-    let template_dir = setup_template_files(&template)?;
+    let template = load_template(template_name)?;
 
-    // Parse and collect variables
-    let mut template_vars = collect_template_variables(variables)?;
-
-    // Prompt for any missing required variables
-    prompt_for_missing_variables(&template, &mut template_vars)?;
-
-    // Create the project using the engine
-    let mut engine = TemplateEngine::new(template.manifest.clone(), template_dir.clone());
-
-    // Set variables
-    for (key, value) in template_vars {
-        engine.set_variable(key, value)?;
+    let mut known = HashMap::new();
+    if template
+        .manifest
+        .variables
+        .iter()
+        .any(|v| v.name == "project_name")
+    {
+        known.insert("project_name".to_string(), project_name.to_string());
     }
-
-    // Generate the project
-    engine.generate(output_dir)?;
+    let variables = resolve_variables(&template.manifest, sources, known)?;
+    generate_project(template_name, &template, variables, output_dir)?;
 
     display_creation_success(output_dir);
     Ok(())
 }
 
-fn get_template<'a>(
-    registry: &'a TemplateRegistry,
+/// Write the project and its template lock, then run the template's
+/// post-generation commands
+///
+/// The lock is saved first, so a project whose commands fail can still be
+/// upgraded with `template apply`.
+fn generate_project(
     template_name: &str,
-) -> Result<&'a crate::templates::registry::BuiltinTemplate> {
-    registry
-        .get_builtin(template_name)
-        .ok_or_else(|| Error::template(format!("Template '{}' not found", template_name)))
+    template: &LoadedTemplate,
+    variables: HashMap<String, String>,
+    output_dir: &Path,
+) -> Result<()> {
+    let mut engine = TemplateEngine::new(template.manifest.clone(), template.source_dir.clone());
+    engine.set_variables(variables.clone())?;
+    engine.write_files(output_dir)?;
+    TemplateLock {
+        template: template_name.to_string(),
+        version: template.manifest.version.clone(),
+        generated_at: Utc::now(),
+        variables: variables.into_iter().collect(),
+        files: engine.render_files()?,
+    }
+    .save(output_dir)?;
+    engine.run_post_generate(output_dir)
 }
 
 fn display_creation_header(template_name: &str) {
@@ -68,89 +135,6 @@ fn display_creation_header(template_name: &str) {
     println!();
 }
 
-fn setup_template_files(template: &crate::templates::registry::BuiltinTemplate) -> Result<PathBuf> {
-    use std::io::Write;
-    use tempfile::TempDir;
-
-    let temp_dir = TempDir::new()
-        .map_err(|e| Error::template(format!("Failed to create temporary directory: {}", e)))?;
-
-    let template_path = temp_dir.path().join("template");
-    std::fs::create_dir_all(&template_path)
-        .map_err(|e| Error::template(format!("Failed to create template directory: {}", e)))?;
-
-    // Write manifest
-    let manifest_path = template_path.join("template.toml");
-    let manifest_toml = toml::to_string(&template.manifest)
-        .map_err(|e| Error::template(format!("Failed to serialize manifest: {}", e)))?;
-    let mut manifest_file = std::fs::File::create(&manifest_path)
-        .map_err(|e| Error::template(format!("Failed to create manifest file: {}", e)))?;
-    manifest_file
-        .write_all(manifest_toml.as_bytes())
-        .map_err(|e| Error::template(format!("Failed to write manifest: {}", e)))?;
-
-    // Write template files
-    for (file_path, content) in &template.files {
-        let full_path = template_path.join(file_path);
-        if let Some(parent) = full_path.parent() {
-            std::fs::create_dir_all(parent)
-                .map_err(|e| Error::template(format!("Failed to create directory: {}", e)))?;
-        }
-
-        let mut file = std::fs::File::create(&full_path)
-            .map_err(|e| Error::template(format!("Failed to create file: {}", e)))?;
-        file.write_all(content.as_bytes())
-            .map_err(|e| Error::template(format!("Failed to write file: {}", e)))?;
-    }
-
-    // Prevent cleanup by returning owned path
-    Ok(template_path)
-}
-
-fn collect_template_variables(variables: &[String]) -> Result<HashMap<String, String>> {
-    let mut template_vars = HashMap::new();
-
-    for var in variables {
-        let (key, value) = parse_var(var)?;
-        template_vars.insert(key, value);
-    }
-
-    Ok(template_vars)
-}
-
-fn parse_var(s: &str) -> Result<(String, String)> {
-    let parts: Vec<&str> = s.splitn(2, '=').collect();
-    if parts.len() != 2 {
-        return Err(Error::template(format!(
-            "Invalid variable format: '{}'. Use key=value",
-            s
-        )));
-    }
-    Ok((parts[0].to_string(), parts[1].to_string()))
-}
-
-fn prompt_for_missing_variables(
-    template: &crate::templates::registry::BuiltinTemplate,
-    template_vars: &mut HashMap<String, String>,
-) -> Result<()> {
-    use dialoguer::Input;
-
-    for var in &template.manifest.variables {
-        if var.required && !template_vars.contains_key(&var.name) {
-            let prompt = format!("{} ({})", var.name, var.description);
-
-            let value: String = Input::new()
-                .with_prompt(&prompt)
-                .interact()
-                .map_err(|e| Error::template(format!("Failed to get input: {}", e)))?;
-
-            template_vars.insert(var.name.clone(), value);
-        }
-    }
-
-    Ok(())
-}
-
 fn display_creation_success(target_dir: &Path) {
     println!();
     println!(
@@ -168,3 +152,34 @@ fn display_creation_success(target_dir: &Path) {
     );
     println!();
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::templates::{TemplateFile, TemplateKind};
+
+    #[test]
+    fn test_lock_is_saved_when_post_generate_fails() {
+        let source = TempDir::new().unwrap();
+        std::fs::write(source.path().join("lib.rs"), "pub fn run() {}\n").unwrap();
+        let mut manifest = TemplateManifest::new("demo".to_string(), TemplateKind::Custom);
+        manifest.add_file(TemplateFile::new(
+            PathBuf::from("lib.rs"),
+            PathBuf::from("src/lib.rs"),
+        ));
+        manifest.post_generate.push("false".to_string());
+        let template = LoadedTemplate {
+            manifest,
+            source_dir: source.path().to_path_buf(),
+            _files: None,
+        };
+
+        let target = TempDir::new().unwrap();
+        let output_dir = target.path().join("demo");
+        assert!(generate_project("demo", &template, HashMap::new(), &output_dir).is_err());
+
+        let lock = TemplateLock::load(&output_dir).unwrap().unwrap();
+        assert_eq!(lock.files["src/lib.rs"], "pub fn run() {}\n");
+    }
+}
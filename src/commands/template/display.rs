@@ -95,6 +95,16 @@ fn display_variable_info(var: &crate::templates::TemplateVariable) {
 
     println!("     {}", style(&var.description).dim());
 
+    match var.kind {
+        crate::templates::VariableKind::String => {}
+        crate::templates::VariableKind::Boolean => println!("     Type: boolean"),
+        crate::templates::VariableKind::List => println!("     Type: comma-separated list"),
+    }
+
+    if !var.choices.is_empty() {
+        println!("     Choices: {}", var.choices.join(", "));
+    }
+
     if let Some(default) = &var.default {
         println!("     Default: {}", style(default).cyan());
     }
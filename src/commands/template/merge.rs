@@ -0,0 +1,194 @@
+//! Line-based three-way merge of template output into project files
+
+use crate::commands::fix::diff::{ChangedRegion, changed_regions};
+
+/// Label of the project side of a conflict
+const PROJECT_LABEL: &str = "project";
+
+/// Text merged from two sides of a common base
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Merged {
+    /// Merged text, with conflict markers around unresolved blocks
+    pub text: String,
+    /// Number of conflicting blocks
+    pub conflicts: usize,
+}
+
+/// Which side of the merge a change came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Project,
+    Template,
+}
+
+/// Merge the changes from `base` to `project` with those from `base` to
+/// `template`
+///
+/// Changes to separate lines are combined. Changes that overlap or touch
+/// conflict unless both sides made the same change; the conflict keeps
+/// both versions between `<<<<<<< project` and `>>>>>>> <label>` markers.
+pub fn merge3(base: &str, project: &str, template: &str, label: &str) -> Merged {
+    let base_lines = lines(base);
+    let mut regions: Vec<(Side, ChangedRegion)> = changed_regions(base, project)
+        .into_iter()
+        .map(|r| (Side::Project, r))
+        .chain(
+            changed_regions(base, template)
+                .into_iter()
+                .map(|r| (Side::Template, r)),
+        )
+        .collect();
+    regions.sort_by_key(|(_, r)| (r.old_start, r.old_end));
+
+    let mut merged = Merged {
+        text: String::new(),
+        conflicts: 0,
+    };
+    let mut pos = 0;
+    let mut i = 0;
+    while i < regions.len() {
+        let start = regions[i].1.old_start;
+        let mut end = regions[i].1.old_end;
+        let mut j = i + 1;
+        while j < regions.len() && regions[j].1.old_start <= end {
+            end = end.max(regions[j].1.old_end);
+            j += 1;
+        }
+
+        let chunk = &regions[i..j];
+        let original: String = base_lines[start..end].concat();
+        let ours = apply_side(&base_lines, start, end, chunk, Side::Project);
+        let theirs = apply_side(&base_lines, start, end, chunk, Side::Template);
+
+        merged.text.push_str(&base_lines[pos..start].concat());
+        if ours == original || ours == theirs {
+            merged.text.push_str(&theirs);
+        } else if theirs == original {
+            merged.text.push_str(&ours);
+        } else {
+            merged.conflicts += 1;
+            merged
+                .text
+                .push_str(&format!("<<<<<<< {}\n", PROJECT_LABEL));
+            push_line_block(&mut merged.text, &ours);
+            merged.text.push_str("=======\n");
+            push_line_block(&mut merged.text, &theirs);
+            merged.text.push_str(&format!(">>>>>>> {}\n", label));
+        }
+        pos = end;
+        i = j;
+    }
+    merged.text.push_str(&base_lines[pos..].concat());
+    merged
+}
+
+/// The lines `project` and `template` share, in order
+///
+/// Used as the base when no earlier rendering of the template is known:
+/// lines only one side has are then kept, and lines both sides changed
+/// differently conflict.
+pub fn common_base(project: &str, template: &str) -> String {
+    let project_lines = lines(project);
+    let mut base = String::new();
+    let mut pos = 0;
+    for region in changed_regions(project, template) {
+        base.push_str(&project_lines[pos..region.old_start].concat());
+        pos = region.old_end;
+    }
+    base.push_str(&project_lines[pos..].concat());
+    base
+}
+
+/// Split `text` into lines that keep their `\n`
+fn lines(text: &str) -> Vec<&str> {
+    text.split_inclusive('\n').collect()
+}
+
+/// Base lines `start..end` with the changes of `side` from `chunk` applied
+fn apply_side(
+    base_lines: &[&str],
+    start: usize,
+    end: usize,
+    chunk: &[(Side, ChangedRegion)],
+    side: Side,
+) -> String {
+    let mut text = String::new();
+    let mut pos = start;
+    for (_, region) in chunk.iter().filter(|(s, _)| *s == side) {
+        text.push_str(&base_lines[pos..region.old_start].concat());
+        text.push_str(&region.replacement);
+        pos = region.old_end;
+    }
+    text.push_str(&base_lines[pos..end].concat());
+    text
+}
+
+/// Append `block`, ending it with a newline so a marker can follow
+fn push_line_block(out: &mut String, block: &str) {
+    out.push_str(block);
+    if !block.is_empty() && !block.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str =
+        "[package]\nname = \"api\"\nedition = \"2021\"\n\n[dependencies]\ntokio = \"1\"\n";
+
+    #[test]
+    fn test_separate_changes_combine() {
+        let project = BASE.replace("tokio = \"1\"\n", "tokio = \"1\"\nsqlx = \"0.8\"\n");
+        let template = BASE.replace("2021", "2024");
+
+        let merged = merge3(BASE, &project, &template, "template");
+        assert_eq!(merged.conflicts, 0);
+        assert_eq!(
+            merged.text,
+            "[package]\nname = \"api\"\nedition = \"2024\"\n\n\
+             [dependencies]\ntokio = \"1\"\nsqlx = \"0.8\"\n"
+        );
+    }
+
+    #[test]
+    fn test_same_change_on_both_sides_is_not_a_conflict() {
+        let changed = BASE.replace("2021", "2024");
+        let merged = merge3(BASE, &changed, &changed, "template");
+        assert_eq!(merged.conflicts, 0);
+        assert_eq!(merged.text, changed);
+    }
+
+    #[test]
+    fn test_overlapping_changes_conflict() {
+        let project = BASE.replace(
+            "tokio = \"1\"",
+            "tokio = { version = \"1\", features = [\"full\"] }",
+        );
+        let template = BASE.replace("tokio = \"1\"", "tokio = \"1.40\"");
+
+        let merged = merge3(BASE, &project, &template, "template web-service 1.1.0");
+        assert_eq!(merged.conflicts, 1);
+        assert!(merged.text.ends_with(
+            "[dependencies]\n<<<<<<< project\n\
+             tokio = { version = \"1\", features = [\"full\"] }\n=======\n\
+             tokio = \"1.40\"\n>>>>>>> template web-service 1.1.0\n"
+        ));
+    }
+
+    #[test]
+    fn test_common_base_keeps_one_sided_lines() {
+        let project = "use a;\nuse project_only;\nfn main() {}\n";
+        let template = "use a;\nfn main() {}\n// template footer\n";
+
+        let base = common_base(project, template);
+        assert_eq!(base, "use a;\nfn main() {}\n");
+        let merged = merge3(&base, project, template, "template");
+        assert_eq!(merged.conflicts, 0);
+        assert_eq!(
+            merged.text,
+            "use a;\nuse project_only;\nfn main() {}\n// template footer\n"
+        );
+    }
+}
@@ -12,13 +12,18 @@ use clap::Subcommand;
 use console::style;
 use std::path::PathBuf;
 
+mod apply;
 mod creation;
 mod display;
+mod merge;
 mod utils;
+mod variables;
 
+pub use apply::*;
 pub use creation::*;
 pub use display::*;
 pub use utils::*;
+pub use variables::*;
 
 /// Template subcommands
 #[derive(Debug, Subcommand)]
@@ -60,8 +65,50 @@ pub enum TemplateCommand {
         check: bool,
     },
 
-    /// Create a new template from current project
+    /// Create a new project from a template
     Create {
+        /// Name of the project
+        name: String,
+        /// Template to create it from (built-in or cached)
+        #[arg(short, long)]
+        template: String,
+        /// Directory to create the project in (defaults to ./<name>)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Set a template variable
+        #[arg(long = "var", value_name = "KEY=VALUE")]
+        vars: Vec<String>,
+        /// Read template variables from a TOML file
+        #[arg(long, value_name = "FILE")]
+        vars_file: Option<PathBuf>,
+        /// Use defaults for variables that are not given instead of prompting
+        #[arg(long)]
+        defaults: bool,
+    },
+
+    /// Merge the current version of a template into a project generated from it
+    Apply {
+        /// Template to apply (built-in or cached)
+        name: String,
+        /// Project directory
+        #[arg(short, long, default_value = ".")]
+        path: PathBuf,
+        /// Set a template variable
+        #[arg(long = "var", value_name = "KEY=VALUE")]
+        vars: Vec<String>,
+        /// Read template variables from a TOML file
+        #[arg(long, value_name = "FILE")]
+        vars_file: Option<PathBuf>,
+        /// Use defaults for new variables instead of prompting
+        #[arg(long)]
+        defaults: bool,
+        /// Show the changes without writing them
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Create a new template from current project
+    FromProject {
         /// Name for the new template
         name: String,
         /// Output directory for template
@@ -123,6 +170,39 @@ impl TemplateCommand {
             }
 
             TemplateCommand::Create {
+                name,
+                template,
+                output,
+                vars,
+                vars_file,
+                defaults,
+            } => {
+                let output_dir = output.clone().unwrap_or_else(|| PathBuf::from(name));
+                let sources = VariableSources {
+                    vars: vars.clone(),
+                    vars_file: vars_file.clone(),
+                    defaults: *defaults,
+                };
+                create_from_template(template, name, &output_dir, &sources).await
+            }
+
+            TemplateCommand::Apply {
+                name,
+                path,
+                vars,
+                vars_file,
+                defaults,
+                dry_run,
+            } => {
+                let sources = VariableSources {
+                    vars: vars.clone(),
+                    vars_file: vars_file.clone(),
+                    defaults: *defaults,
+                };
+                apply_template(name, path, &sources, *dry_run).await
+            }
+
+            TemplateCommand::FromProject {
                 name,
                 output,
                 project,
@@ -190,7 +270,7 @@ async fn list_templates(remote: bool) -> Result<()> {
     );
     println!(
         "Use {} to create a project from a template",
-        style("ferrous-forge template install <name>").cyan()
+        style("ferrous-forge template create <project> --template <name>").cyan()
     );
 
     if remote {
@@ -314,7 +394,7 @@ async fn install_template(name: &str, _as_name: Option<&str>) -> Result<()> {
         println!(
             "Use {} to create a project from this template",
             style(format!(
-                "ferrous-forge template create {} <output-dir>",
+                "ferrous-forge template create <project> --template {}",
                 name
             ))
             .cyan()
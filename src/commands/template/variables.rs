@@ -0,0 +1,240 @@
+//! Resolve template variables from flags, a vars file and prompts
+
+use super::utils::collect_template_variables;
+use crate::templates::manifest::parse_bool;
+use crate::templates::{TemplateManifest, TemplateVariable, VariableKind};
+use crate::{Error, Result};
+use dialoguer::{Confirm, Input, Select};
+use std::collections::HashMap;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
+/// Where template variable values come from
+#[derive(Debug, Clone, Default)]
+pub struct VariableSources {
+    /// `key=value` pairs from `--var`
+    pub vars: Vec<String>,
+    /// TOML file of values from `--vars-file`
+    pub vars_file: Option<PathBuf>,
+    /// Use defaults for every variable not given instead of prompting
+    pub defaults: bool,
+}
+
+/// Values for every variable of `manifest`
+///
+/// `known` values are overridden by the vars file, which is overridden by
+/// `--var`. Variables still unset are prompted for when stdin is a
+/// terminal and `--defaults` is not given; otherwise they take their
+/// default.
+///
+/// # Errors
+///
+/// Returns an error if the vars file cannot be read, a value is invalid,
+/// a prompt fails, or a required variable without a default is left unset.
+pub fn resolve_variables(
+    manifest: &TemplateManifest,
+    sources: &VariableSources,
+    known: HashMap<String, String>,
+) -> Result<HashMap<String, String>> {
+    let mut values = known;
+    if let Some(path) = &sources.vars_file {
+        values.extend(read_vars_file(path)?);
+    }
+    values.extend(collect_template_variables(&sources.vars)?);
+
+    let interactive = !sources.defaults && std::io::stdin().is_terminal();
+    let mut missing = Vec::new();
+    for var in &manifest.variables {
+        if let Some(value) = values.get(&var.name) {
+            var.validate(value)?;
+        } else if interactive {
+            values.insert(var.name.clone(), prompt(var)?);
+        } else if let Some(default) = &var.default {
+            values.insert(var.name.clone(), default.clone());
+        } else if var.required {
+            missing.push(var.name.as_str());
+        }
+    }
+
+    if !missing.is_empty() {
+        return Err(Error::template(format!(
+            "Missing required variables: {} (pass --var key=value or --vars-file)",
+            missing.join(", ")
+        )));
+    }
+    Ok(values)
+}
+
+/// Read `key = value` pairs from a TOML file
+///
+/// Booleans and numbers become their text, and arrays become
+/// comma-separated lists.
+fn read_vars_file(path: &Path) -> Result<HashMap<String, String>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| Error::template(format!("Failed to read {}: {}", path.display(), e)))?;
+    let table: toml::Table = toml::from_str(&content)
+        .map_err(|e| Error::template(format!("Invalid vars file {}: {}", path.display(), e)))?;
+
+    let scalar = |key: &str, value: &toml::Value| match value {
+        toml::Value::String(s) => Ok(s.clone()),
+        toml::Value::Boolean(_) | toml::Value::Integer(_) | toml::Value::Float(_) => {
+            Ok(value.to_string())
+        }
+        _ => Err(Error::template(format!(
+            "Unsupported value for '{}' in {}",
+            key,
+            path.display()
+        ))),
+    };
+
+    let mut values = HashMap::new();
+    for (key, value) in &table {
+        let text = match value {
+            toml::Value::Array(items) => items
+                .iter()
+                .map(|item| scalar(key, item))
+                .collect::<Result<Vec<_>>>()?
+                .join(","),
+            other => scalar(key, other)?,
+        };
+        values.insert(key.clone(), text);
+    }
+    Ok(values)
+}
+
+/// Ask for the value of `var`, as a yes/no question, a list of choices or
+/// validated text
+fn prompt(var: &TemplateVariable) -> Result<String> {
+    let failed = |e: dialoguer::Error| Error::template(format!("Failed to get input: {}", e));
+    let prompt = match var.kind {
+        VariableKind::List => format!("{} ({}, comma-separated)", var.name, var.description),
+        _ => format!("{} ({})", var.name, var.description),
+    };
+
+    if var.kind == VariableKind::Boolean {
+        let default = var.default.as_deref().and_then(parse_bool).unwrap_or(false);
+        let value = Confirm::new()
+            .with_prompt(prompt)
+            .default(default)
+            .interact()
+            .map_err(failed)?;
+        return Ok(value.to_string());
+    }
+
+    if !var.choices.is_empty() {
+        let default = var
+            .default
+            .as_ref()
+            .and_then(|d| var.choices.iter().position(|c| c == d))
+            .unwrap_or(0);
+        let index = Select::new()
+            .with_prompt(prompt)
+            .items(&var.choices)
+            .default(default)
+            .interact()
+            .map_err(failed)?;
+        return Ok(var.choices[index].clone());
+    }
+
+    let mut input = Input::<String>::new()
+        .with_prompt(prompt)
+        .allow_empty(!var.required)
+        .validate_with(|value: &String| var.validate(value).map_err(|e| e.to_string()));
+    if let Some(default) = &var.default {
+        input = input.default(default.clone());
+    }
+    input.interact_text().map_err(failed)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::templates::TemplateKind;
+    use tempfile::TempDir;
+
+    fn manifest() -> TemplateManifest {
+        let mut manifest = TemplateManifest::new("demo".to_string(), TemplateKind::Custom);
+        manifest.add_variable(
+            TemplateVariable::required("project_name".to_string(), "Name".to_string())
+                .with_pattern("^[a-z-]+$".to_string()),
+        );
+        manifest.add_variable(
+            TemplateVariable::optional("use_async".to_string(), "Async".to_string(), "no".into())
+                .with_kind(VariableKind::Boolean),
+        );
+        manifest.add_variable(
+            TemplateVariable::optional("db".to_string(), "Database".to_string(), "none".into())
+                .with_choices(vec!["none".to_string(), "postgres".to_string()]),
+        );
+        manifest
+    }
+
+    #[test]
+    fn test_flags_override_vars_file_and_defaults_fill_the_rest() {
+        let dir = TempDir::new().unwrap();
+        let vars_file = dir.path().join("vars.toml");
+        std::fs::write(
+            &vars_file,
+            "project_name = \"from-file\"\nuse_async = true\n",
+        )
+        .unwrap();
+        let sources = VariableSources {
+            vars: vec!["project_name=from-flag".to_string()],
+            vars_file: Some(vars_file),
+            defaults: true,
+        };
+
+        let values = resolve_variables(&manifest(), &sources, HashMap::new()).unwrap();
+        assert_eq!(values["project_name"], "from-flag");
+        assert_eq!(values["use_async"], "true");
+        assert_eq!(values["db"], "none");
+    }
+
+    #[test]
+    fn test_invalid_and_missing_values_are_rejected() {
+        let defaults = |vars: &[&str]| VariableSources {
+            vars: vars.iter().map(|v| v.to_string()).collect(),
+            defaults: true,
+            ..VariableSources::default()
+        };
+        let manifest = manifest();
+
+        let missing = resolve_variables(&manifest, &defaults(&[]), HashMap::new());
+        assert!(missing.unwrap_err().to_string().contains("project_name"));
+        for invalid in ["project_name=Bad_Name", "db=mysql", "use_async=maybe"] {
+            let sources = defaults(&["project_name=ok", invalid]);
+            assert!(resolve_variables(&manifest, &sources, HashMap::new()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_optional_values_may_be_empty() {
+        let mut manifest = manifest();
+        let mut author =
+            TemplateVariable::optional("author".to_string(), "Author".to_string(), String::new())
+                .with_pattern("^[A-Z].*$".to_string());
+        author.default = None;
+        manifest.add_variable(author);
+        let sources = VariableSources {
+            vars: vec!["project_name=ok".to_string(), "author=".to_string()],
+            defaults: true,
+            ..VariableSources::default()
+        };
+
+        let values = resolve_variables(&manifest, &sources, HashMap::new()).unwrap();
+        assert_eq!(values["author"], "");
+        assert!(
+            resolve_variables(
+                &manifest,
+                &VariableSources {
+                    vars: vec!["project_name=".to_string()],
+                    defaults: true,
+                    ..VariableSources::default()
+                },
+                HashMap::new()
+            )
+            .is_err()
+        );
+    }
+}
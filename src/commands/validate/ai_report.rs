@@ -1,7 +1,7 @@
 //! AI-friendly compliance report generation
 
 use super::{markdown::generate_markdown_report, utils};
-use crate::{Result, validation::Violation};
+use crate::{Result, output, validation::Violation};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -132,7 +132,7 @@ async fn create_ai_violations(violations: &[Violation]) -> Vec<AIViolation> {
             get_fix_suggestion(&violation.violation_type, &violation.message);
 
         ai_violations.push(AIViolation {
-            violation_type: format!("{:?}", violation.violation_type),
+            violation_type: violation.violation_type.label(),
             file: violation.file.display().to_string(),
             line: violation.line,
             message: violation.message.clone(),
@@ -147,9 +147,19 @@ async fn create_ai_violations(violations: &[Violation]) -> Vec<AIViolation> {
 
 fn get_fix_suggestion(
     violation_type: &crate::validation::ViolationType,
-    _message: &str,
+    message: &str,
 ) -> (String, bool, u8) {
     match violation_type {
+        crate::validation::ViolationType::CustomRule { name } => (
+            format!("Resolve custom rule '{}': {}", name, message),
+            false,
+            2,
+        ),
+        crate::validation::ViolationType::CompilerDiagnostic { code } => (
+            format!("Resolve `{}`: {}", code, message),
+            message.contains("(auto-fixable:"),
+            1,
+        ),
         crate::validation::ViolationType::UnderscoreBandaid => (
             "Remove unused parameter or implement missing functionality".to_string(),
             true,
@@ -193,7 +203,8 @@ fn generate_fix_instructions(violation_counts: HashMap<String, usize>) -> Vec<Fi
     fix_instructions
 }
 
-fn get_fix_strategy(vtype: &str) -> (String, String, String) {
+/// Fix strategy, example and effort estimate for a violation type label
+pub(crate) fn get_fix_strategy(vtype: &str) -> (String, String, String) {
     match vtype {
         "UnderscoreBandaid" => (
             "1. Identify what functionality the parameter should provide\n\
@@ -222,6 +233,30 @@ fn get_fix_strategy(vtype: &str) -> (String, String, String) {
                 .to_string(),
             "Hard".to_string(),
         ),
+        "InvalidSuppression" => (
+            "1. Add `reason = \"...\"` explaining why the violation is acceptable\n\
+            2. Remove suppressions that no longer match a violation\n\
+            3. Check the rule name against `ferrous-forge validate --list-suppressions`"
+                .to_string(),
+            "// ferrous-forge: allow(unwrap_in_production, reason = \"checked above\")".to_string(),
+            "Easy".to_string(),
+        ),
+        custom if custom.starts_with("CustomRule(") => (
+            "1. Read the rule message attached to each violation\n\
+            2. Rewrite the matched code to avoid the banned pattern\n\
+            3. If the rule no longer applies, disable it in config"
+                .to_string(),
+            "// See `custom_rules` in .ferrous-forge/config.toml for the pattern".to_string(),
+            "Moderate".to_string(),
+        ),
+        compiler if compiler.starts_with("CompilerDiagnostic(") => (
+            "1. Read the lint explanation (`rustc --explain` or the clippy lint list)\n\
+            2. Apply the compiler's suggestion, or run `ferrous-forge fix` for auto-fixable ones\n\
+            3. Re-run `ferrous-forge validate` to confirm the diagnostic is gone"
+                .to_string(),
+            "// cargo clippy --fix applies machine-applicable suggestions".to_string(),
+            "Easy".to_string(),
+        ),
         _ => (
             "Review and fix manually".to_string(),
             "".to_string(),
@@ -284,17 +319,20 @@ async fn save_and_link_reports(
 }
 
 fn print_report_summary(reports_dir: &Path, timestamp_str: &str) {
-    println!("🤖 AI compliance report generated:");
-    println!(
+    output::human("🤖 AI compliance report generated:");
+    output::human(format!(
         "  📄 JSON: {}/ai_compliance_{}.json",
         reports_dir.display(),
         timestamp_str
-    );
-    println!(
+    ));
+    output::human(format!(
         "  📝 Markdown: {}/ai_compliance_{}.md",
         reports_dir.display(),
         timestamp_str
-    );
-    println!("  🔗 Latest: {}/latest_ai_report.*", reports_dir.display());
-    println!();
+    ));
+    output::human(format!(
+        "  🔗 Latest: {}/latest_ai_report.*",
+        reports_dir.display()
+    ));
+    output::human("");
 }
@@ -1,7 +1,7 @@
 //! Additional validation checks (documentation, formatting, security, test coverage)
 
 use crate::{
-    Result, doc_coverage, formatting, security, test_coverage::CoverageAnalyzer,
+    Result, doc_coverage, formatting, output, security, test_coverage::CoverageAnalyzer,
     validation::RustValidator,
 };
 use std::path::Path;
@@ -125,15 +125,26 @@ async fn check_test_coverage(project_path: &Path) {
 pub async fn run_clippy_validation(
     validator: &RustValidator,
 ) -> Result<crate::validation::ClippyResult> {
-    println!("🔧 Running Clippy with strict configuration...");
+    output::human("🔧 Running Clippy with strict configuration...");
 
     let clippy_result = validator.run_clippy().await?;
 
-    if !clippy_result.success {
-        println!("❌ Clippy found issues:");
-        println!("{}", clippy_result.output);
+    if clippy_result.success && clippy_result.diagnostics.is_empty() {
+        output::human("✅ Clippy validation passed!");
+    } else if clippy_result.failure_is_reported() {
+        let fixable = clippy_result
+            .diagnostics
+            .iter()
+            .filter(|d| d.machine_applicable().next().is_some())
+            .count();
+        output::human(format!(
+            "❌ Clippy found {} issues ({} auto-fixable), reported as violations",
+            clippy_result.diagnostics.len(),
+            fixable
+        ));
     } else {
-        println!("✅ Clippy validation passed!");
+        output::human("❌ Clippy failed:");
+        output::human(&clippy_result.output);
     }
 
     Ok(clippy_result)
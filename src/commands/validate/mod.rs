@@ -14,22 +14,183 @@ use checks::{run_additional_checks, run_clippy_validation};
 
 use crate::{
     Result,
-    config::Config,
-    validation::{RustValidator, Violation, ViolationType},
+    config::HierarchicalConfig,
+    output::{
+        self,
+        sarif::{self, SarifBuilder},
+    },
+    validation::{
+        Baseline, BaselineComparison, BaselineEntry, ClippyResult, PackageSelection, RustValidator,
+        Severity, Suppression, Violation, ViolationType, Workspace, changed_files::changed_files,
+    },
 };
 use console::style;
 use fs2::FileExt;
+use serde::Serialize;
 use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
+/// Structured result of the validate command for `--format json|yaml`
+#[derive(Debug, Serialize)]
+pub struct ValidationOutput {
+    /// Project that was validated
+    pub project_path: PathBuf,
+    /// Whether validation passed (no blocking violations and clippy
+    /// succeeded)
+    pub passed: bool,
+    /// Violation counts
+    pub summary: ViolationSummary,
+    /// Every violation found
+    pub violations: Vec<Violation>,
+    /// Clippy result (absent for `--locked-only`)
+    pub clippy: Option<ClippyResult>,
+    /// Baseline comparison (all zero when there is no baseline)
+    pub baseline: BaselineSummary,
+    /// Per-member results (empty unless several workspace members were
+    /// validated)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<MemberSummary>,
+}
+
+/// Violation counts for one workspace member
+#[derive(Debug, Serialize)]
+pub struct MemberSummary {
+    /// Package name
+    pub name: String,
+    /// Member directory
+    pub path: PathBuf,
+    /// Whether the member has no blocking violations
+    pub passed: bool,
+    /// Violation counts
+    pub summary: ViolationSummary,
+}
+
+/// How the run compared against `.ferrous-forge/baseline.json`
+#[derive(Debug, Default, Serialize)]
+pub struct BaselineSummary {
+    /// Violations accepted by the baseline and not counted above
+    pub baselined: usize,
+    /// Baseline entries whose violation no longer occurs
+    pub fixed: Vec<BaselineEntry>,
+}
+
+impl BaselineSummary {
+    fn from_comparison(comparison: &BaselineComparison) -> Self {
+        Self {
+            baselined: comparison.baselined.len(),
+            fixed: comparison.fixed.clone(),
+        }
+    }
+}
+
+/// Structured result of `validate --list-suppressions`
+#[derive(Debug, Serialize)]
+pub struct SuppressionsOutput {
+    /// Project that was audited
+    pub project_path: PathBuf,
+    /// Number of suppressions found
+    pub total: usize,
+    /// Suppressions without a reason (these have no effect)
+    pub unjustified: usize,
+    /// Justified suppressions that allowed no violation
+    pub unused: usize,
+    /// Every suppression, in file order
+    pub suppressions: Vec<Suppression>,
+}
+
+/// Structured result when another validation of the project is running
+#[derive(Debug, Serialize)]
+pub struct SkippedOutput {
+    /// Project that was not validated
+    pub project_path: PathBuf,
+    /// Why validation was skipped (`locked`)
+    pub skipped: &'static str,
+}
+
+/// Violation counts for structured output
+#[derive(Debug, Serialize)]
+pub struct ViolationSummary {
+    /// Total number of violations
+    pub total: usize,
+    /// Violations with `Error` severity
+    pub errors: usize,
+    /// Violations with `Warning` severity
+    pub warnings: usize,
+    /// Violations with `Info` severity, which never fail validation
+    pub info: usize,
+    /// Count per violation type label
+    pub by_type: BTreeMap<String, usize>,
+}
+
+impl ViolationSummary {
+    /// Summarize a set of violations
+    pub fn from_violations(violations: &[Violation]) -> Self {
+        let mut by_type = BTreeMap::new();
+        for violation in violations {
+            *by_type.entry(violation.violation_type.label()).or_insert(0) += 1;
+        }
+        Self {
+            total: violations.len(),
+            errors: count_severity(violations, &Severity::Error),
+            warnings: count_severity(violations, &Severity::Warning),
+            info: count_severity(violations, &Severity::Info),
+            by_type,
+        }
+    }
+}
+
+fn count_severity(violations: &[Violation], severity: &Severity) -> usize {
+    violations
+        .iter()
+        .filter(|v| &v.severity == severity)
+        .count()
+}
+
+/// Flags of the validate command
+#[derive(Debug, Default)]
+pub struct ValidateOptions {
+    /// Generate the AI compliance report
+    pub ai_report: bool,
+    /// Only report locked setting violations
+    pub locked_only: bool,
+    /// Also write a SARIF log to this path
+    pub sarif: Option<PathBuf>,
+    /// Record current violations as the baseline and exit
+    pub write_baseline: bool,
+    /// Print the suppression audit and exit
+    pub list_suppressions: bool,
+    /// Only validate files changed since the last commit
+    pub changed_only: bool,
+    /// Worker threads for file validation (overrides `validation_jobs`)
+    pub jobs: Option<usize>,
+    /// Show the slowest files and checks
+    pub timings: bool,
+    /// Workspace members to validate (`-p`, `--workspace`, `--exclude`)
+    pub selection: PackageSelection,
+}
+
 /// Execute the validate command
 ///
 /// # Errors
 ///
 /// Returns an error if the configuration cannot be loaded, the validator
-/// fails to initialize, or the validation process encounters an I/O error.
-pub async fn execute(path: Option<PathBuf>, ai_report: bool, locked_only: bool) -> Result<()> {
+/// fails to initialize, the validation process encounters an I/O error, or
+/// the SARIF log or baseline cannot be written.
+pub async fn execute(path: Option<PathBuf>, options: ValidateOptions) -> Result<()> {
+    let ValidateOptions {
+        ai_report,
+        locked_only,
+        sarif,
+        write_baseline,
+        list_suppressions,
+        changed_only,
+        jobs,
+        timings,
+        selection,
+    } = options;
     let project_path = path.unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+    let (project_path, workspace) = load_workspace(project_path, &selection).await?;
 
     // Acquire process lock to prevent concurrent validation deadlocks.
     // Two simultaneous `ferrous-forge validate` invocations (e.g. from parallel
@@ -38,11 +199,19 @@ pub async fn execute(path: Option<PathBuf>, ai_report: bool, locked_only: bool)
     let _lock_guard = match try_acquire_lock(&project_path) {
         Ok(Some(file)) => Some(file),
         Ok(None) => {
-            println!(
-                "{}",
+            output::human(
                 style("Another ferrous-forge validation is running for this project, skipping.")
-                    .yellow()
+                    .yellow(),
             );
+            if output::is_machine_readable() {
+                return output::emit(
+                    "validate",
+                    &SkippedOutput {
+                        project_path,
+                        skipped: "locked",
+                    },
+                );
+            }
             return Ok(());
         }
         Err(e) => {
@@ -55,36 +224,329 @@ pub async fn execute(path: Option<PathBuf>, ai_report: bool, locked_only: bool)
 
     print_header(&project_path);
 
-    // Load config so validators use project-configured limits and locked settings
-    let config = Config::load_or_default().await?;
-    let validator = RustValidator::with_config(project_path.clone(), config)?;
-    let violations = validator.validate_project().await?;
+    // Resolve config for the validated project, not the current directory,
+    // so validators use its limits and locked settings
+    let hierarchy = HierarchicalConfig::load_for_project(&project_path).await?;
+    let mut validator = RustValidator::with_hierarchy(project_path.clone(), &hierarchy)?;
+    if let Some(workspace) = &workspace {
+        validator = validator.with_workspace(workspace, &selection).await?;
+    }
+    if changed_only {
+        let changed = changed_files(&project_path).await?;
+        output::human(format!(
+            "🔀 Validating {} changed files",
+            changed
+                .iter()
+                .filter(|f| f.extension().is_some_and(|e| e == "rs"))
+                .count()
+        ));
+        output::human("");
+        validator = validator.only_files(changed);
+    }
+    if let Some(jobs) = jobs {
+        validator = validator.with_jobs(jobs);
+    }
+    if timings {
+        validator = validator.with_timings();
+    }
+
+    if list_suppressions {
+        let suppressions = validator.list_suppressions().await?;
+        return display_suppressions(&project_path, suppressions);
+    }
+
+    if write_baseline {
+        let mut violations = validator.validate_project().await?;
+        let clippy_result = run_clippy_validation(&validator).await?;
+        validator.add_clippy_violations(&mut violations, &clippy_result);
+        return write_baseline_file(&project_path, &violations);
+    }
+
+    // Clippy findings are violations too, so they are baselined and
+    // reported with the rest; lints an AST check already reported are dropped
+    let clippy_result = if locked_only {
+        None
+    } else {
+        Some(run_clippy_validation(&validator).await?)
+    };
+    let mut violations = validator.validate_project().await?;
+    if let Some(clippy_result) = &clippy_result {
+        validator.add_clippy_violations(&mut violations, clippy_result);
+    }
+    let comparison = validator.compare_with_baseline(violations)?;
+    let baseline = BaselineSummary::from_comparison(&comparison);
+    let violations = comparison.new_violations;
+    if timings {
+        display_timings(&validator);
+    }
 
     if locked_only {
-        return handle_locked_only_check(&violations);
+        let locked = locked_violations(&violations);
+        if let Some(sarif_path) = &sarif {
+            write_sarif(&project_path, &locked, sarif_path).await?;
+        }
+        let members = member_summaries(&validator, &locked);
+        return handle_locked_only_check(&project_path, locked, baseline, members);
+    }
+
+    if let Some(sarif_path) = &sarif {
+        write_sarif(&project_path, &violations, sarif_path).await?;
     }
 
-    display_validation_results(&validator, &violations)?;
+    let members = member_summaries(&validator, &violations);
+    if !output::is_machine_readable() {
+        display_validation_results(&validator, &violations)?;
+        display_member_summaries(&members);
+        display_baseline_summary(&baseline);
+    }
 
     if ai_report {
         generate_ai_report_with_message(&project_path, &violations).await?;
     }
 
-    let clippy_result = run_clippy_validation(&validator).await?;
-    run_additional_checks(&project_path).await;
+    // The additional checks are informational and human-only; structured
+    // output reports the violations and clippy result that decide the exit code.
+    if !output::is_machine_readable() {
+        run_additional_checks(&project_path).await;
+    }
 
-    handle_final_result(&violations, &clippy_result);
+    handle_final_result(&project_path, violations, clippy_result, baseline, members)
+}
 
-    Ok(())
+/// Read the cargo workspace containing `project_path`
+///
+/// With `-p` or `--workspace`, validation starts from the workspace root,
+/// which is returned in place of `project_path`. Without them, a project
+/// outside any cargo workspace is validated without member information.
+async fn load_workspace(
+    project_path: PathBuf,
+    selection: &PackageSelection,
+) -> Result<(PathBuf, Option<Workspace>)> {
+    let workspace = match Workspace::load(&project_path).await {
+        Ok(workspace) => workspace,
+        Err(e) if !selection.is_workspace_wide() => {
+            tracing::debug!("Validating without workspace members: {}", e);
+            return Ok((project_path, None));
+        }
+        Err(e) => return Err(e),
+    };
+    if !selection.is_workspace_wide() || workspace.root == project_path {
+        return Ok((project_path, Some(workspace)));
+    }
+    // Reload from the root so member paths match the files found there
+    let root = workspace.root;
+    let workspace = Workspace::load(&root).await?;
+    Ok((root, Some(workspace)))
 }
 
-fn print_header(project_path: &Path) {
+/// Violation counts per validated workspace member, when there are several
+fn member_summaries(validator: &RustValidator, violations: &[Violation]) -> Vec<MemberSummary> {
+    let members = validator.selected_members();
+    if members.len() < 2 {
+        return Vec::new();
+    }
+    members
+        .into_iter()
+        .map(|(name, path)| {
+            let own: Vec<Violation> = violations
+                .iter()
+                .filter(|v| validator.member_name(&v.file) == Some(name))
+                .cloned()
+                .collect();
+            MemberSummary {
+                name: name.to_string(),
+                path: path.to_path_buf(),
+                passed: !own.iter().any(|v| v.severity.is_blocking()),
+                summary: ViolationSummary::from_violations(&own),
+            }
+        })
+        .collect()
+}
+
+/// Print one line per workspace member
+fn display_member_summaries(members: &[MemberSummary]) {
+    if members.is_empty() {
+        return;
+    }
+    let width = members.iter().map(|m| m.name.len()).max().unwrap_or(0);
+    println!("{}", style("📦 Workspace members:").bold());
+    for member in members {
+        let icon = if member.passed { "✅" } else { "❌" };
+        let summary = &member.summary;
+        println!(
+            "  {} {:<width$}  {} errors, {} warnings, {} info",
+            icon,
+            member.name,
+            summary.errors,
+            summary.warnings,
+            summary.info,
+            width = width
+        );
+    }
     println!();
-    println!("{}", style("🦀 Running Ferrous Forge validation...").bold());
+}
+
+/// Record the current violations as the project's baseline
+fn write_baseline_file(project_path: &Path, violations: &[Violation]) -> Result<()> {
+    let baseline = Baseline::from_violations(project_path, violations);
+    let path = baseline.save(project_path)?;
+    output::human(
+        style(format!(
+            "📌 Baseline written to {} ({} violations recorded)",
+            path.display(),
+            baseline.entries.len()
+        ))
+        .green(),
+    );
+    output::human("   Only violations not in the baseline will fail validation from now on.");
+    Ok(())
+}
+
+/// Print the slowest files and the total time spent in each check
+///
+/// Timings go to stderr so structured output on stdout stays parseable.
+fn display_timings(validator: &RustValidator) {
+    let (mut files, mut checks): (Vec<_>, Vec<_>) = validator
+        .timings()
+        .into_iter()
+        .partition(|(name, _)| name.starts_with("file:"));
+    files.sort_by_key(|(_, m)| std::cmp::Reverse(m.validation_time_us));
+    checks.sort_by_key(|(_, m)| std::cmp::Reverse(m.validation_time_us));
+
+    let ms = |us: u64| us as f64 / 1000.0;
+    eprintln!("{}", style("⏱️  Slowest files:").bold());
+    for (name, metrics) in files.iter().take(10) {
+        eprintln!(
+            "  {:>9.2}ms  {}",
+            ms(metrics.validation_time_us),
+            name.trim_start_matches("file:")
+        );
+    }
+    eprintln!("{}", style("⏱️  Checks (total across files):").bold());
+    if checks.is_empty() {
+        eprintln!("  no checks ran; every file was served from the validation cache");
+    }
+    for (name, metrics) in &checks {
+        eprintln!(
+            "  {:>9.2}ms  {} ({} files)",
+            ms(metrics.validation_time_us),
+            name.trim_start_matches("check:"),
+            metrics.files_processed
+        );
+    }
+    eprintln!();
+}
+
+/// Report every inline suppression with its reason and usage
+fn display_suppressions(project_path: &Path, suppressions: Vec<Suppression>) -> Result<()> {
+    let unjustified = suppressions.iter().filter(|s| !s.is_justified()).count();
+    let unused = suppressions
+        .iter()
+        .filter(|s| s.is_justified() && s.used == 0)
+        .count();
+
+    if output::is_machine_readable() {
+        return output::emit(
+            "validate",
+            &SuppressionsOutput {
+                project_path: project_path.to_path_buf(),
+                total: suppressions.len(),
+                unjustified,
+                unused,
+                suppressions,
+            },
+        );
+    }
+
+    if suppressions.is_empty() {
+        println!("{}", style("No inline suppressions found.").green());
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        style(format!("🔕 {} inline suppressions:", suppressions.len())).bold()
+    );
     println!();
+    for suppression in &suppressions {
+        let location = suppression
+            .file
+            .strip_prefix(project_path)
+            .unwrap_or(&suppression.file);
+        println!(
+            "  {}:{} [{}] allow({})",
+            location.display(),
+            suppression.line,
+            suppression.scope,
+            suppression.rules.join(", ")
+        );
+        match (&suppression.reason, suppression.used) {
+            (Some(reason), used) if suppression.is_justified() => {
+                println!("      reason: {}", reason);
+                if used == 0 {
+                    println!("      {}", style("unused: no matching violation").yellow());
+                } else {
+                    println!("      allowed {} violation(s)", used);
+                }
+            }
+            _ => println!(
+                "      {}",
+                style("no reason given: suppression ignored").red()
+            ),
+        }
+    }
 
-    println!("📁 Project: {}", project_path.display());
     println!();
+    if unjustified > 0 || unused > 0 {
+        println!(
+            "{}",
+            style(format!(
+                "⚠️  {} without a reason, {} unused; both fail validation",
+                unjustified, unused
+            ))
+            .yellow()
+        );
+    }
+    Ok(())
+}
+
+/// Print how many violations the baseline suppressed and which were fixed
+fn display_baseline_summary(baseline: &BaselineSummary) {
+    if baseline.baselined > 0 {
+        println!(
+            "📌 {} existing violations accepted by .ferrous-forge/baseline.json",
+            baseline.baselined
+        );
+    }
+    if !baseline.fixed.is_empty() {
+        println!(
+            "{}",
+            style(format!(
+                "🎉 {} baseline entries have been fixed:",
+                baseline.fixed.len()
+            ))
+            .green()
+        );
+        for entry in baseline.fixed.iter().take(10) {
+            println!("  {} - {}", entry.file.display(), entry.violation_type);
+        }
+        if baseline.fixed.len() > 10 {
+            println!("  ... and {} more", baseline.fixed.len() - 10);
+        }
+        println!("   Run 'ferrous-forge validate --write-baseline' to shrink the baseline.");
+    }
+    if baseline.baselined > 0 || !baseline.fixed.is_empty() {
+        println!();
+    }
+}
+
+fn print_header(project_path: &Path) {
+    output::human("");
+    output::human(style("🦀 Running Ferrous Forge validation...").bold());
+    output::human("");
+
+    output::human(format!("📁 Project: {}", project_path.display()));
+    output::human("");
 }
 
 fn display_validation_results(validator: &RustValidator, violations: &[Violation]) -> Result<()> {
@@ -93,9 +555,18 @@ fn display_validation_results(validator: &RustValidator, violations: &[Violation
     Ok(())
 }
 
-/// When --locked-only is set, only report and fail on locked setting violations
-fn handle_locked_only_check(violations: &[Violation]) -> Result<()> {
-    let locked: Vec<&Violation> = violations
+/// Write violations as a SARIF log
+async fn write_sarif(project_path: &Path, violations: &[Violation], path: &Path) -> Result<()> {
+    let mut builder = SarifBuilder::new(project_path);
+    builder.add_violations(violations);
+    sarif::write_log(&builder.build(), path).await?;
+    output::human(format!("📄 SARIF log written to {}", path.display()));
+    Ok(())
+}
+
+/// Violations of locked settings (edition, rust-version, locked config keys)
+fn locked_violations(violations: &[Violation]) -> Vec<Violation> {
+    violations
         .iter()
         .filter(|v| {
             matches!(
@@ -105,7 +576,36 @@ fn handle_locked_only_check(violations: &[Violation]) -> Result<()> {
                     | ViolationType::LockedSetting
             )
         })
-        .collect();
+        .cloned()
+        .collect()
+}
+
+/// When --locked-only is set, only report and fail on locked setting violations
+fn handle_locked_only_check(
+    project_path: &Path,
+    locked: Vec<Violation>,
+    baseline: BaselineSummary,
+    members: Vec<MemberSummary>,
+) -> Result<()> {
+    if output::is_machine_readable() {
+        let passed = locked.is_empty();
+        output::emit(
+            "validate",
+            &ValidationOutput {
+                project_path: project_path.to_path_buf(),
+                passed,
+                summary: ViolationSummary::from_violations(&locked),
+                violations: locked,
+                clippy: None,
+                baseline,
+                members,
+            },
+        )?;
+        if !passed {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
 
     if locked.is_empty() {
         println!("{}", style("✅ No locked setting violations.").green());
@@ -126,8 +626,42 @@ async fn generate_ai_report_with_message(
     generate_ai_report(project_path, violations).await
 }
 
-fn handle_final_result(violations: &[Violation], clippy_result: &crate::validation::ClippyResult) {
-    if !violations.is_empty() || !clippy_result.success {
+/// Whether validation passes: no error-level violations, and every clippy
+/// failure reported as a violation
+fn validation_passed(violations: &[Violation], clippy_result: Option<&ClippyResult>) -> bool {
+    !violations.iter().any(|v| v.severity.is_blocking())
+        && clippy_result.is_none_or(ClippyResult::failure_is_reported)
+}
+
+fn handle_final_result(
+    project_path: &Path,
+    violations: Vec<Violation>,
+    clippy_result: Option<ClippyResult>,
+    baseline: BaselineSummary,
+    members: Vec<MemberSummary>,
+) -> Result<()> {
+    let passed = validation_passed(&violations, clippy_result.as_ref());
+
+    if output::is_machine_readable() {
+        output::emit(
+            "validate",
+            &ValidationOutput {
+                project_path: project_path.to_path_buf(),
+                passed,
+                summary: ViolationSummary::from_violations(&violations),
+                violations,
+                clippy: clippy_result,
+                baseline,
+                members,
+            },
+        )?;
+        if !passed {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if !passed {
         println!(
             "{}",
             style("❌ Validation completed with issues").red().bold()
@@ -139,6 +673,7 @@ fn handle_final_result(violations: &[Violation], clippy_result: &crate::validati
             style("✅ All validation checks passed!").green().bold()
         );
     }
+    Ok(())
 }
 
 /// Try to acquire an exclusive process lock for validation.
@@ -208,3 +743,31 @@ fn lock_path_for(project_path: &Path) -> PathBuf {
     let hash = format!("{:x}", hasher.finalize());
     std::env::temp_dir().join(format!("ferrous-forge-{}.lock", &hash[..16]))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn violation(severity: Severity) -> Violation {
+        Violation::new(
+            ViolationType::FileTooLarge,
+            PathBuf::from("src/lib.rs"),
+            1,
+            "File has 420 lines, max allowed: 400".to_string(),
+            severity,
+        )
+    }
+
+    #[test]
+    fn test_only_error_violations_fail_validation() {
+        assert!(validation_passed(&[], None));
+        assert!(validation_passed(
+            &[violation(Severity::Warning), violation(Severity::Info)],
+            None
+        ));
+        assert!(!validation_passed(
+            &[violation(Severity::Warning), violation(Severity::Error)],
+            None
+        ));
+    }
+}
@@ -10,7 +10,7 @@ pub fn count_violations_by_type(violations: &[Violation]) -> HashMap<String, usi
     let mut violation_counts = HashMap::new();
     for violation in violations {
         *violation_counts
-            .entry(format!("{:?}", violation.violation_type))
+            .entry(violation.violation_type.label())
             .or_insert(0) += 1;
     }
     violation_counts
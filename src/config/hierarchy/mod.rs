@@ -1,9 +1,16 @@
 //! Hierarchical configuration system
 //!
-//! Implements a three-level configuration hierarchy:
+//! Every command resolves its configuration here, from these layers, each
+//! overriding the ones before it:
 //! 1. System: /etc/ferrous-forge/config.toml
-//! 2. User: ~/.config/ferrous-forge/config.toml  
-//! 3. Project: ./.ferrous-forge/config.toml
+//! 2. User: ~/.config/ferrous-forge/config.toml
+//! 3. Project: `<project>/.ferrous-forge/config.toml`
+//! 4. CLI: the file given with the global `--config` flag
+//! 5. Environment: `FERROUS_FORGE_<KEY>` variables
+//!
+//! When validating a workspace, each member's own
+//! `<member>/.ferrous-forge/config.toml` is layered over the project level
+//! for that member, still beneath the CLI and environment layers.
 
 /// Configuration level definitions (system, user, project).
 pub mod levels;
@@ -13,11 +20,26 @@ pub mod partial;
 pub use levels::ConfigLevel;
 pub use partial::PartialConfig;
 
-use crate::config::Config;
+use crate::config::{Config, HierarchicalLockManager};
 use crate::{Error, Result};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use tokio::fs;
 use tracing::info;
 
+static CLI_CONFIG_PATH: OnceLock<PathBuf> = OnceLock::new();
+
+/// Set the process-wide `--config` file (first call wins)
+pub fn set_cli_config_path(path: PathBuf) {
+    CLI_CONFIG_PATH.get_or_init(|| path);
+}
+
+/// The `--config` file, if one was given
+pub fn cli_config_path() -> Option<&'static Path> {
+    CLI_CONFIG_PATH.get().map(PathBuf::as_path)
+}
+
 /// Hierarchical configuration manager
 pub struct HierarchicalConfig {
     /// System-level configuration
@@ -26,42 +48,218 @@ pub struct HierarchicalConfig {
     pub user: Option<PartialConfig>,
     /// Project-level configuration
     pub project: Option<PartialConfig>,
+    /// Where the project configuration is (or would be) loaded from
+    pub project_path: PathBuf,
+    /// Workspace member configuration, applied over the project level
+    pub member: Option<PartialConfig>,
+    /// Where the member configuration was loaded from
+    pub member_path: Option<PathBuf>,
+    /// Configuration from the `--config` file
+    pub cli: Option<PartialConfig>,
+    /// Configuration from `FERROUS_FORGE_*` environment variables
+    pub env: Option<PartialConfig>,
+}
+
+/// Where a configuration value comes from, as shown by `config explain`
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigExplanation {
+    /// The configuration key
+    pub key: String,
+    /// The effective value, if the key is known
+    pub value: Option<String>,
+    /// The layer that set the effective value; `None` means the default
+    pub source: Option<String>,
+    /// Every layer that sets the key, lowest precedence first
+    pub layers: Vec<LayerValue>,
+}
+
+/// A value set by one configuration layer
+#[derive(Debug, Clone, Serialize)]
+pub struct LayerValue {
+    /// The layer, e.g. `Project (./.ferrous-forge/config.toml)`
+    pub source: String,
+    /// The value the layer sets
+    pub value: String,
 }
 
 impl HierarchicalConfig {
-    /// Load configuration from all levels
+    /// Load configuration from all levels for the current directory
     ///
     /// # Errors
     ///
     /// Returns an error if reading or parsing any configuration level fails.
     pub async fn load() -> Result<Self> {
+        Self::load_for_project(Path::new(".")).await
+    }
+
+    /// Load configuration from all levels for the project at `project_root`
+    ///
+    /// Values from `--config` and the environment that differ from a key
+    /// locked with `config lock` are ignored with a warning; see
+    /// [`PartialConfig::without_locked`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading or parsing any layer or lock file fails,
+    /// if the `--config` file does not exist, or if a `FERROUS_FORGE_*`
+    /// variable has an invalid value.
+    pub async fn load_for_project(project_root: &Path) -> Result<Self> {
         let system = PartialConfig::load_from_level(ConfigLevel::System).await?;
         let user = PartialConfig::load_from_level(ConfigLevel::User).await?;
-        let project = PartialConfig::load_from_level(ConfigLevel::Project).await?;
+        let project_path = project_root.join(ConfigLevel::Project.path()?);
+        let project = PartialConfig::load_from_path(&project_path, "Project").await?;
+        let locks = HierarchicalLockManager::load().await?.get_effective_locks();
 
         Ok(Self {
             system,
             user,
             project,
+            project_path,
+            member: None,
+            member_path: None,
+            cli: Self::load_cli()
+                .await?
+                .map(|cli| cli.without_locked(&locks, "--config")),
+            env: PartialConfig::from_env()?
+                .map(|env| env.without_locked(&locks, "the environment")),
         })
     }
 
-    /// Get merged configuration with proper precedence
-    pub fn merged(&self) -> Config {
-        let mut merged = PartialConfig::default();
+    async fn load_cli() -> Result<Option<PartialConfig>> {
+        let Some(path) = cli_config_path() else {
+            return Ok(None);
+        };
+        if !path.exists() {
+            return Err(Error::config(format!(
+                "Config file given with --config not found: {}",
+                path.display()
+            )));
+        }
+        PartialConfig::load_from_path(path, "CLI").await
+    }
+
+    /// Add the configuration of the workspace member at `member_root`
+    ///
+    /// See [`Self::load_member`] for when nothing is added.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the member config exists but cannot be read or
+    /// parsed.
+    pub async fn with_member(mut self, member_root: &Path) -> Result<Self> {
+        let project_root = Self::project_root_of(&self.project_path);
+        self.member = Self::load_member(member_root, &project_root).await?;
+        if self.member.is_some() {
+            self.member_path = Some(Self::member_config_path(member_root));
+        }
+        Ok(self)
+    }
+
+    /// Load the configuration of the workspace member at `member_root`
+    ///
+    /// Returns `None` when the member has no config, or when the member is
+    /// the project at `project_root`, whose config is the project level.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the member config exists but cannot be read or
+    /// parsed.
+    pub async fn load_member(
+        member_root: &Path,
+        project_root: &Path,
+    ) -> Result<Option<PartialConfig>> {
+        if let (Ok(member), Ok(project)) = (member_root.canonicalize(), project_root.canonicalize())
+            && member == project
+        {
+            return Ok(None);
+        }
+        PartialConfig::load_from_path(&Self::member_config_path(member_root), "Member").await
+    }
+
+    fn member_config_path(member_root: &Path) -> PathBuf {
+        member_root.join(".ferrous-forge").join("config.toml")
+    }
 
-        // Apply in order of precedence (lowest to highest)
+    /// The project directory holding the config file at `project_path`
+    fn project_root_of(project_path: &Path) -> PathBuf {
+        project_path
+            .ancestors()
+            .nth(2)
+            .map(Path::to_path_buf)
+            .unwrap_or_default()
+    }
+
+    /// The layers that are present, lowest precedence first, each with a
+    /// label naming where it came from
+    pub fn layers(&self) -> Vec<(String, &PartialConfig)> {
+        let mut layers = Vec::new();
         if let Some(system) = &self.system {
-            merged = merged.merge(system.clone());
+            let path = ConfigLevel::System.path().unwrap_or_default();
+            layers.push((format!("System ({})", path.display()), system));
         }
         if let Some(user) = &self.user {
-            merged = merged.merge(user.clone());
+            let path = ConfigLevel::User.path().unwrap_or_default();
+            layers.push((format!("User ({})", path.display()), user));
         }
         if let Some(project) = &self.project {
-            merged = merged.merge(project.clone());
+            layers.push((
+                format!("Project ({})", self.project_path.display()),
+                project,
+            ));
+        }
+        if let (Some(member), Some(path)) = (&self.member, &self.member_path) {
+            layers.push((format!("Member ({})", path.display()), member));
+        }
+        if let (Some(cli), Some(path)) = (&self.cli, cli_config_path()) {
+            layers.push((format!("CLI --config ({})", path.display()), cli));
         }
+        if let Some(env) = &self.env {
+            layers.push(("Environment (FERROUS_FORGE_*)".to_string(), env));
+        }
+        layers
+    }
 
-        merged.to_full_config()
+    /// The CLI and environment layers merged; these outrank workspace
+    /// member configs
+    pub fn command_overrides(&self) -> PartialConfig {
+        let mut overrides = PartialConfig::default();
+        if let Some(cli) = &self.cli {
+            overrides = overrides.merge(cli.clone());
+        }
+        if let Some(env) = &self.env {
+            overrides = overrides.merge(env.clone());
+        }
+        overrides
+    }
+
+    /// Get merged configuration with proper precedence
+    pub fn merged(&self) -> Config {
+        self.layers()
+            .into_iter()
+            .fold(PartialConfig::default(), |merged, (_, layer)| {
+                merged.merge(layer.clone())
+            })
+            .to_full_config()
+    }
+
+    /// Explain which layers set `key` and which of them wins
+    pub fn explain(&self, key: &str) -> ConfigExplanation {
+        let layers: Vec<LayerValue> = self
+            .layers()
+            .into_iter()
+            .filter_map(|(source, layer)| {
+                Some(LayerValue {
+                    value: layer.get(key)?,
+                    source,
+                })
+            })
+            .collect();
+        ConfigExplanation {
+            key: key.to_string(),
+            value: PartialConfig::from(self.merged()).get(key),
+            source: layers.last().map(|layer| layer.source.clone()),
+            layers,
+        }
     }
 
     /// Save configuration at a specific level
@@ -115,11 +313,24 @@ impl HierarchicalConfig {
         }
 
         if self.project.is_some() {
-            report.push_str("  ✓ Project: ./.ferrous-forge/config.toml\n");
+            report.push_str(&format!("  ✓ Project: {}\n", self.project_path.display()));
         } else {
             report.push_str("  ✗ Project: Not found\n");
         }
 
+        if let Some(path) = &self.member_path {
+            report.push_str(&format!("  ✓ Member: {}\n", path.display()));
+        }
+
+        if let Some(path) = cli_config_path() {
+            report.push_str(&format!("  ✓ CLI --config: {}\n", path.display()));
+        }
+
+        if let Some(env) = &self.env {
+            let keys = crate::config::sharing::PartialConfigExt::list_keys(env);
+            report.push_str(&format!("  ✓ Environment: {}\n", keys.join(", ")));
+        }
+
         report
     }
 }
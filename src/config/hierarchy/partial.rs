@@ -1,9 +1,13 @@
 //! Partial configuration for hierarchical merging
 
 use super::ConfigLevel;
-use crate::config::{Config, CustomRule, ValidationConfig};
+use crate::config::{Config, CustomRule, LockEntry, PathOverride, ValidationConfig};
+use crate::validation::RuleLevel;
 use crate::{Error, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::str::FromStr;
 use tokio::fs;
 use tracing::debug;
 
@@ -34,6 +38,14 @@ pub struct PartialConfig {
     pub require_documentation: Option<bool>,
     /// Custom validation rules
     pub custom_rules: Option<Vec<CustomRule>>,
+    /// Severity overrides by rule name
+    pub rules: Option<BTreeMap<String, RuleLevel>>,
+    /// Globs of paths that are never validated
+    pub exclude: Option<Vec<String>>,
+    /// Path-scoped settings
+    pub overrides: Option<Vec<PathOverride>>,
+    /// Validation settings, merged per setting
+    pub validation: Option<ValidationConfig>,
 }
 
 impl PartialConfig {
@@ -43,41 +55,230 @@ impl PartialConfig {
     ///
     /// Returns an error if reading or parsing the config file fails.
     pub async fn load_from_level(level: ConfigLevel) -> Result<Option<Self>> {
-        let path = level.path()?;
+        Self::load_from_path(&level.path()?, level.display_name()).await
+    }
 
+    /// Load partial config from `path`, naming it `label` in messages
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading or parsing the config file fails.
+    pub async fn load_from_path(path: &Path, label: &str) -> Result<Option<Self>> {
         if !path.exists() {
-            debug!(
-                "No config found at {} level: {}",
-                level.display_name(),
-                path.display()
-            );
+            debug!("No config found at {} level: {}", label, path.display());
             return Ok(None);
         }
 
-        let contents = fs::read_to_string(&path).await.map_err(|e| {
-            Error::config(format!(
-                "Failed to read {} config: {}",
-                level.display_name(),
-                e
-            ))
-        })?;
-
-        let partial: PartialConfig = toml::from_str(&contents).map_err(|e| {
-            Error::config(format!(
-                "Failed to parse {} config: {}",
-                level.display_name(),
-                e
-            ))
-        })?;
-
-        tracing::info!(
-            "Loaded {} configuration from {}",
-            level.display_name(),
-            path.display()
-        );
+        let contents = fs::read_to_string(path)
+            .await
+            .map_err(|e| Error::config(format!("Failed to read {} config: {}", label, e)))?;
+
+        let partial: PartialConfig = toml::from_str(&contents)
+            .map_err(|e| Error::config(format!("Failed to parse {} config: {}", label, e)))?;
+
+        tracing::info!("Loaded {} configuration from {}", label, path.display());
         Ok(Some(partial))
     }
 
+    /// Build the environment layer from `FERROUS_FORGE_*` variables
+    ///
+    /// See [`Self::from_vars`] for the variables that are recognised.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a recognised variable has an invalid value.
+    pub fn from_env() -> Result<Option<Self>> {
+        Self::from_vars(std::env::vars())
+    }
+
+    /// Build a layer from `FERROUS_FORGE_<KEY>` variables
+    ///
+    /// `<KEY>` is a `config get` key in upper case, e.g.
+    /// `FERROUS_FORGE_MAX_FILE_LINES=400`; rule levels are set with
+    /// `FERROUS_FORGE_RULES_<RULE>=off`. Other `FERROUS_FORGE_*` variables
+    /// (such as `FERROUS_FORGE_BYPASS`) are ignored. Returns `None` when no
+    /// variable applies.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a recognised variable has an invalid value.
+    pub fn from_vars(vars: impl IntoIterator<Item = (String, String)>) -> Result<Option<Self>> {
+        let mut layer = PartialConfig::default();
+        let mut found = false;
+        for (name, value) in vars {
+            let Some(key) = name.strip_prefix("FERROUS_FORGE_") else {
+                continue;
+            };
+            found |= layer.set_from_env(&name, &key.to_lowercase(), &value)?;
+        }
+        Ok(found.then_some(layer))
+    }
+
+    /// Set `key` from the variable `name`; returns false for unknown keys
+    fn set_from_env(&mut self, name: &str, key: &str, value: &str) -> Result<bool> {
+        match key {
+            "update_channel" => self.update_channel = Some(value.to_string()),
+            "auto_update" => self.auto_update = Some(parse_env(name, value)?),
+            "max_file_lines" => self.max_file_lines = Some(parse_env(name, value)?),
+            "max_function_lines" => self.max_function_lines = Some(parse_env(name, value)?),
+            "required_edition" => self.required_edition = Some(value.to_string()),
+            "required_rust_version" => self.required_rust_version = Some(value.to_string()),
+            "ban_underscore_bandaid" => {
+                self.ban_underscore_bandaid = Some(parse_env(name, value)?);
+            }
+            "require_documentation" => {
+                self.require_documentation = Some(parse_env(name, value)?);
+            }
+            "validation_jobs" => {
+                let jobs: usize = parse_env(name, value)?;
+                self.validation.get_or_insert_with(empty_validation).jobs =
+                    Some(jobs).filter(|&j| j > 0);
+            }
+            _ => match key.strip_prefix("rules_") {
+                Some(rule) if !rule.is_empty() => {
+                    self.rules
+                        .get_or_insert_with(BTreeMap::new)
+                        .insert(rule.to_string(), parse_env(name, value)?);
+                }
+                _ => return Ok(false),
+            },
+        }
+        Ok(true)
+    }
+
+    /// Get the value this layer sets for a `config get` key
+    ///
+    /// Returns `None` when the layer leaves the key unset.
+    pub fn get(&self, key: &str) -> Option<String> {
+        match key {
+            "initialized" => self.initialized.map(|v| v.to_string()),
+            "version" => self.version.clone(),
+            "update_channel" => self.update_channel.clone(),
+            "auto_update" => self.auto_update.map(|v| v.to_string()),
+            "clippy_rules" => self.clippy_rules.as_ref().map(|v| format!("{:?}", v)),
+            "max_file_lines" => self.max_file_lines.map(|v| v.to_string()),
+            "max_function_lines" => self.max_function_lines.map(|v| v.to_string()),
+            "required_edition" => self.required_edition.clone(),
+            "required_rust_version" => self.required_rust_version.clone(),
+            "ban_underscore_bandaid" => self.ban_underscore_bandaid.map(|v| v.to_string()),
+            "require_documentation" => self.require_documentation.map(|v| v.to_string()),
+            "custom_rules" => self.custom_rules.as_ref().map(|v| format!("{:?}", v)),
+            "rules" => self.rules.as_ref().map(|v| format!("{:?}", v)),
+            "exclude" => self.exclude.as_ref().map(|v| format!("{:?}", v)),
+            "overrides" => self.overrides.as_ref().map(|v| format!("{:?}", v)),
+            "validation_jobs" => self
+                .validation
+                .as_ref()
+                .and_then(|v| v.jobs)
+                .map(|j| j.to_string()),
+            _ => key
+                .strip_prefix("rules.")
+                .and_then(|rule| self.rules.as_ref()?.get(rule))
+                .map(ToString::to_string),
+        }
+    }
+
+    /// Clear the value this layer sets for a `config get` key
+    pub fn unset(&mut self, key: &str) {
+        match key {
+            "initialized" => self.initialized = None,
+            "version" => self.version = None,
+            "update_channel" => self.update_channel = None,
+            "auto_update" => self.auto_update = None,
+            "clippy_rules" => self.clippy_rules = None,
+            "max_file_lines" => self.max_file_lines = None,
+            "max_function_lines" => self.max_function_lines = None,
+            "required_edition" => self.required_edition = None,
+            "required_rust_version" => self.required_rust_version = None,
+            "ban_underscore_bandaid" => self.ban_underscore_bandaid = None,
+            "require_documentation" => self.require_documentation = None,
+            "custom_rules" => self.custom_rules = None,
+            "rules" => self.rules = None,
+            "exclude" => self.exclude = None,
+            "overrides" => self.overrides = None,
+            "validation_jobs" => {
+                if let Some(validation) = &mut self.validation {
+                    validation.jobs = None;
+                }
+            }
+            _ => {
+                if let (Some(rule), Some(rules)) = (key.strip_prefix("rules."), &mut self.rules) {
+                    rules.remove(rule);
+                }
+            }
+        }
+    }
+
+    /// This layer without the values that differ from a locked value
+    ///
+    /// Locks set with `config lock` bind the `--config` and environment
+    /// layers as they bind config files. Each dropped value is reported on
+    /// stderr, naming `source`.
+    pub fn without_locked(
+        mut self,
+        locks: &HashMap<String, (ConfigLevel, LockEntry)>,
+        source: &str,
+    ) -> Self {
+        for (key, (level, entry)) in locks {
+            if let Some(value) = self.get(key)
+                && value != entry.value
+            {
+                eprintln!(
+                    "⚠️  Ignoring {} = {} from {}: the key is locked to {} at {} level",
+                    key,
+                    value,
+                    source,
+                    entry.value,
+                    level.display_name()
+                );
+                self.unset(key);
+            }
+        }
+        self
+    }
+
+    /// This layer without the settings that `other` overrides
+    ///
+    /// Used to slot a layer beneath `other` after both have been merged:
+    /// applying the result keeps the values `other` set. Exclusions and
+    /// overrides accumulate rather than override, so they are kept.
+    pub fn shadowed_by(&self, other: &PartialConfig) -> Self {
+        fn clear<T, U>(value: &mut Option<T>, other: &Option<U>) {
+            if other.is_some() {
+                *value = None;
+            }
+        }
+        let mut layer = self.clone();
+        clear(&mut layer.initialized, &other.initialized);
+        clear(&mut layer.version, &other.version);
+        clear(&mut layer.update_channel, &other.update_channel);
+        clear(&mut layer.auto_update, &other.auto_update);
+        clear(&mut layer.clippy_rules, &other.clippy_rules);
+        clear(&mut layer.max_file_lines, &other.max_file_lines);
+        clear(&mut layer.max_function_lines, &other.max_function_lines);
+        clear(&mut layer.required_edition, &other.required_edition);
+        clear(
+            &mut layer.required_rust_version,
+            &other.required_rust_version,
+        );
+        clear(
+            &mut layer.ban_underscore_bandaid,
+            &other.ban_underscore_bandaid,
+        );
+        clear(
+            &mut layer.require_documentation,
+            &other.require_documentation,
+        );
+        clear(&mut layer.custom_rules, &other.custom_rules);
+        if let (Some(rules), Some(shadowing)) = (&mut layer.rules, &other.rules) {
+            rules.retain(|rule, _| !shadowing.contains_key(rule));
+        }
+        if let (Some(validation), Some(shadowing)) = (&mut layer.validation, &other.validation) {
+            *validation = merge_validation(validation.clone(), shadowing.clone());
+        }
+        layer
+    }
+
     /// Merge another partial config into this one (other takes precedence)
     pub fn merge(mut self, other: PartialConfig) -> Self {
         if other.initialized.is_some() {
@@ -116,9 +317,38 @@ impl PartialConfig {
         if other.custom_rules.is_some() {
             self.custom_rules = other.custom_rules;
         }
+        // Rule levels merge per rule, so a project can override one rule
+        // without repeating the user's whole table
+        if let Some(rules) = other.rules {
+            self.rules.get_or_insert_with(BTreeMap::new).extend(rules);
+        }
+        // Exclusions and overrides accumulate across levels; overrides from
+        // the more specific level come last, so they win
+        if let Some(exclude) = other.exclude {
+            self.exclude.get_or_insert_with(Vec::new).extend(exclude);
+        }
+        if let Some(overrides) = other.overrides {
+            self.overrides
+                .get_or_insert_with(Vec::new)
+                .extend(overrides);
+        }
+        if let Some(validation) = other.validation {
+            let base = self.validation.take().unwrap_or_else(empty_validation);
+            self.validation = Some(merge_validation(base, validation));
+        }
         self
     }
 
+    /// Apply this partial config on top of `config`
+    ///
+    /// Like [`Self::merge`], exclusions and overrides are appended and rule
+    /// levels merge per rule.
+    pub fn apply_to(&self, config: &Config) -> Config {
+        PartialConfig::from(config.clone())
+            .merge(self.clone())
+            .to_full_config()
+    }
+
     /// Convert to full config, using defaults for missing values
     pub fn to_full_config(self) -> Config {
         let default = Config::default();
@@ -143,12 +373,91 @@ impl PartialConfig {
                 .require_documentation
                 .unwrap_or(default.require_documentation),
             custom_rules: self.custom_rules.unwrap_or(default.custom_rules),
-            validation: ValidationConfig::default(),
+            validation: merge_validation(
+                ValidationConfig::default(),
+                self.validation.unwrap_or_else(empty_validation),
+            ),
+            rules: self.rules.unwrap_or(default.rules),
+            exclude: self.exclude.unwrap_or(default.exclude),
+            overrides: self.overrides.unwrap_or(default.overrides),
         }
     }
 }
 
+impl From<Config> for PartialConfig {
+    fn from(config: Config) -> Self {
+        Self {
+            initialized: Some(config.initialized),
+            version: Some(config.version),
+            update_channel: Some(config.update_channel),
+            auto_update: Some(config.auto_update),
+            clippy_rules: Some(config.clippy_rules),
+            max_file_lines: Some(config.max_file_lines),
+            max_function_lines: Some(config.max_function_lines),
+            required_edition: Some(config.required_edition),
+            required_rust_version: Some(config.required_rust_version),
+            ban_underscore_bandaid: Some(config.ban_underscore_bandaid),
+            require_documentation: Some(config.require_documentation),
+            custom_rules: Some(config.custom_rules),
+            rules: Some(config.rules),
+            exclude: Some(config.exclude),
+            overrides: Some(config.overrides),
+            validation: Some(config.validation),
+        }
+    }
+}
+
+/// Validation settings with nothing set
+fn empty_validation() -> ValidationConfig {
+    ValidationConfig {
+        check_version_consistency: None,
+        version_check_exclusions: None,
+        enforce_keep_a_changelog: None,
+        require_changelog_entry: None,
+        check_changelog_on_tag: None,
+        changelog_required_sections: None,
+        jobs: None,
+    }
+}
+
+/// Overlay the validation settings `other` sets onto `base`
+fn merge_validation(mut base: ValidationConfig, other: ValidationConfig) -> ValidationConfig {
+    if other.check_version_consistency.is_some() {
+        base.check_version_consistency = other.check_version_consistency;
+    }
+    if other.version_check_exclusions.is_some() {
+        base.version_check_exclusions = other.version_check_exclusions;
+    }
+    if other.enforce_keep_a_changelog.is_some() {
+        base.enforce_keep_a_changelog = other.enforce_keep_a_changelog;
+    }
+    if other.require_changelog_entry.is_some() {
+        base.require_changelog_entry = other.require_changelog_entry;
+    }
+    if other.check_changelog_on_tag.is_some() {
+        base.check_changelog_on_tag = other.check_changelog_on_tag;
+    }
+    if other.changelog_required_sections.is_some() {
+        base.changelog_required_sections = other.changelog_required_sections;
+    }
+    if other.jobs.is_some() {
+        base.jobs = other.jobs;
+    }
+    base
+}
+
+/// Parse the value of the environment variable `name`
+fn parse_env<T: FromStr>(name: &str, value: &str) -> Result<T>
+where
+    T::Err: std::fmt::Display,
+{
+    value
+        .parse()
+        .map_err(|e| Error::config(format!("Invalid value for {}: {}", name, e)))
+}
+
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
 
@@ -172,6 +481,28 @@ mod tests {
         assert_eq!(merged.required_edition, Some("2021".to_string()));
     }
 
+    #[test]
+    fn test_partial_config_merges_rules_per_key() {
+        let base = PartialConfig {
+            rules: Some(BTreeMap::from([
+                ("line_too_long".to_string(), RuleLevel::Warning),
+                ("unwrap_in_production".to_string(), RuleLevel::Error),
+            ])),
+            ..Default::default()
+        };
+        let override_config = PartialConfig {
+            rules: Some(BTreeMap::from([(
+                "line_too_long".to_string(),
+                RuleLevel::Off,
+            )])),
+            ..Default::default()
+        };
+
+        let rules = base.merge(override_config).to_full_config().rules;
+        assert_eq!(rules["line_too_long"], RuleLevel::Off);
+        assert_eq!(rules["unwrap_in_production"], RuleLevel::Error);
+    }
+
     #[test]
     fn test_partial_to_full_config() {
         let partial = PartialConfig {
@@ -183,4 +514,103 @@ mod tests {
         assert_eq!(full.max_file_lines, 500);
         assert_eq!(full.max_function_lines, 50); // Default value
     }
+
+    #[test]
+    fn test_partial_config_from_env_vars() {
+        let vars = [
+            ("FERROUS_FORGE_MAX_FILE_LINES", "400"),
+            ("FERROUS_FORGE_RULES_LINE_TOO_LONG", "off"),
+            ("FERROUS_FORGE_VALIDATION_JOBS", "2"),
+            ("FERROUS_FORGE_BYPASS", "true"),
+            ("PATH", "/usr/bin"),
+        ]
+        .map(|(k, v)| (k.to_string(), v.to_string()));
+
+        let layer = PartialConfig::from_vars(vars).unwrap().unwrap();
+        assert_eq!(layer.max_file_lines, Some(400));
+        assert_eq!(layer.get("rules.line_too_long").as_deref(), Some("off"));
+        assert_eq!(layer.get("validation_jobs").as_deref(), Some("2"));
+        assert_eq!(layer.max_function_lines, None);
+
+        let unrelated = [("FERROUS_FORGE_BYPASS".to_string(), "true".to_string())];
+        assert!(matches!(PartialConfig::from_vars(unrelated), Ok(None)));
+
+        let invalid = [(
+            "FERROUS_FORGE_MAX_FILE_LINES".to_string(),
+            "many".to_string(),
+        )];
+        assert!(PartialConfig::from_vars(invalid).is_err());
+    }
+
+    #[test]
+    fn test_environment_cannot_override_locked_keys() {
+        let vars = [
+            ("FERROUS_FORGE_REQUIRED_EDITION", "2021"),
+            ("FERROUS_FORGE_REQUIRED_RUST_VERSION", "1.85"),
+            ("FERROUS_FORGE_MAX_FILE_LINES", "500"),
+        ]
+        .map(|(name, value)| (name.to_string(), value.to_string()));
+        let locks = HashMap::from(
+            [
+                ("required_edition", "2024"),
+                ("required_rust_version", "1.85"),
+            ]
+            .map(|(key, value)| {
+                let entry = LockEntry::new(value, "team policy", ConfigLevel::Project);
+                (key.to_string(), (ConfigLevel::Project, entry))
+            }),
+        );
+
+        let layer = PartialConfig::from_vars(vars)
+            .unwrap()
+            .unwrap()
+            .without_locked(&locks, "the environment");
+        assert_eq!(layer.required_edition, None);
+        assert_eq!(layer.required_rust_version.as_deref(), Some("1.85"));
+        assert_eq!(layer.max_file_lines, Some(500));
+    }
+
+    #[test]
+    fn test_partial_config_shadowed_by() {
+        let member = PartialConfig {
+            max_file_lines: Some(1000),
+            max_function_lines: Some(80),
+            rules: Some(BTreeMap::from([
+                ("line_too_long".to_string(), RuleLevel::Off),
+                ("unwrap_in_production".to_string(), RuleLevel::Warning),
+            ])),
+            exclude: Some(vec!["generated/**".to_string()]),
+            ..Default::default()
+        };
+        let env = PartialConfig {
+            max_file_lines: Some(400),
+            rules: Some(BTreeMap::from([(
+                "line_too_long".to_string(),
+                RuleLevel::Error,
+            )])),
+            ..Default::default()
+        };
+
+        let shadowed = member.shadowed_by(&env);
+        assert_eq!(shadowed.max_file_lines, None);
+        assert_eq!(shadowed.max_function_lines, Some(80));
+        assert_eq!(shadowed.get("rules.line_too_long"), None);
+        assert_eq!(
+            shadowed.get("rules.unwrap_in_production").as_deref(),
+            Some("warning")
+        );
+        assert_eq!(shadowed.exclude, member.exclude);
+    }
+
+    #[test]
+    fn test_partial_config_keeps_validation_settings() {
+        let user: PartialConfig = toml::from_str("[validation]\njobs = 4\n").unwrap();
+        let project: PartialConfig =
+            toml::from_str("[validation]\nrequire_changelog_entry = false\n").unwrap();
+
+        let validation = user.merge(project).to_full_config().validation;
+        assert_eq!(validation.jobs, Some(4));
+        assert_eq!(validation.require_changelog_entry, Some(false));
+        assert_eq!(validation.enforce_keep_a_changelog, Some(true)); // Default value
+    }
 }
@@ -2,50 +2,75 @@
 
 use super::types::Config;
 use crate::{Error, Result};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tokio::fs;
 
 impl Config {
-    /// Load configuration from file, or return default if not found
+    /// Load configuration for the current directory, or return defaults
+    /// where no layer sets a value
+    ///
+    /// A configuration that cannot be loaded, e.g. because a layer does
+    /// not parse, is logged and replaced by the defaults; use [`Self::load`]
+    /// to see the error.
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Config`] if both the hierarchical config and the
-    /// fallback config file exist but fail to parse.
+    /// This function falls back to the defaults instead of returning
+    /// errors.
     pub async fn load_or_default() -> Result<Self> {
         match Self::load().await {
             Ok(config) => Ok(config),
-            Err(_) => Ok(Self::default()),
+            Err(e) => {
+                tracing::warn!("Using the default configuration: {}", e);
+                Ok(Self::default())
+            }
         }
     }
 
-    /// Load configuration using hierarchical system
+    /// Load configuration for the current directory using the hierarchical
+    /// system
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Config`] if the config file cannot be read or parsed.
+    /// Returns [`Error::Config`] if a config file cannot be read or parsed,
+    /// the `--config` file is missing, or a `FERROUS_FORGE_*` variable is
+    /// invalid.
     pub async fn load() -> Result<Self> {
-        // Try hierarchical config first
-        match super::HierarchicalConfig::load().await {
-            Ok(hier) => Ok(hier.merged()),
-            Err(_) => {
-                // Fallback to old single-file config for backward compatibility
-                let config_path = Self::config_file_path()?;
-                if config_path.exists() {
-                    let contents = fs::read_to_string(&config_path)
-                        .await
-                        .map_err(|e| Error::config(format!("Failed to read config file: {}", e)))?;
+        Self::load_for_project(Path::new(".")).await
+    }
 
-                    let config: Config = toml::from_str(&contents).map_err(|e| {
-                        Error::config(format!("Failed to parse config file: {}", e))
-                    })?;
+    /// Load configuration for the project at `project_root`
+    ///
+    /// The project level is read from `<project_root>/.ferrous-forge/config.toml`
+    /// rather than from the current directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] under the same conditions as [`Self::load`].
+    pub async fn load_for_project(project_root: &Path) -> Result<Self> {
+        Ok(super::HierarchicalConfig::load_for_project(project_root)
+            .await?
+            .merged())
+    }
 
-                    Ok(config)
-                } else {
-                    Err(Error::config("No configuration found"))
-                }
-            }
-        }
+    /// Load only the user-level configuration, the file that
+    /// [`Self::save`] writes
+    ///
+    /// Commands that modify and save the configuration start from this, so
+    /// project, `--config` and environment values are not copied into the
+    /// user's file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if the user config exists but cannot be
+    /// read or parsed.
+    pub async fn load_user() -> Result<Self> {
+        Ok(
+            super::PartialConfig::load_from_level(super::ConfigLevel::User)
+                .await?
+                .unwrap_or_default()
+                .to_full_config(),
+        )
     }
 
     /// Save configuration to file
@@ -1,8 +1,12 @@
 //! Audit logging for lock/unlock operations
 //!
+//! Entries are appended to a hash-chained log, so `config audit --verify`
+//! can detect edits and truncation.
+//!
 //! @task T015
 //! @epic T014
 
+use crate::audit_chain::AuditChain;
 use crate::config::hierarchy::ConfigLevel;
 use crate::config::locking::LockEntry;
 use crate::{Error, Result};
@@ -77,6 +81,15 @@ fn audit_log_path() -> Result<PathBuf> {
     Ok(config_dir.join("ferrous-forge").join("audit.log"))
 }
 
+/// The hash-chained lock audit log
+///
+/// # Errors
+///
+/// Returns an error if the config directory cannot be found.
+pub fn audit_chain() -> Result<AuditChain> {
+    Ok(AuditChain::new(audit_log_path()?))
+}
+
 /// Log an unlock operation
 ///
 /// # Errors
@@ -118,33 +131,7 @@ pub async fn log_blocked_attempt(
 ///
 /// Returns an error if writing to the audit log fails.
 async fn append_to_audit_log(entry: AuditEntry) -> Result<()> {
-    let path = audit_log_path()?;
-
-    // Ensure parent directory exists
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent).await?;
-    }
-
-    let line = serde_json::to_string(&entry)
-        .map_err(|e| Error::config(format!("Failed to serialize audit entry: {}", e)))?;
-
-    // Append newline
-    let line = format!("{}\n", line);
-
-    // Open file for appending (create if doesn't exist)
-    use tokio::io::AsyncWriteExt;
-    let mut file = fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(&path)
-        .await
-        .map_err(|e| Error::config(format!("Failed to open audit log: {}", e)))?;
-
-    file.write_all(line.as_bytes())
-        .await
-        .map_err(|e| Error::config(format!("Failed to write to audit log: {}", e)))?;
-
-    Ok(())
+    audit_chain()?.append(&entry).await
 }
 
 /// Read the audit log
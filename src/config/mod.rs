@@ -1,10 +1,13 @@
 //! Configuration management for Ferrous Forge
 //!
-//! Provides a hierarchical configuration system with three levels:
+//! Provides a hierarchical configuration system with three file levels:
 //! - System: /etc/ferrous-forge/config.toml
 //! - User: ~/.config/ferrous-forge/config.toml
 //! - Project: ./.ferrous-forge/config.toml
 //!
+//! A `--config` file and `FERROUS_FORGE_*` environment variables override
+//! all three; see [`hierarchy`].
+//!
 //! @task T018
 //! @epic T014
 
@@ -21,7 +24,10 @@ pub mod sharing;
 /// Configuration data types and defaults.
 pub mod types;
 
-pub use hierarchy::{ConfigLevel, HierarchicalConfig};
+pub use hierarchy::{
+    ConfigExplanation, ConfigLevel, HierarchicalConfig, LayerValue, PartialConfig,
+    set_cli_config_path,
+};
 pub use locking::{ConfigValidator, HierarchicalLockManager, LockEntry, LockedConfig, audit_log};
 pub use sharing::{ImportOptions, ImportReport, SharedConfig, import_shared_config};
-pub use types::{Config, CustomRule, ValidationConfig};
+pub use types::{Config, CustomRule, PathOverride, ValidationConfig};
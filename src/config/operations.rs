@@ -25,7 +25,11 @@ impl Config {
             "required_rust_version" => Some(self.required_rust_version.clone()),
             "ban_underscore_bandaid" => Some(self.ban_underscore_bandaid.to_string()),
             "require_documentation" => Some(self.require_documentation.to_string()),
-            _ => None,
+            "validation_jobs" => Some(self.validation.jobs.unwrap_or(0).to_string()),
+            _ => key
+                .strip_prefix("rules.")
+                .and_then(|rule| self.rules.get(rule))
+                .map(ToString::to_string),
         }
     }
 
@@ -45,7 +49,11 @@ impl Config {
             "required_rust_version" => self.required_rust_version = value.to_string(),
             "ban_underscore_bandaid" => self.set_ban_underscore_bandaid(value)?,
             "require_documentation" => self.set_require_documentation(value)?,
-            _ => return Err(Error::config(format!("Unknown configuration key: {}", key))),
+            "validation_jobs" => self.set_validation_jobs(value)?,
+            _ => match key.strip_prefix("rules.") {
+                Some(rule) if !rule.is_empty() => self.set_rule(rule, value)?,
+                _ => return Err(Error::config(format!("Unknown configuration key: {}", key))),
+            },
         }
         Ok(())
     }
@@ -95,6 +103,10 @@ impl Config {
                 "require_documentation".to_string(),
                 self.require_documentation.to_string(),
             ),
+            (
+                "validation_jobs".to_string(),
+                self.validation.jobs.unwrap_or(0).to_string(),
+            ),
             (
                 "clippy_rules".to_string(),
                 format!("{:?}", self.clippy_rules),
@@ -103,7 +115,19 @@ impl Config {
                 "custom_rules_count".to_string(),
                 self.custom_rules.len().to_string(),
             ),
+            ("exclude".to_string(), format!("{:?}", self.exclude)),
+            (
+                "overrides_count".to_string(),
+                self.overrides.len().to_string(),
+            ),
         ]
+        .into_iter()
+        .chain(
+            self.rules
+                .iter()
+                .map(|(rule, level)| (format!("rules.{}", rule), level.to_string())),
+        )
+        .collect()
     }
 
     /// Reset configuration to defaults
@@ -156,4 +180,17 @@ impl Config {
             .map_err(|_| Error::config("Invalid boolean value for require_documentation"))?;
         Ok(())
     }
+
+    fn set_validation_jobs(&mut self, value: &str) -> Result<()> {
+        let jobs: usize = value
+            .parse()
+            .map_err(|_| Error::config("Invalid number for validation_jobs"))?;
+        self.validation.jobs = (jobs > 0).then_some(jobs);
+        Ok(())
+    }
+
+    fn set_rule(&mut self, rule: &str, value: &str) -> Result<()> {
+        self.rules.insert(rule.to_string(), value.parse()?);
+        Ok(())
+    }
 }
@@ -232,6 +232,15 @@ impl SharedConfig {
         if self.config.custom_rules.is_some() {
             count += 1;
         }
+        if self.config.rules.is_some() {
+            count += 1;
+        }
+        if self.config.exclude.is_some() {
+            count += 1;
+        }
+        if self.config.overrides.is_some() {
+            count += 1;
+        }
         count
     }
 }
@@ -434,25 +443,23 @@ impl PartialConfigExt for PartialConfig {
         if self.custom_rules.is_some() {
             keys.push("custom_rules".to_string());
         }
+        if self.rules.is_some() {
+            keys.push("rules".to_string());
+        }
+        if self.exclude.is_some() {
+            keys.push("exclude".to_string());
+        }
+        if self.overrides.is_some() {
+            keys.push("overrides".to_string());
+        }
+        if self.validation.is_some() {
+            keys.push("validation".to_string());
+        }
         keys
     }
 
     fn get_value(&self, key: &str) -> Option<String> {
-        match key {
-            "initialized" => self.initialized.map(|v| v.to_string()),
-            "version" => self.version.clone(),
-            "update_channel" => self.update_channel.clone(),
-            "auto_update" => self.auto_update.map(|v| v.to_string()),
-            "clippy_rules" => self.clippy_rules.as_ref().map(|v| format!("{:?}", v)),
-            "max_file_lines" => self.max_file_lines.map(|v| v.to_string()),
-            "max_function_lines" => self.max_function_lines.map(|v| v.to_string()),
-            "required_edition" => self.required_edition.clone(),
-            "required_rust_version" => self.required_rust_version.clone(),
-            "ban_underscore_bandaid" => self.ban_underscore_bandaid.map(|v| v.to_string()),
-            "require_documentation" => self.require_documentation.map(|v| v.to_string()),
-            "custom_rules" => self.custom_rules.as_ref().map(|v| format!("{:?}", v)),
-            _ => None,
-        }
+        self.get(key)
     }
 
     fn count_set_fields(&self) -> usize {
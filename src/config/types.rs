@@ -1,6 +1,8 @@
 //! Configuration type definitions
 
+use crate::validation::{RuleLevel, Severity};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Ferrous Forge configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +33,41 @@ pub struct Config {
     pub custom_rules: Vec<CustomRule>,
     /// Validation settings
     pub validation: ValidationConfig,
+    /// Severity overrides by rule name (`off`, `info`, `warning`, `error`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rules: BTreeMap<String, RuleLevel>,
+    /// Glob patterns, relative to the project root, of paths that are
+    /// never validated
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+    /// Settings for paths matching globs (`[[overrides]]`); when several
+    /// blocks match a file, later ones win
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub overrides: Vec<PathOverride>,
+}
+
+/// Settings that apply only to files matching `paths`
+///
+/// ```toml
+/// [[overrides]]
+/// paths = ["src/generated/**"]
+/// max_file_lines = 2000
+/// rules.unwrap_in_production = "off"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PathOverride {
+    /// Glob patterns, relative to the project root; a pattern matching a
+    /// directory covers everything below it
+    pub paths: Vec<String>,
+    /// File size limit in lines for matching files
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_file_lines: Option<usize>,
+    /// Function size limit in lines for matching files
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_function_lines: Option<usize>,
+    /// Severity overrides by rule name for matching files
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rules: BTreeMap<String, RuleLevel>,
 }
 
 /// Custom validation rule
@@ -44,6 +81,14 @@ pub struct CustomRule {
     pub message: String,
     /// Whether this rule is enabled
     pub enabled: bool,
+    /// Severity of violations reported by this rule
+    #[serde(default = "default_custom_rule_severity")]
+    pub severity: Severity,
+}
+
+/// Custom rules block by default, matching the built-in checks
+fn default_custom_rule_severity() -> Severity {
+    Severity::Error
 }
 
 /// Validation configuration settings
@@ -61,6 +106,8 @@ pub struct ValidationConfig {
     pub check_changelog_on_tag: Option<bool>,
     /// Required sections in changelog (e.g., ["Added", "Changed", "Fixed"])
     pub changelog_required_sections: Option<Vec<String>>,
+    /// Worker threads for file validation (0 or unset = one per CPU)
+    pub jobs: Option<usize>,
 }
 
 impl Default for ValidationConfig {
@@ -76,6 +123,7 @@ impl Default for ValidationConfig {
                 "Changed".to_string(),
                 "Fixed".to_string(),
             ]),
+            jobs: None,
         }
     }
 }
@@ -109,6 +157,9 @@ impl Default for Config {
             require_documentation: true,
             custom_rules: vec![],
             validation: ValidationConfig::default(),
+            rules: BTreeMap::new(),
+            exclude: vec![],
+            overrides: vec![],
         }
     }
 }
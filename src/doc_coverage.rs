@@ -3,6 +3,8 @@
 //! This module provides functionality to check documentation coverage
 //! for Rust projects, ensuring all public APIs are properly documented.
 
+use crate::config::HierarchicalConfig;
+use crate::validation::{PathScope, RuleLevel, ViolationType};
 use crate::{Error, Result};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -64,14 +66,22 @@ impl DocCoverage {
 
 /// Check documentation coverage for a Rust project
 ///
+/// Files covered by the configured `exclude` list, or where an
+/// `[[overrides]]` block turns `missing_docs` off, are left out.
+///
 /// # Errors
 ///
 /// Returns [`Error::Process`] if `cargo doc` fails to execute.
 /// Returns [`Error::Io`] if project source files cannot be read.
+/// Returns [`Error::Config`] if the configuration cannot be loaded.
 pub async fn check_documentation_coverage(project_path: &Path) -> Result<DocCoverage> {
+    let config = HierarchicalConfig::load_for_project(project_path)
+        .await?
+        .merged();
+    let scope = PathScope::new(project_path, &config)?;
     let output = run_cargo_doc(project_path).await?;
-    let missing = find_missing_docs(&output)?;
-    let (total, documented) = count_documentation_items(project_path).await?;
+    let missing = find_missing_docs(&output, project_path, &scope)?;
+    let (total, documented) = count_documentation_items(project_path, &scope).await?;
 
     let coverage_percent = calculate_coverage_percent(documented, total);
 
@@ -98,8 +108,13 @@ async fn run_cargo_doc(project_path: &Path) -> Result<std::process::Output> {
         .map_err(|e| Error::process(format!("Failed to run cargo doc: {}", e)))
 }
 
-/// Find missing documentation items from cargo doc output
-fn find_missing_docs(output: &std::process::Output) -> Result<Vec<String>> {
+/// Find missing documentation items from cargo doc output, except those in
+/// files `scope` excludes
+fn find_missing_docs(
+    output: &std::process::Output,
+    project_path: &Path,
+    scope: &PathScope,
+) -> Result<Vec<String>> {
     let stderr = String::from_utf8_lossy(&output.stderr);
     let stdout = String::from_utf8_lossy(&output.stdout);
     let mut missing = Vec::new();
@@ -108,6 +123,9 @@ fn find_missing_docs(output: &std::process::Output) -> Result<Vec<String>> {
     for line in stdout.lines() {
         if line.contains("missing_docs")
             && let Ok(json) = serde_json::from_str::<serde_json::Value>(line)
+            && !json["message"]["spans"][0]["file_name"]
+                .as_str()
+                .is_some_and(|file| is_skipped(scope, &project_path.join(file)))
             && let Some(message) = json["message"]["rendered"].as_str()
             && let Some(item_match) = extract_item_name(message)
         {
@@ -136,18 +154,26 @@ fn calculate_coverage_percent(documented: usize, total: usize) -> f32 {
 }
 
 /// Count documentation items in the project
-async fn count_documentation_items(project_path: &Path) -> Result<(usize, usize)> {
+///
+/// `target` directories and paths [`is_skipped`] in `scope` are left out.
+async fn count_documentation_items(
+    project_path: &Path,
+    scope: &PathScope,
+) -> Result<(usize, usize)> {
     let root = project_path.to_path_buf();
+    let scope = scope.clone();
 
     // Collect file paths in a blocking context (WalkDir is synchronous)
     let paths: Vec<PathBuf> = tokio::task::spawn_blocking(move || {
         walkdir::WalkDir::new(&root)
             .into_iter()
-            .filter_map(|e| e.ok())
-            .filter(|e| {
-                e.path().extension().is_some_and(|ext| ext == "rs")
-                    && !e.path().to_string_lossy().contains("target")
+            .filter_entry(|e| {
+                e.depth() == 0
+                    || !((e.file_type().is_dir() && e.file_name() == "target")
+                        || is_skipped(&scope, e.path()))
             })
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().extension().is_some_and(|ext| ext == "rs"))
             .map(|e| e.path().to_path_buf())
             .collect::<Vec<_>>()
     })
@@ -166,6 +192,13 @@ async fn count_documentation_items(project_path: &Path) -> Result<(usize, usize)
     Ok((total, documented))
 }
 
+/// Whether `path` is excluded, or has the missing-docs rule turned off
+fn is_skipped(scope: &PathScope, path: &Path) -> bool {
+    scope.is_excluded(path)
+        || scope.rule_level_at(path, &ViolationType::MissingDocs.rule_name())
+            == Some(RuleLevel::Off)
+}
+
 /// Count documentation items in a single file
 fn count_items_in_file(content: &str) -> Result<(usize, usize)> {
     let mut total = 0;
@@ -310,6 +343,38 @@ pub struct UndocStruct {}
         assert_eq!(documented, 2);
     }
 
+    #[tokio::test]
+    async fn test_count_skips_excluded_files_and_target() {
+        let project = tempfile::TempDir::new().unwrap();
+        let files = [
+            ("src/lib.rs", "/// Documented\npub fn documented() {}\n"),
+            ("src/targeting.rs", "pub fn aim() {}\n"),
+            ("src/generated/bindings.rs", "pub fn ffi() {}\n"),
+            ("src/ffi.rs", "pub fn raw() {}\n"),
+            ("target/debug/build/out.rs", "pub fn built() {}\n"),
+        ];
+        for (file, content) in files {
+            let path = project.path().join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        let config = crate::config::Config {
+            exclude: vec!["src/generated".to_string()],
+            overrides: vec![crate::config::PathOverride {
+                paths: vec!["src/ffi.rs".to_string()],
+                rules: [("missing_docs".to_string(), RuleLevel::Off)].into(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let scope = PathScope::new(project.path(), &config).unwrap();
+
+        let (total, documented) = count_documentation_items(project.path(), &scope)
+            .await
+            .unwrap();
+        assert_eq!((total, documented), (2, 1));
+    }
+
     #[test]
     fn test_coverage_calculation() {
         let coverage = DocCoverage {
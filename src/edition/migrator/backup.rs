@@ -1,24 +1,471 @@
 //! Backup operations for edition migration
+//!
+//! A backup is a snapshot of every `Cargo.toml`, `Cargo.lock` and `.rs` file
+//! in the project (honouring `.gitignore` and skipping `target/`), copied into
+//! `<backup>/files/` with a `manifest.json` recording each file's SHA-256.
+//! Files of those kinds that the migration creates are recorded in the
+//! manifest too, so a restore deletes them.
 
 use super::EditionMigrator;
 use super::types::{MigrationResult, MigrationStep};
 use crate::{Error, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+/// Name of the manifest file inside a backup directory
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// Directory under the backup root holding the copied files
+const FILES_DIR: &str = "files";
+
+/// A file captured in a backup
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BackupEntry {
+    /// Path relative to the project root
+    pub path: PathBuf,
+    /// SHA-256 of the file contents (lowercase hex)
+    pub sha256: String,
+    /// File size in bytes
+    pub size: u64,
+}
+
+/// Manifest describing a project snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// When the snapshot was taken
+    pub created_at: DateTime<Utc>,
+    /// Project the snapshot was taken from
+    pub project_path: PathBuf,
+    /// Every file in the snapshot
+    pub files: Vec<BackupEntry>,
+    /// Files created after the snapshot, relative to the project root
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub created: Vec<PathBuf>,
+}
+
+/// Files put back and files deleted by a restore
+#[derive(Debug, Clone, Default)]
+pub struct RestoreReport {
+    /// Files written back from the snapshot
+    pub restored: Vec<PathBuf>,
+    /// Files the migration created, deleted again
+    pub removed: Vec<PathBuf>,
+}
+
+impl BackupManifest {
+    /// Load the manifest of the backup in `backup_dir`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest is missing or cannot be parsed.
+    pub async fn load(backup_dir: &Path) -> Result<Self> {
+        let manifest_path = backup_dir.join(MANIFEST_FILE);
+        let contents = fs::read_to_string(&manifest_path).await.map_err(|e| {
+            Error::migration(format!(
+                "Failed to read backup manifest {}: {}",
+                manifest_path.display(),
+                e
+            ))
+        })?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// Write the manifest into `backup_dir`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest cannot be written.
+    pub async fn save(&self, backup_dir: &Path) -> Result<()> {
+        fs::write(
+            backup_dir.join(MANIFEST_FILE),
+            serde_json::to_string_pretty(self)?,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+/// Default directory holding edition migration backups for a project
+pub fn backups_root(project_path: &Path) -> PathBuf {
+    project_path.join(".ferrous-forge").join("backups")
+}
+
+/// Most recent edition backup of a project, if any
+///
+/// # Errors
+///
+/// Returns an error if the backups directory exists but cannot be read.
+pub async fn latest_backup(project_path: &Path) -> Result<Option<PathBuf>> {
+    let root = backups_root(project_path);
+    if !root.exists() {
+        return Ok(None);
+    }
+
+    let mut latest = None;
+    let mut entries = fs::read_dir(&root).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        let is_backup = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with("edition-"));
+        // Names embed a sortable timestamp, so the greatest name is the newest
+        if is_backup && path.join(MANIFEST_FILE).exists() && latest.as_ref() < Some(&path) {
+            latest = Some(path);
+        }
+    }
+    Ok(latest)
+}
+
+/// Snapshot the project's manifests and sources into `backup_dir`
+///
+/// The backups root (or `backup_dir`, when it lies elsewhere) gets a
+/// `.gitignore` so a migration that commits its changes never commits the
+/// backup.
+///
+/// # Errors
+///
+/// Returns an error if the project cannot be walked or any file cannot be
+/// read or copied.
+pub async fn snapshot_project(project_path: &Path, backup_dir: &Path) -> Result<BackupManifest> {
+    let files_dir = backup_dir.join(FILES_DIR);
+    fs::create_dir_all(&files_dir).await?;
+    let root = backups_root(project_path);
+    let ignored = if backup_dir.starts_with(&root) {
+        root
+    } else {
+        backup_dir.to_path_buf()
+    };
+    let gitignore = ignored.join(".gitignore");
+    if !gitignore.exists() {
+        fs::write(gitignore, "*\n").await?;
+    }
+
+    let mut files = Vec::new();
+    for relative in collect_backup_files(project_path, backup_dir)? {
+        let contents = fs::read(project_path.join(&relative)).await?;
+        let destination = files_dir.join(&relative);
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        fs::write(&destination, &contents).await?;
+
+        files.push(BackupEntry {
+            sha256: sha256_hex(&contents),
+            size: contents.len() as u64,
+            path: relative,
+        });
+    }
+
+    let manifest = BackupManifest {
+        created_at: Utc::now(),
+        project_path: project_path.to_path_buf(),
+        files,
+        created: Vec::new(),
+    };
+    manifest.save(backup_dir).await?;
+
+    Ok(manifest)
+}
+
+/// Record in the backup's manifest the files created since the snapshot
+///
+/// Returns every file recorded as created so far.
+///
+/// # Errors
+///
+/// Returns an error if the manifest cannot be read or written, or the
+/// project cannot be walked.
+pub async fn record_created_files(project_path: &Path, backup_dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut manifest = BackupManifest::load(backup_dir).await?;
+    for path in collect_backup_files(project_path, backup_dir)? {
+        let known = manifest.files.iter().any(|f| f.path == path);
+        if !known && !manifest.created.contains(&path) {
+            manifest.created.push(path);
+        }
+    }
+    manifest.save(backup_dir).await?;
+    Ok(manifest.created)
+}
+
+/// Restore a project from the backup in `backup_dir`
+///
+/// Every backed-up file is verified against its recorded hash before any
+/// project file is overwritten, so a damaged backup leaves the project
+/// untouched. Files recorded as created by the migration are deleted.
+///
+/// # Errors
+///
+/// Returns an error if the manifest cannot be read, a backed-up file is
+/// missing or fails verification, or a project file cannot be written or
+/// deleted.
+pub async fn restore_project(project_path: &Path, backup_dir: &Path) -> Result<RestoreReport> {
+    let manifest = BackupManifest::load(backup_dir).await?;
+    let files_dir = backup_dir.join(FILES_DIR);
+
+    let mut verified = Vec::with_capacity(manifest.files.len());
+    for entry in &manifest.files {
+        let source = files_dir.join(&entry.path);
+        let contents = fs::read(&source).await.map_err(|e| {
+            Error::migration(format!(
+                "Backup file {} is missing: {}",
+                source.display(),
+                e
+            ))
+        })?;
+        if sha256_hex(&contents) != entry.sha256 {
+            return Err(Error::migration(format!(
+                "Backup file {} does not match its recorded hash",
+                entry.path.display()
+            )));
+        }
+        verified.push((entry.path.clone(), contents));
+    }
+
+    let mut restored = Vec::with_capacity(verified.len());
+    for (relative, contents) in verified {
+        let destination = project_path.join(&relative);
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        fs::write(&destination, contents).await?;
+        restored.push(relative);
+    }
+
+    let mut removed = Vec::new();
+    for relative in manifest.created {
+        match fs::remove_file(project_path.join(&relative)).await {
+            Ok(()) => removed.push(relative),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(RestoreReport { restored, removed })
+}
+
+/// Relative paths of the files a backup should capture
+fn collect_backup_files(project_path: &Path, backup_dir: &Path) -> Result<Vec<PathBuf>> {
+    let backups = backups_root(project_path);
+    let backup_dir = backup_dir.to_path_buf();
+    let walker = ignore::WalkBuilder::new(project_path)
+        .hidden(false)
+        .require_git(false)
+        .filter_entry(move |entry| {
+            let path = entry.path();
+            let name = entry.file_name();
+            !(name == "target" || name == ".git" || path == backups || path == backup_dir)
+        })
+        .build();
+
+    let mut files = Vec::new();
+    for entry in walker {
+        let entry =
+            entry.map_err(|e| Error::migration(format!("Failed to walk project: {}", e)))?;
+        if !entry.file_type().is_some_and(|t| t.is_file()) || !is_backed_up(entry.path()) {
+            continue;
+        }
+        if let Ok(relative) = entry.path().strip_prefix(project_path) {
+            files.push(relative.to_path_buf());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Whether a file is a manifest, lockfile or Rust source
+fn is_backed_up(path: &Path) -> bool {
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    name == "Cargo.toml" || name == "Cargo.lock" || path.extension().is_some_and(|e| e == "rs")
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
 
 impl EditionMigrator {
     /// Create backup of project
     pub(super) async fn create_backup(&self, result: &mut MigrationResult) -> Result<()> {
-        let backup_dir = self
-            .backup_dir
-            .as_ref()
-            .ok_or_else(|| Error::migration("No backup directory specified"))?;
+        let backup_dir = self.backup_dir.clone().unwrap_or_else(|| {
+            backups_root(&self.project_path).join(format!(
+                "edition-{}",
+                Utc::now().format("%Y%m%d-%H%M%S%.3f")
+            ))
+        });
+
+        let manifest = snapshot_project(&self.project_path, &backup_dir).await?;
 
+        result.backup_location = Some(backup_dir.clone());
+        result.backup_path = Some(backup_dir.clone());
         result.steps_performed.push(MigrationStep {
             name: "Create backup".to_string(),
             description: "Creating project backup".to_string(),
             success: true,
-            message: Some(format!("Backup created at {:?}", backup_dir)),
+            message: Some(format!(
+                "Backed up {} files to {}",
+                manifest.files.len(),
+                backup_dir.display()
+            )),
         });
 
         Ok(())
     }
+
+    /// Restore the backup taken by this migration after a failed step
+    pub(super) async fn restore_after_failure(
+        &self,
+        result: &mut MigrationResult,
+        reason: String,
+    ) -> Result<()> {
+        result.errors.push(reason);
+
+        let Some(backup_dir) = result.backup_location.clone() else {
+            result
+                .warnings
+                .push("No backup was created; the project may be partially migrated".to_string());
+            return Ok(());
+        };
+
+        record_created_files(&self.project_path, &backup_dir).await?;
+        let report = restore_project(&self.project_path, &backup_dir).await?;
+        result.steps_performed.push(MigrationStep {
+            name: "Restore backup".to_string(),
+            description: "Restored project after failed migration".to_string(),
+            success: true,
+            message: Some(format!(
+                "Restored {} files and removed {} created files from {}",
+                report.restored.len(),
+                report.removed.len(),
+                backup_dir.display()
+            )),
+        });
+        result
+            .messages
+            .push("Migration failed; the project was restored from backup".to_string());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    async fn write(root: &Path, relative: &str, contents: &str) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).await.unwrap();
+        fs::write(path, contents).await.unwrap();
+    }
+
+    async fn sample_project() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        write(root, "Cargo.toml", "[package]\nedition = \"2021\"\n").await;
+        write(root, "src/lib.rs", "pub fn a() {}\n").await;
+        write(root, "src/ignored.rs", "// ignored\n").await;
+        write(root, "README.md", "readme\n").await;
+        write(root, "target/debug/build.rs", "// build output\n").await;
+        write(root, ".gitignore", "src/ignored.rs\n").await;
+        temp
+    }
+
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = std::process::Command::new("git")
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_leaves_git_status_clean() {
+        let project = sample_project().await;
+        git(project.path(), &["init", "--quiet"]);
+        git(project.path(), &["add", "."]);
+        git(project.path(), &["commit", "--quiet", "-m", "initial"]);
+
+        let backup = backups_root(project.path()).join("edition-test");
+        snapshot_project(project.path(), &backup).await.unwrap();
+
+        assert_eq!(git(project.path(), &["status", "--porcelain"]), "");
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_respects_gitignore_and_target() {
+        let project = sample_project().await;
+        let backup = backups_root(project.path()).join("edition-test");
+
+        let manifest = snapshot_project(project.path(), &backup).await.unwrap();
+        let paths: Vec<_> = manifest.files.iter().map(|f| f.path.clone()).collect();
+
+        assert_eq!(
+            paths,
+            vec![PathBuf::from("Cargo.toml"), PathBuf::from("src/lib.rs")]
+        );
+        assert_eq!(manifest.files[1].sha256, sha256_hex(b"pub fn a() {}\n"));
+        assert!(backup.join(FILES_DIR).join("src/lib.rs").exists());
+        assert_eq!(latest_backup(project.path()).await.unwrap(), Some(backup));
+    }
+
+    #[tokio::test]
+    async fn test_restore_reverts_changes() {
+        let project = sample_project().await;
+        let backup = backups_root(project.path()).join("edition-test");
+        snapshot_project(project.path(), &backup).await.unwrap();
+
+        write(
+            project.path(),
+            "Cargo.toml",
+            "[package]\nedition = \"2024\"\n",
+        )
+        .await;
+        write(project.path(), "src/lib.rs", "broken\n").await;
+
+        write(project.path(), "Cargo.lock", "# created\n").await;
+        write(project.path(), "src/new_module.rs", "pub fn b() {}\n").await;
+        let created = record_created_files(project.path(), &backup).await.unwrap();
+        assert_eq!(
+            created,
+            vec![
+                PathBuf::from("Cargo.lock"),
+                PathBuf::from("src/new_module.rs")
+            ]
+        );
+
+        let report = restore_project(project.path(), &backup).await.unwrap();
+        assert_eq!(report.restored.len(), 2);
+        assert_eq!(report.removed, created);
+        assert!(!project.path().join("Cargo.lock").exists());
+        assert!(!project.path().join("src/new_module.rs").exists());
+        let manifest = fs::read_to_string(project.path().join("Cargo.toml"))
+            .await
+            .unwrap();
+        assert!(manifest.contains("2021"));
+    }
+
+    #[tokio::test]
+    async fn test_restore_rejects_corrupted_backup() {
+        let project = sample_project().await;
+        let backup = backups_root(project.path()).join("edition-test");
+        snapshot_project(project.path(), &backup).await.unwrap();
+
+        write(&backup.join(FILES_DIR), "Cargo.toml", "corrupted").await;
+        write(project.path(), "src/lib.rs", "migrated\n").await;
+
+        assert!(restore_project(project.path(), &backup).await.is_err());
+        // Nothing was overwritten because verification happens first
+        let lib = fs::read_to_string(project.path().join("src/lib.rs"))
+            .await
+            .unwrap();
+        assert_eq!(lib, "migrated\n");
+    }
 }
@@ -2,9 +2,41 @@
 
 use super::types::{MigrationResult, MigrationStep};
 use super::{Edition, EditionMigrator};
-use crate::Result;
+use crate::{Error, Result};
 
 impl EditionMigrator {
+    /// Run `cargo fix --edition` to prepare code for the next edition
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if cargo cannot be run or `cargo fix` fails.
+    pub(super) async fn run_cargo_fix_edition(&self, result: &mut MigrationResult) -> Result<()> {
+        let output = tokio::process::Command::new("cargo")
+            .args([
+                "fix",
+                "--edition",
+                "--allow-dirty",
+                "--allow-staged",
+                "--allow-no-vcs",
+            ])
+            .current_dir(&self.project_path)
+            .output()
+            .await?;
+
+        let success = output.status.success();
+        result.steps_performed.push(MigrationStep {
+            name: "Run cargo fix --edition".to_string(),
+            description: "Applied compiler-suggested edition fixes".to_string(),
+            success,
+            message: (!success).then(|| String::from_utf8_lossy(&output.stderr).to_string()),
+        });
+
+        if !success {
+            return Err(Error::migration("cargo fix --edition failed"));
+        }
+        Ok(())
+    }
+
     /// Apply code migrations for new edition
     pub(super) async fn apply_code_migrations(
         &self,
@@ -1,6 +1,7 @@
 //! Edition migration assistance
 
-mod backup;
+/// Project snapshots taken before migration and restored on failure.
+pub mod backup;
 mod cargo_ops;
 mod code_migration;
 mod testing;
@@ -11,6 +12,9 @@ use crate::Result;
 use std::path::{Path, PathBuf};
 
 use super::Edition;
+pub use backup::{
+    BackupManifest, RestoreReport, latest_backup, record_created_files, restore_project,
+};
 pub use types::{
     MigrationOptions, MigrationResult, MigrationRule, MigrationStatus, MigrationStep, TestResults,
 };
@@ -38,10 +42,14 @@ impl EditionMigrator {
 
     /// Migrate project to target edition
     ///
+    /// If `cargo fix --edition`, a Cargo.toml update or the post-migration
+    /// tests fail, the backup (when enabled) is restored and the result is
+    /// returned with [`MigrationStatus::Failed`].
+    ///
     /// # Errors
     ///
     /// Returns an error if the current edition cannot be detected, backup
-    /// creation fails, or any migration step fails.
+    /// creation fails, or restoring the backup after a failure fails.
     pub async fn migrate(
         &self,
         target_edition: Edition,
@@ -56,9 +64,25 @@ impl EditionMigrator {
 
         result.status = MigrationStatus::InProgress;
 
-        // Execute migration steps
-        self.execute_migration_steps(target_edition, &options, &mut result)
-            .await?;
+        if options.create_backup {
+            self.create_backup(&mut result).await?;
+        }
+
+        // Execute migration steps, restoring the backup if any of them fail
+        if let Err(e) = self
+            .execute_migration_steps(target_edition, &options, &mut result)
+            .await
+        {
+            self.restore_after_failure(&mut result, e.to_string())
+                .await?;
+            result.status = MigrationStatus::Failed;
+            return Ok(result);
+        }
+
+        // Remember the files the migration added so a restore removes them
+        if let Some(backup_dir) = &result.backup_location {
+            record_created_files(&self.project_path, backup_dir).await?;
+        }
 
         result.status = MigrationStatus::Completed;
         Ok(result)
@@ -90,8 +114,9 @@ impl EditionMigrator {
         options: &MigrationOptions,
         result: &mut MigrationResult,
     ) -> Result<()> {
-        if options.create_backup {
-            self.create_backup(result).await?;
+        // `cargo fix --edition` must run while the old edition is still set
+        if options.apply_code_migrations {
+            self.run_cargo_fix_edition(result).await?;
         }
 
         self.update_cargo_toml(target_edition, result).await?;
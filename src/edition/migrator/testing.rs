@@ -2,10 +2,13 @@
 
 use super::EditionMigrator;
 use super::types::{MigrationResult, MigrationStep, TestResults};
-use crate::Result;
+use crate::{Error, Result};
 
 impl EditionMigrator {
     /// Run tests after migration
+    ///
+    /// A failing test suite is recorded as a failed step and returned as an
+    /// error so the migration can be rolled back.
     pub(super) async fn run_tests(&self, result: &mut MigrationResult) -> Result<()> {
         let output = tokio::process::Command::new("cargo")
             .arg("test")
@@ -46,6 +49,7 @@ impl EditionMigrator {
                 success: false,
                 message: Some(format!("Tests failed: {}", stderr)),
             });
+            return Err(Error::migration("Post-migration tests failed"));
         }
 
         Ok(())
@@ -94,7 +94,7 @@ impl std::fmt::Display for Edition {
 }
 
 /// Edition compliance status
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct EditionStatus {
     /// Current edition in use
     pub current: Edition,
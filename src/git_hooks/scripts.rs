@@ -34,43 +34,20 @@ if [ "$BYPASS_CHECK" = "active" ]; then
     exit 0
 fi
 
-# Run formatting check
+# Run the configured pre-commit checks against the staged snapshot, so
+# unstaged edits cannot change the outcome
+echo ""
+echo "🔍 Running pre-commit safety checks on staged changes..."
 echo ""
-echo "📝 Checking code formatting..."
-if ! cargo fmt -- --check >/dev/null 2>&1; then
-    echo ""
-    echo "═══════════════════════════════════════════════════"
-    echo "🛡️  FERROUS FORGE BLOCKED COMMIT"
-    echo "═══════════════════════════════════════════════════"
-    echo ""
-    echo "Code is not properly formatted."
-    echo ""
-    echo "How to fix:"
-    echo "  Run 'cargo fmt' to format your code"
-    echo ""
-    echo "To bypass (requires reason):"
-    echo "  ferrous-forge safety bypass --stage=pre-commit --reason=\"WIP\""
-    echo ""
-    echo "To bypass for this commit only:"
-    echo "  git commit --no-verify"
-    echo ""
-    exit 1
-fi
 
-# Run Ferrous Forge validation
-echo "🔍 Running Ferrous Forge validation..."
-if ! ferrous-forge validate 2>&1; then
+if ! ferrous-forge safety check --stage=pre-commit --staged --verbose 2>&1; then
     echo ""
     echo "═══════════════════════════════════════════════════"
     echo "🛡️  FERROUS FORGE BLOCKED COMMIT"
     echo "═══════════════════════════════════════════════════"
     echo ""
-    echo "Validation failed. Fix the issues before committing."
-    echo ""
-    echo "How to fix:"
-    echo "  1. Run 'ferrous-forge validate' to see all errors"
-    echo "  2. Fix the reported violations"
-    echo "  3. Try committing again"
+    echo "Safety checks failed on the staged changes. Fix the issues above,"
+    echo "stage the fixes with 'git add', and try committing again."
     echo ""
     echo "To bypass (requires reason):"
     echo "  ferrous-forge safety bypass --stage=pre-commit --reason=\"WIP commit\""
@@ -81,22 +58,6 @@ if ! ferrous-forge validate 2>&1; then
     exit 1
 fi
 
-# Run clippy
-echo "📎 Running clippy checks..."
-if ! cargo clippy -- -D warnings 2>/dev/null; then
-    echo ""
-    echo "═══════════════════════════════════════════════════"
-    echo "🛡️  FERROUS FORGE BLOCKED COMMIT"
-    echo "═══════════════════════════════════════════════════"
-    echo ""
-    echo "Clippy found issues. Fix the warnings before committing."
-    echo ""
-    echo "How to fix:"
-    echo "  Run 'cargo clippy -- -D warnings' to see all issues"
-    echo ""
-    exit 1
-fi
-
 echo ""
 echo "✅ All pre-commit checks passed! Commit allowed."
 echo ""
@@ -47,6 +47,7 @@
 //! - [`commands`] — Implementation of all Ferrous Forge commands
 //! - [`config`] — Configuration management and hierarchical config system
 //! - [`error`] — Error types and result handling
+//! - [`output`] — Machine-readable JSON/YAML output for `--format`
 //!
 //! ### Standards & Validation
 //! - [`standards`] — Development standards definitions and enforcement
@@ -77,6 +78,8 @@
 
 /// AI-powered violation analysis and fix suggestions
 pub mod ai_analyzer;
+/// Tamper-evident, hash-chained audit logs
+pub mod audit_chain;
 /// Cargo command interception for publish validation
 pub mod cargo_intercept;
 /// Command line interface definitions and argument parsing
@@ -95,6 +98,8 @@ pub mod error;
 pub mod formatting;
 /// Git hooks installation and management
 pub mod git_hooks;
+/// Machine-readable JSON/YAML output for the global `--format` flag
+pub mod output;
 /// Performance optimizations for validation
 pub mod performance;
 /// Rust version checking and compatibility validation
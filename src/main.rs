@@ -9,7 +9,7 @@
 #![warn(missing_docs)]
 
 use clap::Parser;
-use ferrous_forge::{Result, cli::Cli, commands};
+use ferrous_forge::{Result, cli::Cli, commands, output};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -19,6 +19,14 @@ async fn main() -> Result<()> {
     // Parse command line arguments
     let cli = Cli::parse();
 
+    // Route human chatter to stderr when a structured format is requested
+    output::set_format(cli.format);
+
+    // Layer the --config file over the system, user and project configs
+    if let Some(path) = cli.config {
+        ferrous_forge::config::set_cli_config_path(path);
+    }
+
     // Execute the appropriate command
     execute_command(cli.command).await
 }
@@ -53,8 +61,41 @@ async fn execute_command(command: commands::Commands) -> Result<()> {
             ai_report,
             compare_previous: _,
             locked_only,
-        } => commands::validate::execute(path, ai_report, locked_only).await,
-        commands::Commands::Rollback { version } => commands::rollback::execute(version).await,
+            sarif,
+            write_baseline,
+            list_suppressions,
+            changed_only,
+            jobs,
+            timings,
+            package,
+            workspace,
+            exclude,
+        } => {
+            commands::validate::execute(
+                path,
+                commands::validate::ValidateOptions {
+                    ai_report,
+                    locked_only,
+                    sarif,
+                    write_baseline,
+                    list_suppressions,
+                    changed_only,
+                    jobs,
+                    timings,
+                    selection: ferrous_forge::validation::PackageSelection {
+                        packages: package,
+                        workspace,
+                        exclude,
+                    },
+                },
+            )
+            .await
+        }
+        commands::Commands::Rollback {
+            version,
+            previous,
+            list,
+        } => commands::rollback::execute(version, previous, list).await,
         commands::Commands::Uninstall { confirm } => commands::uninstall::execute(confirm).await,
         commands::Commands::Rust { command } => execute_rust_command(command).await,
         commands::Commands::Edition { command } => execute_edition_command(command).await,
@@ -67,7 +108,27 @@ async fn execute_command(command: commands::Commands) -> Result<()> {
             dry_run,
             limit,
             ai_analysis,
-        } => commands::fix::execute_with_ai(path, only, skip, dry_run, limit, ai_analysis).await,
+            test,
+            no_verify,
+        } => {
+            let verification = if no_verify {
+                commands::fix::Verification::Off
+            } else if test {
+                commands::fix::Verification::Test
+            } else {
+                commands::fix::Verification::Check
+            };
+            commands::fix::execute_with_verification(
+                path,
+                only,
+                skip,
+                dry_run,
+                limit,
+                ai_analysis,
+                verification,
+            )
+            .await
+        }
     }
 }
 
@@ -121,6 +182,9 @@ async fn execute_edition_command(command: commands::EditionCommand) -> Result<()
         commands::EditionCommand::Analyze { path, edition } => {
             commands::edition::handle_analyze(&path, &edition).await
         }
+        commands::EditionCommand::Restore { path, backup } => {
+            commands::edition::handle_restore(&path, backup).await
+        }
     }
 }
 
@@ -138,7 +202,24 @@ async fn execute_safety_command(command: commands::SafetyCommand) -> Result<()>
             stage,
             path,
             verbose,
-        } => commands::safety::handle_check(&stage, &path, verbose).await,
+            sarif,
+            check,
+            custom_only,
+            staged,
+        } => {
+            commands::safety::handle_check(
+                &stage,
+                &path,
+                commands::safety::CheckOptions {
+                    verbose,
+                    sarif,
+                    checks: check,
+                    custom_only,
+                    staged,
+                },
+            )
+            .await
+        }
         commands::SafetyCommand::Test { path } => {
             commands::safety::test_individual_checks(&path).await
         }
@@ -147,12 +228,45 @@ async fn execute_safety_command(command: commands::SafetyCommand) -> Result<()>
             reason,
             duration,
             user,
-        } => commands::safety::bypass_cmd::handle_bypass(stage, reason, duration, user).await,
-        commands::SafetyCommand::Audit { limit } => {
-            commands::safety::bypass_cmd::handle_audit(limit).await
+            path,
+            branch,
+            commit,
+            once,
+            list,
+            revoke,
+        } => {
+            if list {
+                commands::safety::bypass_cmd::handle_list().await
+            } else if let Some(id) = revoke {
+                commands::safety::bypass_cmd::handle_revoke(&id, user).await
+            } else if let (Some(stage), Some(reason)) = (stage, reason) {
+                let scope = commands::safety::bypass_cmd::ScopeOptions {
+                    path,
+                    branch,
+                    commit,
+                    once,
+                };
+                commands::safety::bypass_cmd::handle_bypass(stage, reason, duration, user, scope)
+                    .await
+            } else {
+                Err(ferrous_forge::Error::config(
+                    "--stage and --reason are required to create a bypass",
+                ))
+            }
+        }
+        commands::SafetyCommand::Audit {
+            limit,
+            verify,
+            export,
+        } => {
+            if verify || export.is_some() {
+                commands::safety::bypass_cmd::handle_verify_audit(export.as_deref()).await
+            } else {
+                commands::safety::bypass_cmd::handle_audit(limit).await
+            }
         }
-        commands::SafetyCommand::CheckBypass { stage } => {
-            commands::safety::handle_check_bypass(stage).await
+        commands::SafetyCommand::CheckBypass { stage, path } => {
+            commands::safety::handle_check_bypass(stage, &path).await
         }
         commands::SafetyCommand::Uninstall { path, confirm } => {
             commands::safety::handle_uninstall(&path, confirm).await
@@ -0,0 +1,154 @@
+//! Machine-readable output for the global `--format` flag
+//!
+//! Commands that support structured output wrap their results in an
+//! [`OutputDocument`] and write it to stdout as JSON or YAML. While a
+//! machine-readable format is active, human progress text is routed to
+//! stderr through [`human`] so stdout stays parseable.
+//!
+//! The [`sarif`] submodule writes SARIF 2.1.0 logs for code-scanning tools.
+
+/// SARIF 2.1.0 export for validation and safety reports
+pub mod sarif;
+
+use crate::cli::OutputFormat;
+use crate::{Error, Result};
+use serde::Serialize;
+use std::sync::OnceLock;
+
+/// Version of the structured output schema
+///
+/// Bump the major component whenever a field is removed or renamed.
+pub const SCHEMA_VERSION: &str = "1.0";
+
+static FORMAT: OnceLock<OutputFormat> = OnceLock::new();
+
+/// Set the process-wide output format (first call wins)
+pub fn set_format(format: OutputFormat) {
+    FORMAT.get_or_init(|| format);
+}
+
+/// The active output format, `Human` if none was set
+pub fn current_format() -> OutputFormat {
+    FORMAT.get().copied().unwrap_or(OutputFormat::Human)
+}
+
+/// Returns true when stdout is reserved for a JSON or YAML document
+pub fn is_machine_readable() -> bool {
+    current_format() != OutputFormat::Human
+}
+
+/// Print a line of human-oriented text
+///
+/// Goes to stdout in human mode and to stderr when a machine-readable
+/// format is active.
+pub fn human(line: impl std::fmt::Display) {
+    if is_machine_readable() {
+        eprintln!("{}", line);
+    } else {
+        println!("{}", line);
+    }
+}
+
+/// Envelope shared by every structured command output
+#[derive(Debug, Serialize)]
+pub struct OutputDocument<'a, T: Serialize> {
+    /// Version of the output schema
+    pub schema_version: &'static str,
+    /// Version of Ferrous Forge that produced the document
+    pub ferrous_forge_version: &'static str,
+    /// Command that produced the document (e.g. `safety check`)
+    pub command: &'a str,
+    /// When the document was generated
+    pub generated_at: chrono::DateTime<chrono::Utc>,
+    /// Command-specific payload
+    pub data: &'a T,
+}
+
+impl<'a, T: Serialize> OutputDocument<'a, T> {
+    /// Wrap a command's payload in the standard envelope
+    pub fn new(command: &'a str, data: &'a T) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            ferrous_forge_version: crate::VERSION,
+            command,
+            generated_at: chrono::Utc::now(),
+            data,
+        }
+    }
+}
+
+/// Render a command's payload in the given format
+///
+/// # Errors
+///
+/// Returns an error if the format is `Human` or serialization fails.
+pub fn render<T: Serialize>(format: OutputFormat, command: &str, data: &T) -> Result<String> {
+    let document = OutputDocument::new(command, data);
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(&document)?),
+        OutputFormat::Yaml => serde_norway::to_string(&document)
+            .map_err(|e| Error::config(format!("Failed to serialize YAML output: {}", e))),
+        OutputFormat::Human => Err(Error::cli("Human output has no structured representation")),
+    }
+}
+
+/// Write a command's payload to stdout in the active format
+///
+/// # Errors
+///
+/// Returns an error if no machine-readable format is active or
+/// serialization fails.
+pub fn emit<T: Serialize>(command: &str, data: &T) -> Result<()> {
+    let rendered = render(current_format(), command, data)?;
+    println!("{}", rendered.trim_end());
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Payload {
+        passed: bool,
+        count: usize,
+    }
+
+    #[test]
+    fn test_render_json_envelope() {
+        let payload = Payload {
+            passed: true,
+            count: 3,
+        };
+        let rendered = render(OutputFormat::Json, "validate", &payload).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&rendered).unwrap();
+
+        assert_eq!(value["schema_version"], SCHEMA_VERSION);
+        assert_eq!(value["command"], "validate");
+        assert_eq!(value["data"]["passed"], true);
+        assert_eq!(value["data"]["count"], 3);
+    }
+
+    #[test]
+    fn test_render_yaml_envelope() {
+        let payload = Payload {
+            passed: false,
+            count: 0,
+        };
+        let rendered = render(OutputFormat::Yaml, "status", &payload).unwrap();
+
+        assert!(rendered.contains(&format!("schema_version: '{}'", SCHEMA_VERSION)));
+        assert!(rendered.contains("command: status"));
+        assert!(rendered.contains("passed: false"));
+    }
+
+    #[test]
+    fn test_render_human_is_rejected() {
+        let payload = Payload {
+            passed: true,
+            count: 0,
+        };
+        assert!(render(OutputFormat::Human, "status", &payload).is_err());
+    }
+}
@@ -0,0 +1,572 @@
+//! SARIF 2.1.0 export for validation and safety reports
+//!
+//! Converts [`Violation`]s and failed safety [`CheckResult`]s into a SARIF
+//! log that code-scanning dashboards can ingest. Each `ViolationType` and
+//! each `CheckType` becomes a rule; each violation or check error becomes a
+//! result referencing that rule. Violation rules carry no default level
+//! because a rule's severity is configurable, so every result states its
+//! own.
+
+use crate::commands::validate::ai_report::get_fix_strategy;
+use crate::safety::checks::CheckRegistry;
+use crate::safety::{CheckResult, SafetyReport};
+use crate::validation::{Severity, Violation};
+use crate::{Error, Result};
+use regex::Regex;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// SARIF specification version written by this module
+pub const SARIF_VERSION: &str = "2.1.0";
+
+/// JSON schema for SARIF 2.1.0 logs
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Base id that result locations are relative to
+const SRCROOT: &str = "%SRCROOT%";
+
+/// Matches `path/to/file.rs:LINE[:COL]` inside tool output
+static RUST_LOCATION: LazyLock<Option<Regex>> =
+    LazyLock::new(|| Regex::new(r"([\w./\\-]+\.rs):(\d+)(?::(\d+))?").ok());
+
+/// Top-level SARIF log
+#[derive(Debug, Serialize)]
+pub struct SarifLog {
+    /// Schema URI
+    #[serde(rename = "$schema")]
+    pub schema: &'static str,
+    /// SARIF version
+    pub version: &'static str,
+    /// Analysis runs (Ferrous Forge always writes one)
+    pub runs: Vec<SarifRun>,
+}
+
+/// A single analysis run
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRun {
+    /// Tool that produced the run
+    pub tool: SarifTool,
+    /// Base URIs that artifact locations resolve against
+    pub original_uri_base_ids: BTreeMap<&'static str, SarifArtifactLocation>,
+    /// Results of the run
+    pub results: Vec<SarifResult>,
+}
+
+/// Tool wrapper
+#[derive(Debug, Serialize)]
+pub struct SarifTool {
+    /// The analysis driver
+    pub driver: SarifDriver,
+}
+
+/// Tool component describing Ferrous Forge and its rules
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifDriver {
+    /// Tool name
+    pub name: &'static str,
+    /// Tool version
+    pub version: &'static str,
+    /// Tool homepage
+    pub information_uri: &'static str,
+    /// Rules referenced by results
+    pub rules: Vec<SarifRule>,
+}
+
+/// Rule (reporting descriptor)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRule {
+    /// Stable rule id
+    pub id: String,
+    /// One-line description
+    pub short_description: SarifMessage,
+    /// How to fix results of this rule
+    pub help: SarifMessage,
+    /// Default severity of the rule (absent when its results differ)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_configuration: Option<SarifConfiguration>,
+}
+
+/// Rule configuration
+#[derive(Debug, Serialize)]
+pub struct SarifConfiguration {
+    /// Default level (`error`, `warning` or `note`)
+    pub level: &'static str,
+}
+
+/// A plain-text message
+#[derive(Debug, Serialize)]
+pub struct SarifMessage {
+    /// Message text
+    pub text: String,
+}
+
+/// A single finding
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    /// Id of the rule this result belongs to
+    pub rule_id: String,
+    /// Index of the rule in the driver's rule list
+    pub rule_index: usize,
+    /// Result level
+    pub level: &'static str,
+    /// Result message
+    pub message: SarifMessage,
+    /// Where the finding is (empty for project-wide results)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<SarifLocation>,
+}
+
+/// Location wrapper
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifLocation {
+    /// Physical file location
+    pub physical_location: SarifPhysicalLocation,
+}
+
+/// File and region of a finding
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifPhysicalLocation {
+    /// The file
+    pub artifact_location: SarifArtifactLocation,
+    /// The line/column range (absent for whole-file results)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<SarifRegion>,
+}
+
+/// A file URI, optionally relative to a base id
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifArtifactLocation {
+    /// File URI
+    pub uri: String,
+    /// Base id the URI is relative to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri_base_id: Option<&'static str>,
+}
+
+/// 1-based line and column of a finding
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRegion {
+    /// Start line
+    pub start_line: usize,
+    /// Start column (absent when the whole line is meant)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_column: Option<usize>,
+}
+
+/// Incrementally builds a single-run SARIF log
+pub struct SarifBuilder {
+    project_root: PathBuf,
+    rules: Vec<SarifRule>,
+    rule_indices: BTreeMap<String, usize>,
+    results: Vec<SarifResult>,
+}
+
+impl SarifBuilder {
+    /// Start a log whose locations are relative to `project_root`
+    pub fn new(project_root: &Path) -> Self {
+        Self {
+            project_root: project_root.to_path_buf(),
+            rules: Vec::new(),
+            rule_indices: BTreeMap::new(),
+            results: Vec::new(),
+        }
+    }
+
+    /// Add one result per validation violation
+    pub fn add_violations(&mut self, violations: &[Violation]) -> &mut Self {
+        for violation in violations {
+            let rule_id = violation.violation_type.label();
+            let rule_index = self.rule_index(&rule_id, || {
+                let (strategy, example, _) = get_fix_strategy(&rule_id);
+                SarifRule {
+                    id: rule_id.clone(),
+                    short_description: message(violation.violation_type.description()),
+                    help: message(join_help(&strategy, &example)),
+                    default_configuration: None,
+                }
+            });
+
+            // Toolchain violations have no file a dashboard could show
+            let locations = if violation.is_system() {
+                Vec::new()
+            } else {
+                let region = (violation.line > 0).then(|| SarifRegion {
+                    start_line: violation.line,
+                    start_column: (violation.column > 0).then_some(violation.column),
+                });
+                vec![self.location(&violation.file, region)]
+            };
+            self.results.push(SarifResult {
+                rule_id,
+                rule_index,
+                level: severity_level(&violation.severity),
+                message: message(&violation.message),
+                locations,
+            });
+        }
+        self
+    }
+
+    /// Add one result per error of every failed check in a safety report
+    pub fn add_safety_report(&mut self, report: &SafetyReport) -> &mut Self {
+        for check in report
+            .checks
+            .iter()
+            .filter(|c| !c.passed || c.has_warnings())
+        {
+            self.add_check_result(check);
+        }
+        self
+    }
+
+    /// Add one result per error and per warning of a check
+    pub fn add_check_result(&mut self, check: &CheckResult) -> &mut Self {
+        let rule_id = format!("safety/{}", check.check_type.name());
+        let rule_index = self.rule_index(&rule_id, || SarifRule {
+            id: rule_id.clone(),
+            short_description: message(CheckRegistry::get_description(&check.check_type)),
+            help: message(if check.suggestions.is_empty() {
+                format!(
+                    "Fix the issues reported by the {}",
+                    check.check_type.display_name()
+                )
+            } else {
+                check.suggestions.join("\n")
+            }),
+            default_configuration: Some(SarifConfiguration { level: "error" }),
+        });
+
+        for error in &check.errors {
+            let locations = self.location_in_text(error).into_iter().collect::<Vec<_>>();
+            self.results.push(SarifResult {
+                rule_id: rule_id.clone(),
+                rule_index,
+                level: "error",
+                message: message(error),
+                locations,
+            });
+        }
+        for warning in &check.warnings {
+            let locations = self
+                .location_in_text(warning)
+                .into_iter()
+                .collect::<Vec<_>>();
+            self.results.push(SarifResult {
+                rule_id: rule_id.clone(),
+                rule_index,
+                level: "warning",
+                message: message(warning),
+                locations,
+            });
+        }
+        self
+    }
+
+    /// Finish the log
+    pub fn build(self) -> SarifLog {
+        let mut base_ids = BTreeMap::new();
+        base_ids.insert(
+            SRCROOT,
+            SarifArtifactLocation {
+                uri: directory_uri(&self.project_root),
+                uri_base_id: None,
+            },
+        );
+
+        SarifLog {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "ferrous-forge",
+                        version: crate::VERSION,
+                        information_uri: env!("CARGO_PKG_REPOSITORY"),
+                        rules: self.rules,
+                    },
+                },
+                original_uri_base_ids: base_ids,
+                results: self.results,
+            }],
+        }
+    }
+
+    /// Index of `rule_id`, registering it with `make_rule` on first use
+    fn rule_index(&mut self, rule_id: &str, make_rule: impl FnOnce() -> SarifRule) -> usize {
+        if let Some(index) = self.rule_indices.get(rule_id) {
+            return *index;
+        }
+        let index = self.rules.len();
+        self.rules.push(make_rule());
+        self.rule_indices.insert(rule_id.to_string(), index);
+        index
+    }
+
+    /// Location of `file`, relative to the project root when possible
+    fn location(&self, file: &Path, region: Option<SarifRegion>) -> SarifLocation {
+        let artifact_location = match file.strip_prefix(&self.project_root) {
+            Ok(relative) => SarifArtifactLocation {
+                uri: path_to_uri(relative),
+                uri_base_id: Some(SRCROOT),
+            },
+            Err(_) if file.is_relative() => SarifArtifactLocation {
+                uri: path_to_uri(file),
+                uri_base_id: Some(SRCROOT),
+            },
+            Err(_) => SarifArtifactLocation {
+                uri: format!("file://{}", path_to_uri(file)),
+                uri_base_id: None,
+            },
+        };
+        SarifLocation {
+            physical_location: SarifPhysicalLocation {
+                artifact_location,
+                region,
+            },
+        }
+    }
+
+    /// Best-effort location from a `file.rs:line[:col]` reference in tool output
+    fn location_in_text(&self, text: &str) -> Option<SarifLocation> {
+        let captures = RUST_LOCATION.as_ref()?.captures(text)?;
+        let file = Path::new(captures.get(1)?.as_str());
+        let start_line = captures.get(2)?.as_str().parse().ok()?;
+        let start_column = captures.get(3).and_then(|c| c.as_str().parse().ok());
+        Some(self.location(
+            file,
+            Some(SarifRegion {
+                start_line,
+                start_column,
+            }),
+        ))
+    }
+}
+
+/// Serialize a SARIF log to `path` as pretty-printed JSON
+///
+/// # Errors
+///
+/// Returns an error if serialization fails or the file cannot be written.
+pub async fn write_log(log: &SarifLog, path: &Path) -> Result<()> {
+    let json = serde_json::to_string_pretty(log)?;
+    tokio::fs::write(path, json).await.map_err(|e| {
+        Error::io(format!(
+            "Failed to write SARIF log to {}: {}",
+            path.display(),
+            e
+        ))
+    })
+}
+
+fn severity_level(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+fn message(text: impl Into<String>) -> SarifMessage {
+    SarifMessage { text: text.into() }
+}
+
+fn join_help(strategy: &str, example: &str) -> String {
+    if example.is_empty() {
+        strategy.to_string()
+    } else {
+        format!("{}\n\n{}", strategy, example)
+    }
+}
+
+/// Forward-slash URI path with spaces and percent signs escaped
+fn path_to_uri(path: &Path) -> String {
+    path.to_string_lossy()
+        .replace('\\', "/")
+        .replace('%', "%25")
+        .replace(' ', "%20")
+}
+
+/// `file://` URI of a directory, with the trailing slash SARIF requires
+fn directory_uri(dir: &Path) -> String {
+    let absolute = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+    let mut uri = path_to_uri(&absolute);
+    if !uri.starts_with('/') {
+        uri.insert(0, '/');
+    }
+    if !uri.ends_with('/') {
+        uri.push('/');
+    }
+    format!("file://{}", uri)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::safety::CheckType;
+    use crate::validation::ViolationType;
+
+    fn to_json(log: &SarifLog) -> serde_json::Value {
+        serde_json::to_value(log).unwrap()
+    }
+
+    #[test]
+    fn test_violations_become_rules_and_results() {
+        let root = Path::new("/project");
+        let violations = vec![
+            Violation::new(
+                ViolationType::UnwrapInProduction,
+                root.join("src/lib.rs"),
+                12,
+                "unwrap in production".to_string(),
+                Severity::Error,
+            )
+            .with_column(9),
+            Violation::new(
+                ViolationType::UnwrapInProduction,
+                root.join("src/main.rs"),
+                3,
+                "another unwrap".to_string(),
+                Severity::Error,
+            ),
+            Violation::new(
+                ViolationType::FileTooLarge,
+                root.join("src/big.rs"),
+                0,
+                "too large".to_string(),
+                Severity::Warning,
+            ),
+        ];
+
+        let mut builder = SarifBuilder::new(root);
+        builder.add_violations(&violations);
+        let json = to_json(&builder.build());
+        let run = &json["runs"][0];
+
+        assert_eq!(json["version"], SARIF_VERSION);
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 2);
+        assert_eq!(
+            run["tool"]["driver"]["rules"][0]["id"],
+            "UnwrapInProduction"
+        );
+        assert!(
+            run["tool"]["driver"]["rules"][0]["help"]["text"]
+                .as_str()
+                .unwrap()
+                .contains("Replace unwrap with ?")
+        );
+
+        let first = &run["results"][0];
+        let location = &first["locations"][0]["physicalLocation"];
+        assert_eq!(first["ruleIndex"], 0);
+        assert_eq!(first["level"], "error");
+        assert_eq!(location["artifactLocation"]["uri"], "src/lib.rs");
+        assert_eq!(location["artifactLocation"]["uriBaseId"], SRCROOT);
+        assert_eq!(location["region"]["startLine"], 12);
+        assert_eq!(location["region"]["startColumn"], 9);
+
+        assert!(
+            run["results"][1]["locations"][0]["physicalLocation"]["region"]
+                .get("startColumn")
+                .is_none()
+        );
+        let whole_file = &run["results"][2];
+        assert_eq!(whole_file["level"], "warning");
+        assert!(
+            whole_file["locations"][0]["physicalLocation"]
+                .get("region")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_result_levels_and_system_violations() {
+        let root = Path::new("/project");
+        let violations = vec![
+            Violation::new(
+                ViolationType::UnwrapInProduction,
+                root.join("src/lib.rs"),
+                1,
+                "unwrap".to_string(),
+                Severity::Warning,
+            ),
+            Violation::new(
+                ViolationType::UnwrapInProduction,
+                root.join("src/main.rs"),
+                2,
+                "unwrap".to_string(),
+                Severity::Error,
+            ),
+            Violation::new(
+                ViolationType::OldRustVersion,
+                PathBuf::from(crate::validation::SYSTEM_FILE),
+                0,
+                "too old".to_string(),
+                Severity::Error,
+            ),
+        ];
+
+        let mut builder = SarifBuilder::new(root);
+        builder.add_violations(&violations);
+        let json = to_json(&builder.build());
+        let run = &json["runs"][0];
+
+        assert!(
+            run["tool"]["driver"]["rules"][0]
+                .get("defaultConfiguration")
+                .is_none()
+        );
+        assert_eq!(run["results"][0]["level"], "warning");
+        assert_eq!(run["results"][1]["level"], "error");
+        assert!(run["results"][2].get("locations").is_none());
+    }
+
+    #[test]
+    fn test_check_errors_become_results_under_check_type() {
+        let mut clippy = CheckResult::new(CheckType::Clippy);
+        clippy.add_error("Clippy lints found");
+        clippy.add_error("Clippy: error: used `unwrap()` at src/lib.rs:4:13");
+        clippy.add_suggestion("Fix clippy warnings before proceeding");
+        let format = CheckResult::new(CheckType::Format);
+
+        let mut report = SafetyReport::new(crate::safety::PipelineStage::PreCommit);
+        report.add_check(clippy);
+        report.add_check(format);
+
+        let mut builder = SarifBuilder::new(Path::new("/project"));
+        builder.add_safety_report(&report);
+        let json = to_json(&builder.build());
+        let run = &json["runs"][0];
+
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0]["id"], "safety/clippy");
+        assert_eq!(
+            rules[0]["help"]["text"],
+            "Fix clippy warnings before proceeding"
+        );
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(results[0].get("locations").is_none());
+        let region = &results[1]["locations"][0]["physicalLocation"]["region"];
+        assert_eq!(region["startLine"], 4);
+        assert_eq!(region["startColumn"], 13);
+    }
+
+    #[test]
+    fn test_directory_uri_has_trailing_slash() {
+        let uri = directory_uri(Path::new("/does/not exist"));
+        assert_eq!(uri, "file:///does/not%20exist/");
+    }
+}
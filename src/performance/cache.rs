@@ -1,8 +1,20 @@
 //! Caching strategies for performance optimization
-
+//!
+//! [`Cache`] and its wrappers are in-memory, TTL-based caches.
+//! [`PersistentValidationCache`] stores per-file validation results on disk
+//! so unchanged files are not re-validated across runs.
+
+use crate::config::Config;
+use crate::validation::{Suppression, Violation};
+use crate::{Error, Result};
 use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 use tracing::{debug, trace};
 
@@ -33,8 +45,8 @@ where
 {
     storage: Arc<DashMap<K, CacheEntry<V>>>,
     ttl: Duration,
-    hits: Arc<std::sync::atomic::AtomicU64>,
-    misses: Arc<std::sync::atomic::AtomicU64>,
+    hits: Arc<AtomicU64>,
+    misses: Arc<AtomicU64>,
 }
 
 impl<K, V> Cache<K, V>
@@ -47,8 +59,8 @@ where
         Self {
             storage: Arc::new(DashMap::new()),
             ttl,
-            hits: Arc::new(std::sync::atomic::AtomicU64::new(0)),
-            misses: Arc::new(std::sync::atomic::AtomicU64::new(0)),
+            hits: Arc::new(AtomicU64::new(0)),
+            misses: Arc::new(AtomicU64::new(0)),
         }
     }
 
@@ -56,7 +68,7 @@ where
     pub fn get(&self, key: &K) -> Option<V> {
         if let Some(entry) = self.storage.get(key) {
             if !entry.is_expired() {
-                self.hits.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                self.hits.fetch_add(1, Ordering::Relaxed);
                 trace!("Cache hit for key");
                 return Some(entry.value.clone());
             } else {
@@ -67,8 +79,7 @@ where
             }
         }
 
-        self.misses
-            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        self.misses.fetch_add(1, Ordering::Relaxed);
         trace!("Cache miss for key");
         None
     }
@@ -95,8 +106,8 @@ where
 
     /// Get cache hit rate
     pub fn hit_rate(&self) -> f64 {
-        let hits = self.hits.load(std::sync::atomic::Ordering::Relaxed);
-        let misses = self.misses.load(std::sync::atomic::Ordering::Relaxed);
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
         let total = hits + misses;
 
         if total > 0 {
@@ -110,8 +121,8 @@ where
     pub fn stats(&self) -> CacheStats {
         CacheStats {
             entries: self.storage.len(),
-            hits: self.hits.load(std::sync::atomic::Ordering::Relaxed),
-            misses: self.misses.load(std::sync::atomic::Ordering::Relaxed),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
             hit_rate: self.hit_rate(),
         }
     }
@@ -132,7 +143,7 @@ pub struct CacheStats {
 
 /// Validation result cache
 pub struct ValidationCache {
-    cache: Cache<String, Vec<crate::validation::Violation>>,
+    cache: Cache<String, Vec<Violation>>,
 }
 
 impl ValidationCache {
@@ -144,12 +155,12 @@ impl ValidationCache {
     }
 
     /// Get cached validation results
-    pub fn get_results(&self, file_path: &str) -> Option<Vec<crate::validation::Violation>> {
+    pub fn get_results(&self, file_path: &str) -> Option<Vec<Violation>> {
         self.cache.get(&file_path.to_string())
     }
 
     /// Cache validation results
-    pub fn cache_results(&self, file_path: String, violations: Vec<crate::validation::Violation>) {
+    pub fn cache_results(&self, file_path: String, violations: Vec<Violation>) {
         self.cache.insert(file_path, violations);
     }
 
@@ -164,9 +175,192 @@ impl ValidationCache {
     }
 }
 
+/// Location of the on-disk validation cache relative to the project root
+pub const VALIDATION_CACHE_FILE: &str = ".ferrous-forge/cache/validation.json";
+
+/// Current on-disk cache format version
+const VALIDATION_CACHE_FORMAT: u32 = 1;
+
+/// Stored validation result for one file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedFileResult {
+    /// SHA-256 of the file contents the result was computed from
+    pub sha256: String,
+    /// Violations after inline suppressions were applied
+    pub violations: Vec<Violation>,
+    /// Suppressions found in the file, with usage counts
+    pub suppressions: Vec<Suppression>,
+}
+
+/// On-disk format of the validation cache
+#[derive(Debug, Serialize, Deserialize)]
+struct ValidationCacheFile {
+    format: u32,
+    /// Ferrous Forge version that wrote the cache
+    version: String,
+    /// Hash of the configuration the results were computed with
+    config_hash: String,
+    entries: HashMap<PathBuf, CachedFileResult>,
+}
+
+/// Persistent cache of per-file validation results
+///
+/// Results are keyed by file path and reused only when the file's SHA-256
+/// matches. The whole cache is discarded when the Ferrous Forge version or
+/// the configuration hash differs from the one that wrote it.
+pub struct PersistentValidationCache {
+    path: PathBuf,
+    data: ValidationCacheFile,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    dirty: bool,
+}
+
+impl PersistentValidationCache {
+    /// Path of the cache file for a project
+    pub fn path(project_root: &Path) -> PathBuf {
+        project_root.join(VALIDATION_CACHE_FILE)
+    }
+
+    /// Load the project's cache for `config`
+    ///
+    /// A missing, unreadable or stale cache yields an empty one; the cache
+    /// is an optimization and never fails validation.
+    pub fn load(project_root: &Path, config: &Config) -> Self {
+        Self::load_with_hash(project_root, &config_hash(config))
+    }
+
+    /// Load the project's cache for the configurations hashing to
+    /// `config_hash`, e.g. when workspace members have their own config
+    pub fn load_with_hash(project_root: &Path, config_hash: &str) -> Self {
+        let path = Self::path(project_root);
+        let config_hash = config_hash.to_string();
+        let cached = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| serde_json::from_str::<ValidationCacheFile>(&contents).ok())
+            .filter(|data| {
+                data.format == VALIDATION_CACHE_FORMAT
+                    && data.version == crate::VERSION
+                    && data.config_hash == config_hash
+            });
+        if cached.is_none() && path.exists() {
+            debug!("Discarding stale validation cache {}", path.display());
+        }
+
+        Self {
+            data: cached.unwrap_or_else(|| ValidationCacheFile {
+                format: VALIDATION_CACHE_FORMAT,
+                version: crate::VERSION.to_string(),
+                config_hash,
+                entries: HashMap::new(),
+            }),
+            path,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            dirty: false,
+        }
+    }
+
+    /// Cached result for `file` if its contents still hash to `sha256`
+    ///
+    /// Takes `&self` so worker threads can look up results concurrently.
+    pub fn get(&self, file: &Path, sha256: &str) -> Option<CachedFileResult> {
+        match self.data.entries.get(file) {
+            Some(entry) if entry.sha256 == sha256 => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(entry.clone())
+            }
+            _ => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// Store the result for `file`
+    pub fn insert(&mut self, file: PathBuf, result: CachedFileResult) {
+        self.data.entries.insert(file, result);
+        self.dirty = true;
+    }
+
+    /// Drop entries for files not in `files` (deleted or renamed)
+    pub fn retain_files(&mut self, files: &[PathBuf]) {
+        let before = self.data.entries.len();
+        let files: HashSet<&PathBuf> = files.iter().collect();
+        self.data.entries.retain(|path, _| files.contains(path));
+        self.dirty |= self.data.entries.len() != before;
+    }
+
+    /// Write the cache if anything changed
+    ///
+    /// The cache directory gets a `.gitignore` so the cache is never
+    /// committed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache file cannot be written.
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+            let gitignore = dir.join(".gitignore");
+            if !gitignore.exists() {
+                std::fs::write(gitignore, "*\n")?;
+            }
+        }
+        let contents = serde_json::to_string(&self.data)?;
+        std::fs::write(&self.path, contents).map_err(|e| {
+            Error::io(format!(
+                "Failed to write validation cache {}: {}",
+                self.path.display(),
+                e
+            ))
+        })?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Hit and miss counts since the cache was loaded
+    pub fn stats(&self) -> CacheStats {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let total = hits + misses;
+        CacheStats {
+            entries: self.data.entries.len(),
+            hits,
+            misses,
+            hit_rate: if total > 0 {
+                hits as f64 / total as f64
+            } else {
+                0.0
+            },
+        }
+    }
+}
+
+/// Stable hash of a configuration
+///
+/// Serializing through `serde_json::Value` sorts object keys, so the hash
+/// does not depend on field or map ordering.
+pub fn config_hash(config: &Config) -> String {
+    let canonical = serde_json::to_value(config)
+        .map(|value| value.to_string())
+        .unwrap_or_default();
+    sha256_hex(canonical.as_bytes())
+}
+
+/// Lowercase hex SHA-256 of `bytes`
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
 /// File content cache for lazy parsing
 pub struct FileCache {
-    cache: Cache<std::path::PathBuf, String>,
+    cache: Cache<PathBuf, String>,
 }
 
 impl FileCache {
@@ -178,12 +372,12 @@ impl FileCache {
     }
 
     /// Get cached file content
-    pub fn get_content(&self, path: &std::path::Path) -> Option<String> {
+    pub fn get_content(&self, path: &Path) -> Option<String> {
         self.cache.get(&path.to_path_buf())
     }
 
     /// Cache file content
-    pub fn cache_content(&self, path: std::path::PathBuf, content: String) {
+    pub fn cache_content(&self, path: PathBuf, content: String) {
         self.cache.insert(path, content);
     }
 
@@ -194,6 +388,7 @@ impl FileCache {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
 
@@ -230,6 +425,55 @@ mod tests {
         assert_eq!(stats.hit_rate, 0.5);
     }
 
+    #[test]
+    fn test_persistent_cache_reuses_matching_hash_only() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let config = Config::default();
+        let file = temp.path().join("src/lib.rs");
+
+        let mut cache = PersistentValidationCache::load(temp.path(), &config);
+        cache.insert(
+            file.clone(),
+            CachedFileResult {
+                sha256: sha256_hex(b"fn a() {}"),
+                violations: vec![],
+                suppressions: vec![],
+            },
+        );
+        cache.save().unwrap();
+        assert!(temp.path().join(".ferrous-forge/cache/.gitignore").exists());
+
+        let reloaded = PersistentValidationCache::load(temp.path(), &config);
+        assert!(reloaded.get(&file, &sha256_hex(b"fn a() {}")).is_some());
+        assert!(reloaded.get(&file, &sha256_hex(b"fn b() {}")).is_none());
+        assert_eq!(reloaded.stats().hits, 1);
+        assert_eq!(reloaded.stats().misses, 1);
+    }
+
+    #[test]
+    fn test_persistent_cache_invalidated_by_config_change() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let file = temp.path().join("src/lib.rs");
+        let mut cache = PersistentValidationCache::load(temp.path(), &Config::default());
+        cache.insert(
+            file.clone(),
+            CachedFileResult {
+                sha256: "abc".to_string(),
+                violations: vec![],
+                suppressions: vec![],
+            },
+        );
+        cache.save().unwrap();
+
+        let changed = Config {
+            max_function_lines: 10,
+            ..Config::default()
+        };
+        let reloaded = PersistentValidationCache::load(temp.path(), &changed);
+        assert!(reloaded.get(&file, "abc").is_none());
+        assert_eq!(reloaded.stats().entries, 0);
+    }
+
     #[test]
     fn test_validation_cache() {
         let cache = ValidationCache::new(Duration::from_secs(60));
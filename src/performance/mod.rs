@@ -49,6 +49,8 @@ impl Default for PerformanceConfig {
 pub struct PerformanceMetrics {
     /// Total validation time in milliseconds
     pub validation_time_ms: u64,
+    /// Total validation time in microseconds, for sub-millisecond tasks
+    pub validation_time_us: u64,
     /// Memory usage in bytes
     pub memory_usage_bytes: u64,
     /// Number of files processed
@@ -105,6 +107,18 @@ impl PerformanceMonitor {
         TaskMonitor::new(task_name.to_string(), self.metrics.clone())
     }
 
+    /// Add `elapsed` and `files_processed` to a task's running totals
+    ///
+    /// Unlike [`TaskMonitor::complete`], repeated calls accumulate, so one
+    /// task name can aggregate many short measurements (for example one
+    /// check run on every file). Safe to call from worker threads.
+    pub fn record(&self, task_name: &str, elapsed: Duration, files_processed: usize) {
+        let mut entry = self.metrics.entry(task_name.to_string()).or_default();
+        entry.validation_time_us += elapsed.as_micros() as u64;
+        entry.validation_time_ms = entry.validation_time_us / 1000;
+        entry.files_processed += files_processed;
+    }
+
     /// Get metrics for a task
     pub fn get_metrics(&self, task_name: &str) -> Option<PerformanceMetrics> {
         self.metrics.get(task_name).map(|m| m.clone())
@@ -143,6 +157,7 @@ impl TaskMonitor {
         let elapsed = self.start_time.elapsed();
         let metrics = PerformanceMetrics {
             validation_time_ms: elapsed.as_millis() as u64,
+            validation_time_us: elapsed.as_micros() as u64,
             memory_usage_bytes: estimate_memory_usage(),
             files_processed,
             cache_hit_rate: 0.0,   // Will be updated by cache module
@@ -206,4 +221,21 @@ mod tests {
         let metrics = monitor.get_metrics("test_task");
         assert!(metrics.is_some());
     }
+
+    #[test]
+    fn test_performance_monitor_record_accumulates() {
+        let monitor = PerformanceMonitor::new(PerformanceConfig::default());
+
+        monitor.record("check:ast", Duration::from_micros(1500), 1);
+        monitor.record("check:ast", Duration::from_micros(700), 1);
+
+        let metrics = monitor.get_metrics("check:ast");
+        assert!(
+            metrics
+                .as_ref()
+                .is_some_and(|m| m.validation_time_us == 2200
+                    && m.validation_time_ms == 2
+                    && m.files_processed == 2)
+        );
+    }
 }
@@ -2,27 +2,61 @@
 
 use crate::Result;
 use crate::validation::Violation;
+use crate::validation::rust_validator::file_checks::validate_rust_source;
+use crate::validation::rust_validator::patterns::ValidationPatterns;
 use rayon::prelude::*;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tracing::{debug, info};
 
 /// Parallel validator for concurrent file processing
+///
+/// Owns a dedicated rayon pool so `--jobs` applies only to validation and
+/// several validators can coexist in one process.
 pub struct ParallelValidator {
-    /// Number of threads to use (0 = auto)
+    /// Number of threads requested (0 = auto)
     thread_count: usize,
+    pool: rayon::ThreadPool,
 }
 
 impl ParallelValidator {
-    /// Create a new parallel validator
-    pub fn new(thread_count: usize) -> Self {
-        if thread_count > 0 {
-            rayon::ThreadPoolBuilder::new()
-                .num_threads(thread_count)
-                .build_global()
-                .ok();
-        }
-        Self { thread_count }
+    /// Create a new parallel validator with `thread_count` workers
+    /// (0 = one per CPU)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the thread pool cannot be created.
+    pub fn new(thread_count: usize) -> Result<Self> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(thread_count)
+            .thread_name(|i| format!("ferrous-forge-validate-{}", i))
+            .build()
+            .map_err(|e| crate::Error::config(format!("Failed to create thread pool: {}", e)))?;
+        Ok(Self { thread_count, pool })
+    }
+
+    /// Number of worker threads actually in use
+    pub fn threads(&self) -> usize {
+        self.pool.current_num_threads()
+    }
+
+    /// Apply `check` to every file on the pool
+    ///
+    /// Results are returned in the order of `files`, regardless of which
+    /// worker finished first.
+    pub fn map_files<T, F>(&self, files: &[PathBuf], check: F) -> Vec<T>
+    where
+        F: Fn(&Path) -> T + Send + Sync,
+        T: Send,
+    {
+        debug!(
+            "Validating {} files on {} threads (requested: {})",
+            files.len(),
+            self.threads(),
+            self.thread_count
+        );
+        self.pool
+            .install(|| files.par_iter().map(|file| check(file)).collect())
     }
 
     /// Validate multiple files in parallel
@@ -35,28 +69,21 @@ impl ParallelValidator {
         info!(
             "Validating {} files in parallel (threads: {})",
             files.len(),
-            if self.thread_count == 0 {
-                rayon::current_num_threads()
-            } else {
-                self.thread_count
-            }
+            self.threads()
         );
 
         let start = std::time::Instant::now();
 
-        // Process files in parallel
-        let violations: Vec<Violation> = files
-            .into_par_iter()
-            .flat_map(|file| {
-                debug!("Validating file: {}", file.display());
-                match validate_single_file(&file) {
-                    Ok(violations) => violations,
-                    Err(e) => {
-                        tracing::error!("Error validating {}: {}", file.display(), e);
-                        vec![]
-                    }
+        let violations: Vec<Violation> = self
+            .map_files(&files, |file| match validate_single_file(file) {
+                Ok(violations) => violations,
+                Err(e) => {
+                    tracing::error!("Error validating {}: {}", file.display(), e);
+                    vec![]
                 }
             })
+            .into_iter()
+            .flatten()
             .collect();
 
         let elapsed = start.elapsed();
@@ -79,27 +106,22 @@ impl ParallelValidator {
     }
 }
 
-/// Validate a single file (used in parallel processing)
+/// Validate a single file with the default configuration
 fn validate_single_file(path: &Path) -> Result<Vec<Violation>> {
-    // Use sync validation for parallel processing
-    use std::fs;
-
-    let content = fs::read_to_string(path).map_err(crate::Error::Io)?;
+    let content = std::fs::read_to_string(path).map_err(crate::Error::Io)?;
+    let config = crate::config::Config::default();
+    let patterns = ValidationPatterns::new()?;
 
-    // Simple validation for now - check file size
     let mut violations = Vec::new();
-    let line_count = content.lines().count();
-
-    if line_count > 300 {
-        violations.push(Violation {
-            violation_type: crate::validation::ViolationType::FileTooLarge,
-            file: path.to_path_buf(),
-            line: line_count,
-            message: format!("File has {} lines, maximum allowed is 300", line_count),
-            severity: crate::validation::Severity::Error,
-        });
-    }
-
+    validate_rust_source(
+        path,
+        &content,
+        &mut violations,
+        &patterns,
+        config.max_file_lines,
+        config.max_function_lines,
+        None,
+    )?;
     Ok(violations)
 }
 
@@ -186,13 +208,25 @@ mod tests {
 
     #[test]
     fn test_parallel_validator_creation() {
-        let validator = ParallelValidator::new(4);
+        let validator = ParallelValidator::new(4).unwrap();
         assert_eq!(validator.thread_count, 4);
+        assert_eq!(validator.threads(), 4);
+    }
+
+    #[test]
+    fn test_map_files_preserves_order() {
+        let validator = ParallelValidator::new(4).unwrap();
+        let files: Vec<PathBuf> = (0..64).map(|i| PathBuf::from(format!("{i}.rs"))).collect();
+
+        let names = validator.map_files(&files, |f| f.display().to_string());
+
+        let expected: Vec<String> = (0..64).map(|i| format!("{i}.rs")).collect();
+        assert_eq!(names, expected);
     }
 
     #[test]
     fn test_speedup_calculation() {
-        let validator = ParallelValidator::new(0);
+        let validator = ParallelValidator::new(0).unwrap();
         let speedup = validator.calculate_speedup(10.0, 2.5);
         assert_eq!(speedup, 4.0);
     }
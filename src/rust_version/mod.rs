@@ -57,7 +57,7 @@ impl std::fmt::Display for Channel {
 }
 
 /// Update information for available version
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct UpdateInfo {
     /// Current Rust version
     pub current: Version,
@@ -70,7 +70,8 @@ pub struct UpdateInfo {
 }
 
 /// Version update recommendation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", content = "info", rename_all = "snake_case")]
 pub enum UpdateRecommendation {
     /// Already on latest version
     UpToDate,
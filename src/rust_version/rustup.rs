@@ -74,7 +74,7 @@ pub struct ToolchainInfo {
 }
 
 /// Version requirements from locked config
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VersionRequirements {
     /// Minimum required version (inclusive)
     pub minimum: Option<Version>,
@@ -478,7 +478,7 @@ pub struct UpdateResult {
 }
 
 /// Result of a version check
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VersionCheckResult {
     /// Current installed version
     pub current: Version,
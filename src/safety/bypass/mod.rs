@@ -0,0 +1,463 @@
+//! Emergency bypass system for safety pipeline
+//!
+//! Bypasses are scoped to a repository, optionally narrowed to a branch or
+//! a single HEAD commit, so bypassing a stage in one project never skips
+//! checks anywhere else. All bypasses live in one list in the config
+//! directory and are matched against where a check runs. Every creation,
+//! use and revocation goes to a hash-chained audit log.
+
+mod scope;
+
+pub use scope::BypassScope;
+
+use crate::audit_chain::AuditChain;
+use crate::{Error, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+use tokio::fs;
+
+use super::{PipelineStage, config::BypassConfig};
+
+/// Bypass manager for emergency situations
+pub struct BypassManager {
+    config: BypassConfig,
+}
+
+/// Active bypass record
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveBypass {
+    /// Identifier used to revoke the bypass
+    pub id: String,
+    /// Stage being bypassed
+    pub stage: PipelineStage,
+    /// Reason for bypass
+    pub reason: String,
+    /// User who created the bypass
+    pub user: String,
+    /// When the bypass was created
+    pub created_at: DateTime<Utc>,
+    /// When the bypass expires
+    pub expires_at: DateTime<Utc>,
+    /// Where the bypass applies
+    pub scope: BypassScope,
+    /// Whether the first check it skips uses the bypass up
+    #[serde(default)]
+    pub one_shot: bool,
+}
+
+/// What happened to a bypass in an audit log entry
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BypassAction {
+    /// The bypass was requested
+    #[default]
+    Created,
+    /// A one-shot bypass skipped a check and was used up
+    Used,
+    /// The bypass was revoked before it expired
+    Revoked,
+}
+
+impl BypassAction {
+    /// Short name shown in the audit log
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Used => "used",
+            Self::Revoked => "revoked",
+        }
+    }
+}
+
+/// Bypass audit log entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BypassLogEntry {
+    /// Stage that was bypassed
+    pub stage: PipelineStage,
+    /// Reason for bypass
+    pub reason: String,
+    /// User who bypassed
+    pub user: String,
+    /// Timestamp of bypass
+    pub timestamp: DateTime<Utc>,
+    /// Whether bypass was successful
+    pub successful: bool,
+    /// What happened to the bypass
+    #[serde(default)]
+    pub action: BypassAction,
+    /// Where the bypass applies; absent in entries from before bypasses
+    /// were scoped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BypassScope>,
+}
+
+impl BypassManager {
+    /// Create a new bypass manager
+    ///
+    /// # Errors
+    ///
+    /// This function is infallible but returns `Result` for future extensibility.
+    pub fn new(config: &BypassConfig) -> Result<Self> {
+        Ok(Self {
+            config: config.clone(),
+        })
+    }
+
+    /// Check if bypass is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    /// Create a temporary bypass of `stage` within `scope`
+    ///
+    /// A bypass replaces any earlier one for the same stage and scope.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bypass system is disabled, the reason is empty
+    /// when required, the daily bypass limit is reached, or the bypass cannot
+    /// be saved to disk.
+    pub async fn create_bypass(
+        &self,
+        stage: PipelineStage,
+        reason: String,
+        user: String,
+        duration_hours: u64,
+        scope: BypassScope,
+        one_shot: bool,
+    ) -> Result<ActiveBypass> {
+        if !self.config.enabled {
+            return Err(Error::safety("Bypass system is disabled"));
+        }
+
+        if self.config.require_reason && reason.trim().is_empty() {
+            return Err(Error::safety("Bypass reason is required"));
+        }
+
+        // Check daily bypass limit
+        if self.config.max_bypasses_per_day > 0 {
+            let today_count = self.count_bypasses_today(&user).await?;
+            if today_count >= self.config.max_bypasses_per_day {
+                return Err(Error::safety(format!(
+                    "Daily bypass limit reached ({}/{})",
+                    today_count, self.config.max_bypasses_per_day
+                )));
+            }
+        }
+
+        let created_at = Utc::now();
+        let bypass = ActiveBypass {
+            id: bypass_id(stage, &scope, created_at),
+            stage,
+            reason,
+            user,
+            created_at,
+            expires_at: created_at + chrono::Duration::hours(duration_hours as i64),
+            scope,
+            one_shot,
+        };
+
+        let mut bypasses = self.load_active_bypasses().await?;
+        bypasses.retain(|b| !(b.stage == stage && b.scope == bypass.scope));
+        bypasses.push(bypass.clone());
+        self.save_active_bypasses(&bypasses).await?;
+
+        // Log the bypass
+        if self.config.log_bypasses {
+            self.log_bypass(&bypass, BypassAction::Created, &bypass.user)
+                .await?;
+        }
+
+        Ok(bypass)
+    }
+
+    /// Find a bypass of `stage` that applies at `location`
+    ///
+    /// Expired bypasses are dropped, and a matching one-shot bypass is used
+    /// up by this call. Reusable bypasses are preferred over one-shot ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bypass file cannot be read, parsed or
+    /// updated.
+    pub async fn check_active_bypass(
+        &self,
+        stage: PipelineStage,
+        location: &BypassScope,
+    ) -> Result<Option<ActiveBypass>> {
+        if !self.config.enabled {
+            return Ok(None);
+        }
+
+        let stored = self.load_active_bypasses().await?;
+        let stored_count = stored.len();
+        let mut bypasses: Vec<ActiveBypass> = stored
+            .into_iter()
+            .filter(|b| b.expires_at > Utc::now())
+            .collect();
+
+        let found = bypasses
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| b.stage == stage && b.scope.covers(location))
+            .min_by_key(|(_, b)| b.one_shot)
+            .map(|(i, b)| (i, b.clone()));
+
+        let used = match &found {
+            Some((index, bypass)) if bypass.one_shot => {
+                bypasses.remove(*index);
+                true
+            }
+            _ => false,
+        };
+        if used || bypasses.len() != stored_count {
+            self.save_active_bypasses(&bypasses).await?;
+        }
+        if let Some((_, bypass)) = &found
+            && used
+            && self.config.log_bypasses
+        {
+            self.log_bypass(bypass, BypassAction::Used, &bypass.user)
+                .await?;
+        }
+
+        Ok(found.map(|(_, bypass)| bypass))
+    }
+
+    /// Bypasses that have not expired, oldest first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bypass file cannot be read or parsed.
+    pub async fn list_bypasses(&self) -> Result<Vec<ActiveBypass>> {
+        let mut bypasses = self.load_active_bypasses().await?;
+        bypasses.retain(|b| b.expires_at > Utc::now());
+        bypasses.sort_by_key(|b| b.created_at);
+        Ok(bypasses)
+    }
+
+    /// Revoke the bypass with `id` on behalf of `user`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no bypass has that id, or the bypass file cannot
+    /// be read or updated.
+    pub async fn revoke_bypass(&self, id: &str, user: &str) -> Result<ActiveBypass> {
+        let mut bypasses = self.load_active_bypasses().await?;
+        let index = bypasses
+            .iter()
+            .position(|b| b.id == id)
+            .ok_or_else(|| Error::safety(format!("No active bypass with id '{}'", id)))?;
+        let bypass = bypasses.remove(index);
+        self.save_active_bypasses(&bypasses).await?;
+
+        if self.config.log_bypasses {
+            self.log_bypass(&bypass, BypassAction::Revoked, user)
+                .await?;
+        }
+        Ok(bypass)
+    }
+
+    /// Get bypass audit log
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the audit log path cannot be resolved or the
+    /// log file cannot be read.
+    pub async fn get_audit_log(&self, limit: usize) -> Result<Vec<BypassLogEntry>> {
+        let log_path = self.get_audit_log_path()?;
+
+        if !log_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let contents = fs::read_to_string(&log_path).await?;
+        let mut entries: Vec<BypassLogEntry> = contents
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+
+        // Sort by timestamp (newest first) and limit
+        entries.sort_by_key(|b| std::cmp::Reverse(b.timestamp));
+        entries.truncate(limit);
+
+        Ok(entries)
+    }
+
+    /// Count bypasses created by a user today
+    async fn count_bypasses_today(&self, user: &str) -> Result<u32> {
+        let today = Utc::now().date_naive();
+        let log = self.get_audit_log(100).await?; // Check last 100 entries
+
+        let count = log
+            .iter()
+            .filter(|entry| {
+                entry.action == BypassAction::Created
+                    && entry.user == user
+                    && entry.timestamp.date_naive() == today
+            })
+            .count() as u32;
+
+        Ok(count)
+    }
+
+    /// Read every stored bypass, expired or not
+    async fn load_active_bypasses(&self) -> Result<Vec<ActiveBypass>> {
+        let bypass_path = self.get_active_bypass_path()?;
+        if !bypass_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let contents = fs::read_to_string(&bypass_path).await?;
+        serde_json::from_str(&contents)
+            .map_err(|e| Error::parse(format!("Failed to parse bypasses: {}", e)))
+    }
+
+    /// Replace the stored bypasses
+    async fn save_active_bypasses(&self, bypasses: &[ActiveBypass]) -> Result<()> {
+        let bypass_path = self.get_active_bypass_path()?;
+
+        if let Some(parent) = bypass_path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+
+        let contents = serde_json::to_string_pretty(bypasses)
+            .map_err(|e| Error::parse(format!("Failed to serialize bypasses: {}", e)))?;
+
+        fs::write(&bypass_path, contents).await?;
+
+        Ok(())
+    }
+
+    /// Log what happened to a bypass to the audit trail
+    async fn log_bypass(
+        &self,
+        bypass: &ActiveBypass,
+        action: BypassAction,
+        user: &str,
+    ) -> Result<()> {
+        let entry = BypassLogEntry {
+            stage: bypass.stage,
+            reason: bypass.reason.clone(),
+            user: user.to_string(),
+            timestamp: match action {
+                BypassAction::Created => bypass.created_at,
+                BypassAction::Used | BypassAction::Revoked => Utc::now(),
+            },
+            successful: true,
+            action,
+            scope: Some(bypass.scope.clone()),
+        };
+
+        self.audit_chain()?.append(&entry).await
+    }
+
+    /// Get path for the list of active bypasses
+    fn get_active_bypass_path(&self) -> Result<PathBuf> {
+        let config_dir = crate::config::Config::config_dir_path()?;
+        Ok(config_dir.join("safety-bypasses").join("active.json"))
+    }
+
+    /// Get path for audit log
+    fn get_audit_log_path(&self) -> Result<PathBuf> {
+        let config_dir = crate::config::Config::config_dir_path()?;
+        Ok(config_dir.join("safety-bypasses").join("audit.log"))
+    }
+
+    /// The hash-chained bypass audit log
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration directory cannot be resolved.
+    pub fn audit_chain(&self) -> Result<AuditChain> {
+        Ok(AuditChain::new(self.get_audit_log_path()?))
+    }
+}
+
+/// Short identifier for a new bypass
+fn bypass_id(stage: PipelineStage, scope: &BypassScope, created_at: DateTime<Utc>) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(stage.name());
+    hasher.update(scope.to_string());
+    hasher.update(created_at.to_rfc3339());
+    format!("{:x}", hasher.finalize())[..8].to_string()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bypass_manager_creation() {
+        let config = BypassConfig {
+            enabled: true,
+            require_reason: true,
+            require_confirmation: true,
+            log_bypasses: true,
+            max_bypasses_per_day: 3,
+        };
+
+        let manager = BypassManager::new(&config).unwrap();
+        assert!(manager.is_enabled());
+    }
+
+    #[tokio::test]
+    async fn test_bypass_creation() {
+        let config = BypassConfig {
+            enabled: true,
+            require_reason: true,
+            require_confirmation: false,
+            log_bypasses: false,
+            max_bypasses_per_day: 0, // No limit
+        };
+
+        let manager = BypassManager::new(&config).unwrap();
+        let scope = BypassScope {
+            repo_root: PathBuf::from("/nonexistent/ferrous-forge-bypass-test"),
+            branch: None,
+            commit: None,
+        };
+
+        let bypass = manager
+            .create_bypass(
+                PipelineStage::PreCommit,
+                "test reason".to_string(),
+                "test_user".to_string(),
+                1, // 1 hour
+                scope.clone(),
+                true,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(bypass.stage, PipelineStage::PreCommit);
+        assert_eq!(bypass.reason, "test reason");
+        assert_eq!(bypass.user, "test_user");
+
+        // Another repository is not bypassed
+        let elsewhere = BypassScope {
+            repo_root: PathBuf::from("/nonexistent/other-repo"),
+            ..scope.clone()
+        };
+        let found = manager
+            .check_active_bypass(PipelineStage::PreCommit, &elsewhere)
+            .await
+            .unwrap();
+        assert!(found.is_none());
+
+        // A one-shot bypass skips one check
+        let found = manager
+            .check_active_bypass(PipelineStage::PreCommit, &scope)
+            .await
+            .unwrap();
+        assert_eq!(found.map(|b| b.id), Some(bypass.id));
+        let found = manager
+            .check_active_bypass(PipelineStage::PreCommit, &scope)
+            .await
+            .unwrap();
+        assert!(found.is_none());
+    }
+}
@@ -0,0 +1,135 @@
+//! Where a bypass applies: a repository, optionally narrowed to a branch
+//! or a single HEAD commit
+
+use crate::Result;
+use crate::validation::changed_files::git;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Repository, branch and commit a bypass is limited to
+///
+/// The same type describes where a check runs, with `branch` and `commit`
+/// set from the current checkout.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BypassScope {
+    /// Root of the git repository, or the project directory outside git
+    pub repo_root: PathBuf,
+    /// Branch the bypass is limited to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// HEAD commit the bypass is limited to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+}
+
+impl BypassScope {
+    /// Describe where `path` is: its repository root, current branch and
+    /// HEAD commit
+    ///
+    /// Outside a git repository the canonical `path` is the root and there
+    /// is no branch or commit. A detached HEAD has no branch, and a
+    /// repository without commits has no commit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` does not exist.
+    pub async fn locate(path: &Path) -> Result<Self> {
+        let path = path.canonicalize()?;
+        let Ok(root) = git(&path, &["rev-parse", "--show-toplevel"]).await else {
+            return Ok(Self {
+                repo_root: path,
+                branch: None,
+                commit: None,
+            });
+        };
+
+        let branch = git(&path, &["symbolic-ref", "--short", "-q", "HEAD"])
+            .await
+            .ok()
+            .map(|b| b.trim().to_string())
+            .filter(|b| !b.is_empty());
+        let commit = git(&path, &["rev-parse", "HEAD"])
+            .await
+            .ok()
+            .map(|c| c.trim().to_string());
+        Ok(Self {
+            repo_root: PathBuf::from(root.trim()),
+            branch,
+            commit,
+        })
+    }
+
+    /// Limit a location to its repository, plus its branch and commit when
+    /// asked for
+    pub fn narrowed(self, branch: bool, commit: bool) -> Self {
+        Self {
+            repo_root: self.repo_root,
+            branch: self.branch.filter(|_| branch),
+            commit: self.commit.filter(|_| commit),
+        }
+    }
+
+    /// Whether a bypass with this scope applies at `location`
+    pub fn covers(&self, location: &BypassScope) -> bool {
+        self.repo_root == location.repo_root
+            && self
+                .branch
+                .as_ref()
+                .is_none_or(|b| location.branch.as_ref() == Some(b))
+            && self
+                .commit
+                .as_ref()
+                .is_none_or(|c| location.commit.as_ref() == Some(c))
+    }
+}
+
+impl fmt::Display for BypassScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.repo_root.display())?;
+        if let Some(branch) = &self.branch {
+            write!(f, " on {}", branch)?;
+        }
+        if let Some(commit) = &self.commit {
+            write!(f, " at {}", commit.get(..12).unwrap_or(commit))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scope(root: &str, branch: Option<&str>, commit: Option<&str>) -> BypassScope {
+        BypassScope {
+            repo_root: PathBuf::from(root),
+            branch: branch.map(String::from),
+            commit: commit.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_scope_covers_only_its_repository() {
+        let here = scope("/repo/a", Some("main"), Some("abc"));
+        assert!(scope("/repo/a", None, None).covers(&here));
+        assert!(!scope("/repo/b", None, None).covers(&here));
+    }
+
+    #[test]
+    fn test_scope_narrowed_to_branch_and_commit() {
+        let here = scope("/repo/a", Some("main"), Some("abc"));
+        assert!(scope("/repo/a", Some("main"), None).covers(&here));
+        assert!(!scope("/repo/a", Some("dev"), None).covers(&here));
+        assert!(!scope("/repo/a", None, Some("def")).covers(&here));
+
+        let detached = scope("/repo/a", None, Some("abc"));
+        assert!(!scope("/repo/a", Some("main"), None).covers(&detached));
+    }
+
+    #[test]
+    fn test_scope_display() {
+        let pinned = scope("/repo/a", Some("main"), Some("0123456789abcdef"));
+        assert_eq!(pinned.to_string(), "/repo/a on main at 0123456789ab");
+    }
+}
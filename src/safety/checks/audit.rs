@@ -56,10 +56,9 @@ pub async fn run(project_path: &Path) -> Result<CheckResult> {
 
 /// Check if cargo-audit is available on the system
 async fn check_audit_availability() -> std::result::Result<(), String> {
-    let audit_check = tokio::process::Command::new("cargo")
-        .args(&["audit", "--version"])
-        .output()
-        .await;
+    let audit_check =
+        super::command::output(tokio::process::Command::new("cargo").args(&["audit", "--version"]))
+            .await;
 
     if audit_check
         .as_ref()
@@ -73,12 +72,13 @@ async fn check_audit_availability() -> std::result::Result<(), String> {
 
 /// Execute cargo audit command
 async fn execute_audit(project_path: &Path) -> Result<std::process::Output> {
-    tokio::process::Command::new("cargo")
-        .current_dir(project_path)
-        .args(&["audit"])
-        .output()
-        .await
-        .map_err(Into::into)
+    super::command::output(
+        tokio::process::Command::new("cargo")
+            .current_dir(project_path)
+            .args(&["audit"]),
+    )
+    .await
+    .map_err(Into::into)
 }
 
 /// Process audit command failures and parse vulnerabilities
@@ -4,8 +4,10 @@ use crate::Result;
 use std::path::Path;
 use std::time::Instant;
 
-use super::SafetyCheck;
+use super::{SafetyCheck, add_diagnostics};
 use crate::safety::{CheckType, report::CheckResult};
+use crate::validation::CompilerDiagnostic;
+use crate::validation::diagnostics::parse_cargo_messages;
 
 /// Build check implementation
 pub struct BuildCheck;
@@ -35,70 +37,51 @@ pub async fn run(project_path: &Path) -> Result<CheckResult> {
 
     // Run cargo build --release
     // Disable the Ferrous Forge cargo wrapper to avoid recursive validation
-    let output = tokio::process::Command::new("cargo")
-        .current_dir(project_path)
-        .env("FERROUS_FORGE_ENABLED", "0")
-        .args(["build", "--release"])
-        .output()
-        .await?;
+    let output = super::command::output(
+        tokio::process::Command::new("cargo")
+            .current_dir(project_path)
+            .env("FERROUS_FORGE_ENABLED", "0")
+            .args(["build", "--release", "--message-format=json"]),
+    )
+    .await?;
 
     result.set_duration(start.elapsed());
 
+    let diagnostics = parse_cargo_messages(&String::from_utf8_lossy(&output.stdout));
     if !output.status.success() {
-        handle_build_failure(&mut result, &output);
+        handle_build_failure(&mut result, &output, &diagnostics);
     } else {
-        handle_build_success(&mut result, &output);
+        handle_build_success(&mut result, &diagnostics);
     }
 
     Ok(result)
 }
 
 /// Handle build failure output
-fn handle_build_failure(result: &mut CheckResult, output: &std::process::Output) {
+fn handle_build_failure(
+    result: &mut CheckResult,
+    output: &std::process::Output,
+    diagnostics: &[CompilerDiagnostic],
+) {
     result.add_error("Build failed");
     result.add_suggestion("Fix compilation errors before proceeding");
 
-    let error_count = parse_build_errors(result, &output.stderr);
-
-    if error_count >= 3 {
-        result.add_error("... and more build errors (showing first 3)");
-    }
-
-    result.add_suggestion("Run 'cargo build' to see detailed error messages");
-    result.add_suggestion("Check for missing dependencies or syntax errors");
-}
-
-/// Parse build errors from stderr
-fn parse_build_errors(result: &mut CheckResult, stderr: &[u8]) -> usize {
-    let stderr = String::from_utf8_lossy(stderr);
-    let mut error_count = 0;
-
-    for line in stderr.lines() {
-        if line.starts_with("error") && error_count < 3 {
+    if add_diagnostics(result, "Build", diagnostics) == 0 {
+        // Cargo failed before rustc reported anything, e.g. on a bad manifest
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        for line in stderr.lines().filter(|l| l.starts_with("error")).take(3) {
             result.add_error(format!("Build: {}", line.trim()));
-            error_count += 1;
-        } else if line.trim().starts_with("-->") && error_count <= 3 {
-            result.add_context(format!("Location: {}", line.trim()));
         }
     }
 
-    error_count
+    result.add_suggestion("Run 'cargo build' to see detailed error messages");
+    result.add_suggestion("Check for missing dependencies or syntax errors");
 }
 
 /// Handle successful build output
-fn handle_build_success(result: &mut CheckResult, output: &std::process::Output) {
+fn handle_build_success(result: &mut CheckResult, diagnostics: &[CompilerDiagnostic]) {
     result.add_context("Project builds successfully in release mode");
-
-    // Check for warnings
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    let warning_count = stderr
-        .lines()
-        .filter(|line| line.starts_with("warning:"))
-        .count();
-
-    if warning_count > 0 {
-        result.add_context(format!("Build completed with {} warnings", warning_count));
-    }
+    add_diagnostics(result, "Build", diagnostics);
 }
 
 #[cfg(test)]
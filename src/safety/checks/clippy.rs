@@ -4,8 +4,10 @@ use crate::Result;
 use std::path::Path;
 use std::time::Instant;
 
-use super::SafetyCheck;
+use super::{SafetyCheck, add_diagnostics};
 use crate::safety::{CheckType, report::CheckResult};
+use crate::validation::CompilerDiagnostic;
+use crate::validation::diagnostics::parse_cargo_messages;
 
 /// Clippy check implementation
 pub struct ClippyCheck;
@@ -43,9 +45,11 @@ pub async fn run(project_path: &Path) -> Result<CheckResult> {
     let output = run_clippy_command(project_path).await?;
     result.set_duration(start.elapsed());
 
+    let diagnostics = parse_cargo_messages(&String::from_utf8_lossy(&output.stdout));
     if !output.status.success() {
-        handle_clippy_errors(&output, &mut result);
+        handle_clippy_errors(&output, &diagnostics, &mut result);
     } else {
+        add_diagnostics(&mut result, "Clippy", &diagnostics);
         result.add_context("All clippy lints passed");
     }
 
@@ -54,73 +58,51 @@ pub async fn run(project_path: &Path) -> Result<CheckResult> {
 
 /// Check if clippy is available
 async fn is_clippy_available() -> bool {
-    tokio::process::Command::new("cargo")
-        .args(&["clippy", "--version"])
-        .output()
+    super::command::output(tokio::process::Command::new("cargo").args(&["clippy", "--version"]))
         .await
         .is_ok_and(|output| output.status.success())
 }
 
 /// Run clippy command with strict settings
 async fn run_clippy_command(project_path: &Path) -> Result<std::process::Output> {
-    let output = tokio::process::Command::new("cargo")
-        .current_dir(project_path)
-        .args(&[
-            "clippy",
-            "--all-targets",
-            "--all-features",
-            "--",
-            "-D",
-            "warnings",
-        ])
-        .output()
-        .await?;
+    let output = super::command::output(
+        tokio::process::Command::new("cargo")
+            .current_dir(project_path)
+            .args(&[
+                "clippy",
+                "--all-targets",
+                "--all-features",
+                "--message-format=json",
+                "--",
+                "-D",
+                "warnings",
+            ]),
+    )
+    .await?;
 
     Ok(output)
 }
 
-/// Handle clippy errors and parse output
-fn handle_clippy_errors(output: &std::process::Output, result: &mut CheckResult) {
-    result.add_error("Clippy lints found");
+/// Handle clippy errors from the parsed diagnostics
+fn handle_clippy_errors(
+    output: &std::process::Output,
+    diagnostics: &[CompilerDiagnostic],
+    result: &mut CheckResult,
+) {
+    if add_diagnostics(result, "Clippy", diagnostics) == 0 {
+        // Clippy failed before reporting a lint, e.g. on a broken manifest
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        match stderr.lines().find(|line| line.starts_with("error")) {
+            Some(line) => result.add_error(format!("Clippy: {}", line.trim())),
+            None => result.add_error("Clippy failed without reporting a diagnostic"),
+        }
+    }
     result.add_suggestion("Fix clippy warnings before proceeding");
 
-    parse_clippy_output(&String::from_utf8_lossy(&output.stderr), result);
-
     // Add general suggestions
-    result.add_suggestion("Run 'cargo clippy --fix' to auto-fix some issues");
     result.add_suggestion("Check https://rust-lang.github.io/rust-clippy/ for lint explanations");
 }
 
-/// Parse clippy output for specific issues
-fn parse_clippy_output(stderr: &str, result: &mut CheckResult) {
-    let mut error_count = 0;
-    let mut in_error = false;
-
-    for line in stderr.lines() {
-        if (line.starts_with("error:") || line.starts_with("warning:")) && error_count < 5 {
-            result.add_error(format!("Clippy: {}", line.trim()));
-            error_count += 1;
-            in_error = true;
-        } else if in_error && line.trim().starts_with("-->") {
-            // Add file location context
-            result.add_context(format!("Location: {}", line.trim()));
-            in_error = false;
-        } else if line.contains("help:") && !line.trim().is_empty() {
-            result.add_suggestion(
-                line.trim()
-                    .strip_prefix("help: ")
-                    .unwrap_or(line.trim())
-                    .to_string(),
-            );
-        }
-    }
-
-    if error_count >= 5 {
-        result.add_error("... and more clippy issues (showing first 5)");
-        result.add_suggestion("Fix the issues above first, then run again");
-    }
-}
-
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
@@ -0,0 +1,140 @@
+//! Running check commands so a timeout stops everything they started
+//!
+//! Cargo starts rustc, build scripts and test binaries as its own children.
+//! Killing only the `cargo` process on a timeout would leave those running
+//! after the hook has reported the timeout, so on Unix every check command
+//! runs in its own process group and the whole group is killed when the
+//! check is abandoned.
+
+use std::io;
+use std::path::PathBuf;
+use std::process::{Output, Stdio};
+use tokio::process::Command;
+
+tokio::task_local! {
+    /// `CARGO_TARGET_DIR` for the commands run by [`with_cargo_target_dir`]
+    static CARGO_TARGET_DIR: PathBuf;
+}
+
+/// Run `future` with every check command in it building into `target_dir`
+///
+/// Checks of a staged snapshot use this to keep their build output out of
+/// the project's own `target` directory.
+pub async fn with_cargo_target_dir<F: Future>(target_dir: PathBuf, future: F) -> F::Output {
+    CARGO_TARGET_DIR.scope(target_dir, future).await
+}
+
+/// Run `command` to completion and collect its output
+///
+/// If the returned future is dropped before the command finishes, e.g.
+/// because the stage deadline passed, the command and every process it
+/// started are killed. Inside [`with_cargo_target_dir`] the command gets
+/// that `CARGO_TARGET_DIR`.
+///
+/// # Errors
+///
+/// Returns an error if the command cannot be started or waited for.
+pub async fn output(command: &mut Command) -> io::Result<Output> {
+    if let Ok(target_dir) = CARGO_TARGET_DIR.try_with(PathBuf::clone) {
+        command.env("CARGO_TARGET_DIR", target_dir);
+    }
+    #[cfg(unix)]
+    command.process_group(0);
+    let child = command
+        .kill_on_drop(true)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let mut group = ProcessGroup(child.id());
+    let output = child.wait_with_output().await;
+    // Finished normally: leave anything it left behind alone
+    group.0 = None;
+    output
+}
+
+/// Kills the process group led by the given process when dropped
+struct ProcessGroup(Option<u32>);
+
+impl Drop for ProcessGroup {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        if let Some(pid) = self
+            .0
+            .and_then(|id| i32::try_from(id).ok())
+            .and_then(rustix::process::Pid::from_raw)
+        {
+            match rustix::process::kill_process_group(pid, rustix::process::Signal::KILL) {
+                // The group is already gone; there is nothing left to stop
+                Ok(()) | Err(rustix::io::Errno::SRCH) => {}
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to kill process group {}: {}",
+                        pid.as_raw_nonzero(),
+                        e
+                    )
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_output_collects_stdout() {
+        let output = output(Command::new("sh").args(["-c", "echo hi"]))
+            .await
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"hi\n");
+    }
+
+    /// Processes in group `pgid` that are still running (not zombies)
+    #[cfg(target_os = "linux")]
+    fn running_in_group(pgid: &str) -> usize {
+        std::fs::read_dir("/proc")
+            .unwrap()
+            .filter_map(|entry| std::fs::read_to_string(entry.ok()?.path().join("stat")).ok())
+            .filter(|stat| {
+                // `pid (comm) state ppid pgrp ...`; comm may contain spaces
+                let fields: Vec<&str> = stat
+                    .rsplit_once(')')
+                    .map_or(Vec::new(), |(_, rest)| rest.split_whitespace().collect());
+                fields.len() > 2 && fields[0] != "Z" && fields[2] == pgid
+            })
+            .count()
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_abandoned_command_kills_its_process_group() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut command = Command::new("sh");
+        command
+            .args(["-c", "echo $$ > group.pid; sleep 30 & sleep 30"])
+            .current_dir(dir.path());
+
+        let timed_out =
+            tokio::time::timeout(std::time::Duration::from_millis(500), output(&mut command)).await;
+        assert!(timed_out.is_err());
+
+        let pgid = std::fs::read_to_string(dir.path().join("group.pid")).unwrap();
+        let pgid = pgid.trim();
+        for _ in 0..50 {
+            if running_in_group(pgid) == 0 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        assert_eq!(
+            running_in_group(pgid),
+            0,
+            "the command's processes are still running"
+        );
+    }
+}
@@ -0,0 +1,292 @@
+//! User-defined checks from the `custom_checks` table of the safety config
+//!
+//! A custom check runs an arbitrary command, such as `cargo deny check` or a
+//! project script, in the project directory. The exit code decides whether
+//! it passed. Commands that print JSON lines can also report individual
+//! errors and warnings:
+//!
+//! ```text
+//! {"level":"error","message":"schema drift","file":"db/schema.sql","line":12}
+//! ```
+
+use crate::Result;
+use crate::safety::CheckType;
+use crate::safety::config::{CustomCheckConfig, CustomOutputFormat};
+use crate::safety::report::{CheckResult, CheckStatus};
+use serde::Deserialize;
+use std::path::Path;
+use std::process::Output;
+use std::time::{Duration, Instant};
+
+/// Number of output lines kept as context for a failed plain check
+const PLAIN_OUTPUT_LINES: usize = 10;
+
+/// Run the custom check `name`
+///
+/// `spec` is the check's entry in `custom_checks`; a check missing from the
+/// table fails. Failing to start the command is reported as a failed check
+/// rather than an error, so one broken check does not abort the stage.
+///
+/// # Errors
+///
+/// This function reports every failure on the returned [`CheckResult`] and
+/// does not itself return errors.
+pub async fn run(
+    name: &str,
+    spec: Option<&CustomCheckConfig>,
+    project_path: &Path,
+) -> Result<CheckResult> {
+    let start = Instant::now();
+    let mut result = CheckResult::new(CheckType::Custom(name.to_string()));
+
+    let Some(spec) = spec else {
+        result.add_error(format!(
+            "Custom check '{}' is not defined in [custom_checks]",
+            name
+        ));
+        result.add_suggestion(
+            "Define it under [custom_checks] in the safety config or remove it from the stage",
+        );
+        return Ok(result);
+    };
+
+    let mut command = tokio::process::Command::new(&spec.command);
+    command
+        .args(&spec.args)
+        .envs(&spec.env)
+        .current_dir(match &spec.working_dir {
+            Some(dir) => project_path.join(dir),
+            None => project_path.to_path_buf(),
+        });
+
+    let running = super::command::output(&mut command);
+    let output = match spec.timeout_seconds.map(Duration::from_secs) {
+        Some(limit) => match tokio::time::timeout(limit, running).await {
+            Ok(output) => output,
+            Err(_) => {
+                result.status = CheckStatus::TimedOut;
+                result.add_error(format!(
+                    "Timed out: check timeout of {}s exceeded",
+                    limit.as_secs()
+                ));
+                result.add_suggestion(format!(
+                    "Raise custom_checks.{}.timeout_seconds in the safety config",
+                    name
+                ));
+                result.set_duration(start.elapsed());
+                return Ok(result);
+            }
+        },
+        None => running.await,
+    };
+    result.set_duration(start.elapsed());
+
+    match output {
+        Ok(output) => record_output(&mut result, name, spec, &output),
+        Err(e) => {
+            result.add_error(format!("Failed to run '{}': {}", spec.command, e));
+            result.add_suggestion(format!(
+                "Check that '{}' is installed and on PATH",
+                spec.command
+            ));
+        }
+    }
+
+    Ok(result)
+}
+
+/// Record a finished command's outcome on `result`
+fn record_output(result: &mut CheckResult, name: &str, spec: &CustomCheckConfig, output: &Output) {
+    let succeeded = output
+        .status
+        .code()
+        .is_some_and(|code| spec.success_exit_codes.contains(&code));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    let reported_errors = match spec.output {
+        CustomOutputFormat::Plain => 0,
+        CustomOutputFormat::JsonLines => add_json_lines(result, name, &stdout),
+    };
+
+    if succeeded {
+        if reported_errors == 0 {
+            result.add_context(format!("{} passed", name));
+        }
+        return;
+    }
+
+    if reported_errors == 0 {
+        let code = output
+            .status
+            .code()
+            .map_or_else(|| "a signal".to_string(), |code| format!("code {}", code));
+        result.add_error(format!("{} exited with {}", name, code));
+    }
+    if spec.output == CustomOutputFormat::Plain {
+        let lines: Vec<&str> = stdout
+            .lines()
+            .chain(stderr.lines())
+            .filter(|l| !l.trim().is_empty())
+            .collect();
+        let skip = lines.len().saturating_sub(PLAIN_OUTPUT_LINES);
+        for line in &lines[skip..] {
+            result.add_context(format!("{}: {}", name, line.trim_end()));
+        }
+    }
+    result.add_suggestion(format!(
+        "Run '{}' to see the full output",
+        spec.command_line()
+    ));
+}
+
+/// Record JSON-lines messages on `result`, returning the number of errors
+fn add_json_lines(result: &mut CheckResult, name: &str, stdout: &str) -> usize {
+    let mut errors = 0;
+    for line in stdout.lines().filter(|l| l.trim_start().starts_with('{')) {
+        let Ok(message) = serde_json::from_str::<CustomMessage>(line) else {
+            continue;
+        };
+        let text = format!("{}: {}", name, message.summary());
+        match message.level.as_str() {
+            "error" => {
+                result.add_error(text);
+                errors += 1;
+            }
+            "warning" | "warn" => result.add_warning(text),
+            _ => result.add_context(text),
+        }
+    }
+    errors
+}
+
+/// One line of `json_lines` output
+#[derive(Deserialize)]
+struct CustomMessage {
+    #[serde(default = "default_level")]
+    level: String,
+    message: String,
+    file: Option<String>,
+    line: Option<usize>,
+    column: Option<usize>,
+}
+
+fn default_level() -> String {
+    "error".to_string()
+}
+
+impl CustomMessage {
+    /// `file:line:col: message`, with the location as precise as reported
+    fn summary(&self) -> String {
+        match (&self.file, self.line, self.column) {
+            (Some(file), Some(line), Some(column)) => {
+                format!("{}:{}:{}: {}", file, line, column, self.message)
+            }
+            (Some(file), Some(line), None) => format!("{}:{}: {}", file, line, self.message),
+            (Some(file), None, _) => format!("{}: {}", file, self.message),
+            (None, _, _) => self.message.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use tempfile::TempDir;
+
+    fn shell(script: &str, output: CustomOutputFormat) -> CustomCheckConfig {
+        CustomCheckConfig {
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), script.to_string()],
+            working_dir: None,
+            env: BTreeMap::new(),
+            success_exit_codes: vec![0],
+            timeout_seconds: None,
+            output,
+            description: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exit_code_decides_plain_checks() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut spec = shell("echo drift detected; exit 3", CustomOutputFormat::Plain);
+
+        let result = run("schema", Some(&spec), temp_dir.path()).await.unwrap();
+        assert!(!result.passed);
+        assert_eq!(result.check_type, CheckType::Custom("schema".to_string()));
+        assert_eq!(result.errors, vec!["schema exited with code 3"]);
+        assert!(
+            result
+                .context
+                .contains(&"schema: drift detected".to_string())
+        );
+
+        spec.success_exit_codes = vec![0, 3];
+        let result = run("schema", Some(&spec), temp_dir.path()).await.unwrap();
+        assert!(result.passed);
+    }
+
+    #[tokio::test]
+    async fn test_json_lines_report_errors_and_warnings() {
+        let temp_dir = TempDir::new().unwrap();
+        let script = r#"echo '{"level":"warning","message":"unused dep","file":"Cargo.toml","line":9}'
+echo 'not json'
+echo '{"level":"error","message":"banned license"}'
+exit 1"#;
+        let spec = shell(script, CustomOutputFormat::JsonLines);
+
+        let result = run("deny", Some(&spec), temp_dir.path()).await.unwrap();
+        assert!(!result.passed);
+        assert_eq!(result.errors, vec!["deny: banned license"]);
+        assert_eq!(result.warnings, vec!["deny: Cargo.toml:9: unused dep"]);
+    }
+
+    #[tokio::test]
+    async fn test_check_timeout_and_missing_definition() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut spec = shell("sleep 5", CustomOutputFormat::Plain);
+        spec.timeout_seconds = Some(0);
+
+        let result = run("slow", Some(&spec), temp_dir.path()).await.unwrap();
+        assert_eq!(result.status, CheckStatus::TimedOut);
+
+        let result = run("missing", None, temp_dir.path()).await.unwrap();
+        assert!(!result.passed);
+        assert!(result.errors[0].contains("not defined"));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_check_timeout_kills_grandchildren() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut spec = shell(
+            "sleep 60 & echo $! > grandchild.pid; wait",
+            CustomOutputFormat::Plain,
+        );
+        spec.timeout_seconds = Some(1);
+
+        let result = run("hang", Some(&spec), temp_dir.path()).await.unwrap();
+        assert_eq!(result.status, CheckStatus::TimedOut);
+
+        let pid = std::fs::read_to_string(temp_dir.path().join("grandchild.pid")).unwrap();
+        let stat = format!("/proc/{}/stat", pid.trim());
+        // A killed process is gone, or a zombie until its parent is reaped
+        let running = || {
+            std::fs::read_to_string(&stat).is_ok_and(|s| {
+                s.split_whitespace()
+                    .nth(2)
+                    .is_some_and(|state| state != "Z")
+            })
+        };
+        for _ in 0..50 {
+            if !running() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert!(!running(), "the check's grandchild is still running");
+    }
+}
@@ -33,11 +33,12 @@ pub async fn run(project_path: &Path) -> Result<CheckResult> {
     let start = Instant::now();
     let mut result = CheckResult::new(CheckType::Doc);
 
-    match tokio::process::Command::new("cargo")
-        .args(["doc", "--no-deps"])
-        .current_dir(project_path)
-        .output()
-        .await
+    match super::command::output(
+        tokio::process::Command::new("cargo")
+            .args(["doc", "--no-deps"])
+            .current_dir(project_path),
+    )
+    .await
     {
         Ok(output) => {
             if !output.status.success() {
@@ -63,12 +63,13 @@ fn check_cargo_availability() -> std::result::Result<(), String> {
 
 /// Execute cargo fmt --check command
 async fn execute_format_check(project_path: &Path) -> Result<std::process::Output> {
-    tokio::process::Command::new("cargo")
-        .current_dir(project_path)
-        .args(&["fmt", "--check"])
-        .output()
-        .await
-        .map_err(Into::into)
+    super::command::output(
+        tokio::process::Command::new("cargo")
+            .current_dir(project_path)
+            .args(&["fmt", "--check"]),
+    )
+    .await
+    .map_err(Into::into)
 }
 
 /// Process format check violations and parse output
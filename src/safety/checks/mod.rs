@@ -4,6 +4,7 @@
 //! Each check module implements a specific validation (format, clippy, tests, etc.)
 
 use crate::Result;
+use crate::validation::{CompilerDiagnostic, DiagnosticLevel};
 use std::path::Path;
 
 use super::report::CheckResult;
@@ -14,6 +15,10 @@ pub mod audit;
 pub mod build;
 /// Clippy lint analysis check.
 pub mod clippy;
+/// Running check commands in their own process group.
+pub mod command;
+/// User-defined command checks from the safety config.
+pub mod custom;
 /// Documentation generation and coverage check.
 pub mod doc;
 /// Code formatting verification via `rustfmt`.
@@ -31,6 +36,43 @@ pub mod test;
 /// Test runner infrastructure for safety checks.
 pub mod test_runner;
 
+/// Record cargo diagnostics on a check result
+///
+/// Error-level diagnostics become errors and warnings become warnings, each
+/// as a `file:line:col: [code] message` line prefixed with `label`. Returns
+/// the number of errors recorded.
+pub fn add_diagnostics(
+    result: &mut CheckResult,
+    label: &str,
+    diagnostics: &[CompilerDiagnostic],
+) -> usize {
+    let mut errors = 0;
+    for diagnostic in diagnostics {
+        match diagnostic.level {
+            DiagnosticLevel::Error => {
+                result.add_error(format!("{}: {}", label, diagnostic.summary()));
+                errors += 1;
+            }
+            DiagnosticLevel::Warning => {
+                result.add_warning(format!("{}: {}", label, diagnostic.summary()));
+            }
+            DiagnosticLevel::Note => {}
+        }
+    }
+
+    let fixable = diagnostics
+        .iter()
+        .flat_map(CompilerDiagnostic::machine_applicable)
+        .count();
+    if fixable > 0 {
+        result.add_suggestion(format!(
+            "{} machine-applicable suggestions can be applied with 'cargo clippy --fix' or 'ferrous-forge fix'",
+            fixable
+        ));
+    }
+    errors
+}
+
 /// Trait for implementing safety checks
 #[allow(async_fn_in_trait)]
 pub trait SafetyCheck {
@@ -66,7 +108,7 @@ impl CheckRegistry {
     }
 
     /// Get description for a check type
-    pub fn get_description(check_type: super::CheckType) -> &'static str {
+    pub fn get_description(check_type: &super::CheckType) -> &'static str {
         match check_type {
             super::CheckType::Format => "Validates code formatting with rustfmt",
             super::CheckType::Clippy => "Runs clippy lints with strict warnings",
@@ -79,6 +121,7 @@ impl CheckRegistry {
             super::CheckType::DocCoverage => "Checks documentation coverage",
             super::CheckType::License => "Validates license compatibility",
             super::CheckType::Semver => "Checks semantic versioning compliance",
+            super::CheckType::Custom(_) => "Runs a user-defined command from custom_checks",
         }
     }
 }
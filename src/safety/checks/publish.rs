@@ -34,11 +34,12 @@ pub async fn run(project_path: &Path) -> Result<CheckResult> {
     let mut result = CheckResult::new(CheckType::PublishDryRun);
 
     // Run cargo publish --dry-run
-    let output = tokio::process::Command::new("cargo")
-        .current_dir(project_path)
-        .args(&["publish", "--dry-run"])
-        .output()
-        .await?;
+    let output = super::command::output(
+        tokio::process::Command::new("cargo")
+            .current_dir(project_path)
+            .args(&["publish", "--dry-run"]),
+    )
+    .await?;
 
     result.set_duration(start.elapsed());
 
@@ -93,7 +94,7 @@ fn handle_publish_success(result: &mut CheckResult, stderr: &[u8]) {
         .count();
 
     if warning_count > 0 {
-        result.add_context(format!(
+        result.add_warning(format!(
             "Publish dry run completed with {} warnings",
             warning_count
         ));
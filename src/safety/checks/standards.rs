@@ -1,6 +1,7 @@
 //! Ferrous Forge standards checking
 
 use crate::Result;
+use crate::validation::{RustValidator, Severity, Violation};
 use std::path::Path;
 use std::time::Instant;
 
@@ -33,32 +34,18 @@ pub async fn run(project_path: &Path) -> Result<CheckResult> {
     let start = Instant::now();
     let mut result = CheckResult::new(CheckType::Standards);
 
-    // Use the existing validation module
-    let validator = crate::validation::RustValidator::new(project_path.to_path_buf())?;
-    match validator.validate_project().await {
-        Ok(violations) => {
+    // Use the existing validation module with the configured [rules]
+    let validator = RustValidator::for_project(project_path.to_path_buf()).await?;
+    match validator.validate_with_baseline().await {
+        Ok(comparison) => {
             result.set_duration(start.elapsed());
-
-            if violations.is_empty() {
-                result.add_context("All Ferrous Forge standards met");
-            } else {
-                result.add_error(format!("Found {} standards violations", violations.len()));
-
-                // Add specific violations (limit to first 5)
-                for violation in violations.iter().take(5) {
-                    result.add_error(format!(
-                        "{:?}: {}",
-                        violation.violation_type, violation.message
-                    ));
-                }
-
-                if violations.len() > 5 {
-                    result.add_error(format!("... and {} more violations", violations.len() - 5));
-                }
-
-                result.add_suggestion("Run 'ferrous-forge validate' for detailed report");
-                result.add_suggestion("Fix standards violations before proceeding");
+            if !comparison.baselined.is_empty() {
+                result.add_context(format!(
+                    "{} baselined violations ignored",
+                    comparison.baselined.len()
+                ));
             }
+            record_violations(&mut result, &comparison.new_violations);
         }
         Err(e) => {
             result.set_duration(start.elapsed());
@@ -70,14 +57,92 @@ pub async fn run(project_path: &Path) -> Result<CheckResult> {
     Ok(result)
 }
 
+/// Record violations on `result` by severity: errors fail the check,
+/// warnings are check warnings and info findings are context only
+fn record_violations(result: &mut CheckResult, violations: &[Violation]) {
+    if violations.is_empty() {
+        result.add_context("All Ferrous Forge standards met");
+        return;
+    }
+
+    let errors: Vec<&Violation> = violations
+        .iter()
+        .filter(|v| v.severity == Severity::Error)
+        .collect();
+    if !errors.is_empty() {
+        result.add_error(format!("Found {} standards violations", errors.len()));
+
+        // Add specific violations (limit to first 5)
+        for violation in errors.iter().take(5) {
+            result.add_error(format!(
+                "{:?}: {}",
+                violation.violation_type, violation.message
+            ));
+        }
+
+        if errors.len() > 5 {
+            result.add_error(format!("... and {} more violations", errors.len() - 5));
+        }
+
+        result.add_suggestion("Run 'ferrous-forge validate' for detailed report");
+        result.add_suggestion("Fix standards violations before proceeding");
+    }
+
+    for violation in violations {
+        let text = format!(
+            "{}: {}",
+            violation.violation_type.rule_name(),
+            violation.message
+        );
+        match violation.severity {
+            Severity::Error => {}
+            Severity::Warning => result.add_warning(text),
+            Severity::Info => result.add_context(text),
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
+    use crate::validation::ViolationType;
 
     #[test]
     fn test_standards_check_struct() {
         assert_eq!(StandardsCheck::name(), "standards");
         assert!(!StandardsCheck::description().is_empty());
     }
+
+    #[test]
+    fn test_only_error_violations_fail_the_check() {
+        let violation = |violation_type, severity| {
+            Violation::new(
+                violation_type,
+                "src/lib.rs".into(),
+                1,
+                "message".to_string(),
+                severity,
+            )
+        };
+        let mut result = CheckResult::new(CheckType::Standards);
+        record_violations(
+            &mut result,
+            &[
+                violation(ViolationType::LineTooLong, Severity::Warning),
+                violation(ViolationType::MissingDocs, Severity::Info),
+            ],
+        );
+        assert!(result.passed);
+        assert_eq!(result.warnings.len(), 1);
+
+        record_violations(
+            &mut result,
+            &[violation(
+                ViolationType::UnwrapInProduction,
+                Severity::Error,
+            )],
+        );
+        assert!(!result.passed);
+    }
 }
@@ -35,12 +35,13 @@ pub async fn run(project_path: &Path) -> Result<CheckResult> {
 
     // Run cargo test with comprehensive flags
     // Disable the Ferrous Forge cargo wrapper to avoid recursive validation
-    let output = tokio::process::Command::new("cargo")
-        .current_dir(project_path)
-        .env("FERROUS_FORGE_ENABLED", "0")
-        .args(["test", "--all-targets", "--all-features"])
-        .output()
-        .await?;
+    let output = super::command::output(
+        tokio::process::Command::new("cargo")
+            .current_dir(project_path)
+            .env("FERROUS_FORGE_ENABLED", "0")
+            .args(["test", "--all-targets", "--all-features"]),
+    )
+    .await?;
 
     result.set_duration(start.elapsed());
 
@@ -2,6 +2,7 @@
 
 use crate::{Error, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::time::Duration;
 use tokio::fs;
@@ -13,7 +14,7 @@ use super::{CheckType, PipelineStage};
 pub struct SafetyConfig {
     /// Whether safety pipeline is enabled
     pub enabled: bool,
-    /// Strict mode - block operations on failure
+    /// Strict mode - block operations on warnings as well as failures
     pub strict_mode: bool,
     /// Show progress indicators
     pub show_progress: bool,
@@ -27,6 +28,10 @@ pub struct SafetyConfig {
     pub publish: StageConfig,
     /// Bypass configuration
     pub bypass: BypassConfig,
+    /// User-defined checks, keyed by the name used in stage check lists
+    /// (`{ Custom = "<name>" }`)
+    #[serde(default)]
+    pub custom_checks: BTreeMap<String, CustomCheckConfig>,
 }
 
 /// Configuration for a specific pipeline stage
@@ -42,6 +47,85 @@ pub struct StageConfig {
     pub continue_on_warning: bool,
 }
 
+impl StageConfig {
+    /// Deadline for running every check in this stage
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_seconds)
+    }
+
+    /// The stage's checks as a comma-separated list, in the format
+    /// accepted by `<stage>.checks`
+    pub fn check_list(&self) -> String {
+        self.checks
+            .iter()
+            .map(CheckType::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// A user-defined safety check that runs an external command
+///
+/// ```toml
+/// [custom_checks.deny]
+/// command = "cargo"
+/// args = ["deny", "check"]
+/// timeout_seconds = 120
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomCheckConfig {
+    /// Program to run
+    pub command: String,
+    /// Arguments passed to the program
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Directory to run in, relative to the project root (defaults to the
+    /// project root)
+    #[serde(default)]
+    pub working_dir: Option<PathBuf>,
+    /// Extra environment variables
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    /// Exit codes that count as success
+    #[serde(default = "default_success_exit_codes")]
+    pub success_exit_codes: Vec<i32>,
+    /// Time limit for this check, on top of the stage deadline
+    #[serde(default)]
+    pub timeout_seconds: Option<u64>,
+    /// How to read the command's output
+    #[serde(default)]
+    pub output: CustomOutputFormat,
+    /// One-line description shown in reports
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl CustomCheckConfig {
+    /// The command and its arguments as one line
+    pub fn command_line(&self) -> String {
+        std::iter::once(self.command.as_str())
+            .chain(self.args.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+fn default_success_exit_codes() -> Vec<i32> {
+    vec![0]
+}
+
+/// Output format of a custom check
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomOutputFormat {
+    /// Free-form text; the exit code alone decides the outcome
+    #[default]
+    Plain,
+    /// One JSON object per stdout line with `level`, `message` and
+    /// optional `file`, `line` and `column`
+    JsonLines,
+}
+
 /// Bypass system configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BypassConfig {
@@ -91,7 +175,7 @@ impl Default for SafetyConfig {
     fn default() -> Self {
         Self {
             enabled: true,
-            strict_mode: true,
+            strict_mode: false,
             show_progress: true,
             parallel_checks: true,
             pre_commit: StageConfig {
@@ -119,6 +203,7 @@ impl Default for SafetyConfig {
                 log_bypasses: true,
                 max_bypasses_per_day: 3,
             },
+            custom_checks: BTreeMap::new(),
         }
     }
 }
@@ -207,14 +292,48 @@ impl SafetyConfig {
     }
 
     /// Check if a specific check is enabled for a stage
-    pub fn is_check_enabled(&self, stage: PipelineStage, check: CheckType) -> bool {
+    pub fn is_check_enabled(&self, stage: PipelineStage, check: &CheckType) -> bool {
         let stage_config = self.get_stage_config(stage);
-        stage_config.enabled && stage_config.checks.contains(&check)
+        stage_config.enabled && stage_config.checks.contains(check)
+    }
+
+    /// Parse a comma-separated check list such as `format,clippy,custom:deny`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a name is not a built-in check or a custom check
+    /// defined in `custom_checks`.
+    pub fn parse_check_list(&self, value: &str) -> Result<Vec<CheckType>> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| {
+                let check: CheckType = name.parse()?;
+                if let CheckType::Custom(custom) = &check
+                    && !self.custom_checks.contains_key(custom)
+                {
+                    return Err(Error::config(format!(
+                        "Custom check '{}' is not defined in [custom_checks]",
+                        custom
+                    )));
+                }
+                Ok(check)
+            })
+            .collect()
     }
 
     /// Get timeout for a specific stage
     pub fn get_timeout(&self, stage: PipelineStage) -> Duration {
-        Duration::from_secs(self.get_stage_config(stage).timeout_seconds)
+        self.get_stage_config(stage).timeout()
+    }
+
+    /// Whether checks that pass with warnings block a stage
+    ///
+    /// Warnings block in strict mode unless the stage sets
+    /// `continue_on_warning`.
+    pub fn warnings_block(&self, stage: PipelineStage) -> bool {
+        self.strict_mode && !self.get_stage_config(stage).continue_on_warning
     }
 
     /// Set a configuration value
@@ -257,6 +376,18 @@ impl SafetyConfig {
             .split_once('.')
             .ok_or_else(|| Error::config(format!("Invalid config key format: {}", key)))?;
 
+        if field == "checks" {
+            let checks = self.parse_check_list(value)?;
+            let stage = match stage {
+                "pre_commit" => PipelineStage::PreCommit,
+                "pre_push" => PipelineStage::PrePush,
+                "publish" => PipelineStage::Publish,
+                _ => return Err(Error::config(format!("Unknown safety config key: {}", key))),
+            };
+            self.get_stage_config_mut(stage).checks = checks;
+            return Ok(());
+        }
+
         match (stage, field) {
             ("pre_commit", "enabled") => {
                 self.pre_commit.enabled = self.parse_bool(value, "pre_commit.enabled")?;
@@ -265,6 +396,10 @@ impl SafetyConfig {
                 self.pre_commit.timeout_seconds =
                     self.parse_u64(value, "pre_commit.timeout_seconds")?;
             }
+            ("pre_commit", "continue_on_warning") => {
+                self.pre_commit.continue_on_warning =
+                    self.parse_bool(value, "pre_commit.continue_on_warning")?;
+            }
             ("pre_push", "enabled") => {
                 self.pre_push.enabled = self.parse_bool(value, "pre_push.enabled")?;
             }
@@ -272,12 +407,20 @@ impl SafetyConfig {
                 self.pre_push.timeout_seconds =
                     self.parse_u64(value, "pre_push.timeout_seconds")?;
             }
+            ("pre_push", "continue_on_warning") => {
+                self.pre_push.continue_on_warning =
+                    self.parse_bool(value, "pre_push.continue_on_warning")?;
+            }
             ("publish", "enabled") => {
                 self.publish.enabled = self.parse_bool(value, "publish.enabled")?;
             }
             ("publish", "timeout_seconds") => {
                 self.publish.timeout_seconds = self.parse_u64(value, "publish.timeout_seconds")?;
             }
+            ("publish", "continue_on_warning") => {
+                self.publish.continue_on_warning =
+                    self.parse_bool(value, "publish.continue_on_warning")?;
+            }
             _ => return Err(Error::config(format!("Unknown safety config key: {}", key))),
         }
         Ok(())
@@ -317,10 +460,18 @@ impl SafetyConfig {
             "parallel_checks" => Some(self.parallel_checks.to_string()),
             "pre_commit.enabled" => Some(self.pre_commit.enabled.to_string()),
             "pre_commit.timeout_seconds" => Some(self.pre_commit.timeout_seconds.to_string()),
+            "pre_commit.continue_on_warning" => {
+                Some(self.pre_commit.continue_on_warning.to_string())
+            }
             "pre_push.enabled" => Some(self.pre_push.enabled.to_string()),
             "pre_push.timeout_seconds" => Some(self.pre_push.timeout_seconds.to_string()),
+            "pre_push.continue_on_warning" => Some(self.pre_push.continue_on_warning.to_string()),
             "publish.enabled" => Some(self.publish.enabled.to_string()),
             "publish.timeout_seconds" => Some(self.publish.timeout_seconds.to_string()),
+            "publish.continue_on_warning" => Some(self.publish.continue_on_warning.to_string()),
+            "pre_commit.checks" => Some(self.pre_commit.check_list()),
+            "pre_push.checks" => Some(self.pre_push.check_list()),
+            "publish.checks" => Some(self.publish.check_list()),
             "bypass.enabled" => Some(self.bypass.enabled.to_string()),
             _ => None,
         }
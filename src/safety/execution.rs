@@ -5,13 +5,15 @@
 
 use super::{
     CheckType, PipelineStage, checks,
-    config::StageConfig,
+    config::CustomCheckConfig,
     report::{CheckResult, SafetyReport},
 };
-use crate::Result;
+use crate::{Result, output};
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
+use tokio::time::{self, Instant};
 
 /// Progress display and execution coordinator
 pub struct ExecutionManager {
@@ -19,6 +21,10 @@ pub struct ExecutionManager {
     show_progress: bool,
     /// Whether to run checks in parallel
     parallel_checks: bool,
+    /// Definitions of the user-defined checks, by name
+    custom_checks: BTreeMap<String, CustomCheckConfig>,
+    /// `CARGO_TARGET_DIR` for the check commands, when set
+    cargo_target_dir: Option<PathBuf>,
 }
 
 impl ExecutionManager {
@@ -27,9 +33,27 @@ impl ExecutionManager {
         Self {
             show_progress,
             parallel_checks,
+            custom_checks: BTreeMap::new(),
+            cargo_target_dir: None,
         }
     }
 
+    /// Set the definitions used to run [`CheckType::Custom`] checks
+    pub fn with_custom_checks(
+        mut self,
+        custom_checks: BTreeMap<String, CustomCheckConfig>,
+    ) -> Self {
+        self.custom_checks = custom_checks;
+        self
+    }
+
+    /// Make the check commands build into `target_dir` instead of the
+    /// project's `target` directory
+    pub fn with_cargo_target_dir(mut self, target_dir: PathBuf) -> Self {
+        self.cargo_target_dir = Some(target_dir);
+        self
+    }
+
     /// Setup progress indicators and display header
     pub fn setup_progress_display(&self, stage: PipelineStage) -> Option<MultiProgress> {
         let multi_progress = if self.show_progress {
@@ -38,34 +62,38 @@ impl ExecutionManager {
             None
         };
 
-        println!(
+        output::human(format!(
             "🛡️  Ferrous Forge Safety Pipeline - {}",
             stage.display_name()
-        );
-        println!("{}", "=".repeat(50));
+        ));
+        output::human("=".repeat(50));
 
         multi_progress
     }
 
-    /// Execute checks for the stage
+    /// Execute `checks` for a stage
+    ///
+    /// Every check must finish before the stage's `timeout` deadline.
+    /// A check still running at the deadline is cancelled, which kills its
+    /// child processes, and it and every check after it are recorded as
+    /// timed out.
     ///
     /// # Errors
     ///
     /// Returns an error if any individual check fails to execute.
     pub async fn execute_stage_checks(
         &self,
-        stage_config: &StageConfig,
+        checks: &[CheckType],
+        timeout: Duration,
         report: &mut SafetyReport,
         multi_progress: Option<&MultiProgress>,
         project_path: &Path,
     ) -> Result<()> {
-        let checks = &stage_config.checks;
-
         if self.parallel_checks && checks.len() > 1 {
-            self.run_checks_parallel(checks, report, multi_progress, project_path)
+            self.run_checks_parallel(checks, timeout, report, multi_progress, project_path)
                 .await
         } else {
-            self.run_checks_sequential(checks, report, multi_progress, project_path)
+            self.run_checks_sequential(checks, timeout, report, multi_progress, project_path)
                 .await
         }
     }
@@ -74,11 +102,25 @@ impl ExecutionManager {
     async fn run_checks_sequential(
         &self,
         checks: &[CheckType],
+        timeout: Duration,
         report: &mut SafetyReport,
         multi_progress: Option<&MultiProgress>,
         project_path: &Path,
     ) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+
         for check_type in checks {
+            if Instant::now() >= deadline {
+                let check_result = CheckResult::timed_out(check_type.clone(), timeout, false);
+                output::human(format!(
+                    "  {} {} (not run)",
+                    check_result.status_emoji(),
+                    check_type.display_name()
+                ));
+                report.add_check(check_result);
+                continue;
+            }
+
             let pb = if let Some(mp) = multi_progress {
                 let pb = mp.add(ProgressBar::new_spinner());
                 pb.set_style(
@@ -93,7 +135,24 @@ impl ExecutionManager {
                 None
             };
 
-            let check_result = execute_check(*check_type, project_path).await?;
+            let started = Instant::now();
+            let check = execute_check(check_type, &self.custom_checks, project_path);
+            let check_result = match &self.cargo_target_dir {
+                Some(target_dir) => {
+                    let check = time::timeout_at(deadline, check);
+                    checks::command::with_cargo_target_dir(target_dir.clone(), check).await
+                }
+                None => time::timeout_at(deadline, check).await,
+            };
+            let check_result = match check_result {
+                Ok(check_result) => check_result?,
+                Err(_) => {
+                    let mut check_result =
+                        CheckResult::timed_out(check_type.clone(), timeout, true);
+                    check_result.set_duration(started.elapsed());
+                    check_result
+                }
+            };
 
             if let Some(pb) = pb {
                 pb.finish_with_message(format!(
@@ -103,12 +162,12 @@ impl ExecutionManager {
                     check_result.duration.as_secs_f64()
                 ));
             } else {
-                println!(
+                output::human(format!(
                     "  {} {} ({:.2}s)",
                     check_result.status_emoji(),
                     check_type.display_name(),
                     check_result.duration.as_secs_f64()
-                );
+                ));
             }
 
             report.add_check(check_result);
@@ -121,23 +180,30 @@ impl ExecutionManager {
     async fn run_checks_parallel(
         &self,
         checks: &[CheckType],
+        timeout: Duration,
         report: &mut SafetyReport,
         _multi_progress: Option<&MultiProgress>,
         project_path: &Path,
     ) -> Result<()> {
         // For now, implement as sequential until we add proper parallel execution
         // Parallel execution requires careful handling of stdout/stderr
-        self.run_checks_sequential(checks, report, _multi_progress, project_path)
+        self.run_checks_sequential(checks, timeout, report, _multi_progress, project_path)
             .await
     }
 }
 
 /// Execute a specific check type
 ///
+/// [`CheckType::Custom`] checks are looked up in `custom_checks`.
+///
 /// # Errors
 ///
 /// Returns an error if the underlying check command fails to run.
-pub async fn execute_check(check_type: CheckType, project_path: &Path) -> Result<CheckResult> {
+pub async fn execute_check(
+    check_type: &CheckType,
+    custom_checks: &BTreeMap<String, CustomCheckConfig>,
+    project_path: &Path,
+) -> Result<CheckResult> {
     match check_type {
         CheckType::Format => checks::format::run(project_path).await,
         CheckType::Clippy => checks::clippy::run(project_path).await,
@@ -150,6 +216,9 @@ pub async fn execute_check(check_type: CheckType, project_path: &Path) -> Result
         CheckType::DocCoverage => checks::doc::coverage_check(project_path).await,
         CheckType::License => checks::license::run(project_path).await,
         CheckType::Semver => checks::semver::run(project_path).await,
+        CheckType::Custom(name) => {
+            checks::custom::run(name, custom_checks.get(name), project_path).await
+        }
     }
 }
 
@@ -175,14 +244,14 @@ pub fn handle_check_result(
 }
 
 /// Get the appropriate stage for a check type
-pub fn get_stage_for_check(check_type: CheckType) -> PipelineStage {
+pub fn get_stage_for_check(check_type: &CheckType) -> PipelineStage {
     // Find the first stage that includes this check
     for stage in [
         PipelineStage::PreCommit,
         PipelineStage::PrePush,
         PipelineStage::Publish,
     ] {
-        if CheckType::for_stage(stage).contains(&check_type) {
+        if CheckType::for_stage(stage).contains(check_type) {
             return stage;
         }
     }
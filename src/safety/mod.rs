@@ -21,10 +21,12 @@ pub mod execution;
 pub mod pipeline;
 /// Check result reporting and aggregation.
 pub mod report;
+/// Staged snapshots for checking exactly what will be committed.
+pub mod staged;
 
 pub use config::SafetyConfig;
 pub use pipeline::SafetyPipeline;
-pub use report::{CheckResult, SafetyReport};
+pub use report::{CheckResult, CheckStatus, SafetyReport};
 
 /// Pipeline stage for safety checks
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -79,14 +81,45 @@ impl std::str::FromStr for PipelineStage {
     }
 }
 
+impl std::str::FromStr for CheckType {
+    type Err = Error;
+
+    /// Parse a check by its [`CheckType::name`]; `custom:<name>` selects a
+    /// user-defined check
+    fn from_str(s: &str) -> Result<Self> {
+        if let Some(name) = s.strip_prefix("custom:") {
+            return Ok(Self::Custom(name.to_string()));
+        }
+        checks::CheckRegistry::all_checks()
+            .into_iter()
+            .find(|check| check.name() == s.to_lowercase())
+            .ok_or_else(|| {
+                Error::parse(format!(
+                    "Unknown safety check: {} (use custom:<name> for custom checks)",
+                    s
+                ))
+            })
+    }
+}
+
 impl std::fmt::Display for PipelineStage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.name())
     }
 }
 
+/// Formats a check the way [`CheckType::from_str`] parses it
+impl std::fmt::Display for CheckType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Custom(name) => write!(f, "custom:{}", name),
+            builtin => write!(f, "{}", builtin.name()),
+        }
+    }
+}
+
 /// Safety check type
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CheckType {
     /// Format checking (cargo fmt --check)
     Format,
@@ -110,11 +143,14 @@ pub enum CheckType {
     License,
     /// Semver compatibility check
     Semver,
+    /// User-defined command from the `custom_checks` table of the safety
+    /// config, identified by its key
+    Custom(String),
 }
 
 impl CheckType {
     /// Get the check name as a string
-    pub fn name(&self) -> &'static str {
+    pub fn name(&self) -> &str {
         match self {
             Self::Format => "format",
             Self::Clippy => "clippy",
@@ -127,11 +163,12 @@ impl CheckType {
             Self::DocCoverage => "doc-coverage",
             Self::License => "license",
             Self::Semver => "semver",
+            Self::Custom(name) => name,
         }
     }
 
     /// Get the display name for the check
-    pub fn display_name(&self) -> &'static str {
+    pub fn display_name(&self) -> &str {
         match self {
             Self::Format => "Format Check",
             Self::Clippy => "Clippy Check",
@@ -144,9 +181,15 @@ impl CheckType {
             Self::DocCoverage => "Documentation Coverage",
             Self::License => "License Check",
             Self::Semver => "Semver Check",
+            Self::Custom(name) => name,
         }
     }
 
+    /// Whether this is a user-defined check
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Self::Custom(_))
+    }
+
     /// Get the checks for a specific pipeline stage
     pub fn for_stage(stage: PipelineStage) -> Vec<Self> {
         match stage {
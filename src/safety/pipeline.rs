@@ -1,13 +1,17 @@
 //! Main safety pipeline execution engine
 
-use crate::Result;
+use crate::{Result, output};
 // Removed unused indicatif imports as they are now in execution module
 use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 use super::{
-    PipelineStage, SafetyResult, bypass::BypassManager, config::SafetyConfig,
-    execution::ExecutionManager, report::SafetyReport,
+    CheckType, PipelineStage, SafetyResult,
+    bypass::{BypassManager, BypassScope},
+    config::SafetyConfig,
+    execution::ExecutionManager,
+    report::SafetyReport,
+    staged::StagedSnapshot,
 };
 
 /// Main safety pipeline coordinator
@@ -16,6 +20,8 @@ pub struct SafetyPipeline {
     project_path: PathBuf,
     bypass_manager: BypassManager,
     execution_manager: ExecutionManager,
+    /// Staged snapshot the checks run in, for `--staged`
+    snapshot: Option<StagedSnapshot>,
 }
 
 impl SafetyPipeline {
@@ -28,29 +34,68 @@ impl SafetyPipeline {
     pub async fn new(project_path: impl AsRef<Path>) -> Result<Self> {
         let config = SafetyConfig::load_or_default().await?;
         let bypass_manager = BypassManager::new(&config.bypass)?;
-        let execution_manager = ExecutionManager::new(config.show_progress, config.parallel_checks);
+        let execution_manager = ExecutionManager::new(config.show_progress, config.parallel_checks)
+            .with_custom_checks(config.custom_checks.clone());
 
         Ok(Self {
             config,
             project_path: project_path.as_ref().to_path_buf(),
             bypass_manager,
             execution_manager,
+            snapshot: None,
         })
     }
 
+    /// Create a safety pipeline that checks the staged contents of the
+    /// repository containing `project_path` rather than its working tree
+    ///
+    /// Paths in the reports refer to the real repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the staged snapshot cannot be created or the
+    /// pipeline fails to initialize.
+    pub async fn new_staged(project_path: impl AsRef<Path>) -> Result<Self> {
+        let snapshot = StagedSnapshot::create(project_path.as_ref()).await?;
+        let mut pipeline = Self::new(snapshot.project_path()).await?;
+        pipeline.execution_manager = pipeline
+            .execution_manager
+            .with_cargo_target_dir(snapshot.cargo_target_dir().to_path_buf());
+        pipeline.snapshot = Some(snapshot);
+        Ok(pipeline)
+    }
+
     /// Run safety checks for a specific stage
     ///
     /// # Errors
     ///
     /// Returns an error if any safety check fails to execute.
     pub async fn run_checks(&self, stage: PipelineStage) -> Result<SafetyReport> {
+        let checks = self.config.get_stage_config(stage).checks.clone();
+        self.run_checks_with(stage, &checks).await
+    }
+
+    /// Run a selection of checks under a stage's deadline and strictness
+    ///
+    /// The checks need not be part of the stage's configured list, which
+    /// lets `safety check --check` run a single check on demand.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any safety check fails to execute.
+    pub async fn run_checks_with(
+        &self,
+        stage: PipelineStage,
+        checks: &[CheckType],
+    ) -> Result<SafetyReport> {
         // Check if pipeline or stage is disabled
         if let Some(report) = self.check_disabled_pipeline(stage) {
             return Ok(report);
         }
 
         let start_time = Instant::now();
-        let mut report = SafetyReport::new(stage);
+        let mut report =
+            SafetyReport::new(stage).block_on_warnings(self.config.warnings_block(stage));
         let stage_config = self.config.get_stage_config(stage);
 
         // Setup progress indicators and display header
@@ -59,13 +104,18 @@ impl SafetyPipeline {
         // Execute the checks
         self.execution_manager
             .execute_stage_checks(
-                stage_config,
+                checks,
+                stage_config.timeout(),
                 &mut report,
                 multi_progress.as_ref(),
                 &self.project_path,
             )
             .await?;
 
+        if let Some(snapshot) = &self.snapshot {
+            snapshot.map_report(&mut report);
+        }
+
         // Finalize the report
         self.finalize_report(&mut report, start_time).await;
 
@@ -75,13 +125,13 @@ impl SafetyPipeline {
     /// Check if pipeline or stage is disabled, return early report if so
     fn check_disabled_pipeline(&self, stage: PipelineStage) -> Option<SafetyReport> {
         if !self.config.enabled {
-            println!("⚠️  Safety pipeline is disabled");
+            output::human("⚠️  Safety pipeline is disabled");
             return Some(SafetyReport::new(stage));
         }
 
         let stage_config = self.config.get_stage_config(stage);
         if !stage_config.enabled {
-            println!("⚠️  {} stage is disabled", stage.display_name());
+            output::human(format!("⚠️  {} stage is disabled", stage.display_name()));
             return Some(SafetyReport::new(stage));
         }
 
@@ -99,14 +149,28 @@ impl SafetyPipeline {
 
     /// Enforce safety checks and block operation if they fail
     ///
+    /// Failed and timed-out checks always block. Checks that pass with
+    /// warnings block too in strict mode, unless the stage sets
+    /// `continue_on_warning`.
+    ///
     /// # Errors
     ///
     /// Returns an error if checking for an active bypass fails or if
     /// safety checks fail to execute.
     pub async fn enforce_safety(&self, stage: PipelineStage) -> Result<SafetyResult> {
-        // Check for active bypass first
-        if let Some(bypass) = self.bypass_manager.check_active_bypass(stage).await? {
-            println!("⚠️  Safety checks bypassed: {}", bypass.reason);
+        // Check for a bypass of this repository first; a staged snapshot
+        // stands in for the repository it was taken from
+        let checked_path = self
+            .snapshot
+            .as_ref()
+            .map_or(self.project_path.as_path(), |s| s.repo_root());
+        let location = BypassScope::locate(checked_path).await?;
+        if let Some(bypass) = self
+            .bypass_manager
+            .check_active_bypass(stage, &location)
+            .await?
+        {
+            output::human(format!("⚠️  Safety checks bypassed: {}", bypass.reason));
             return Ok(SafetyResult::Bypassed {
                 reason: bypass.reason,
                 user: bypass.user,
@@ -122,7 +186,7 @@ impl SafetyPipeline {
         if report.passed {
             Ok(SafetyResult::Passed)
         } else {
-            let failures = report.all_errors();
+            let failures = report.blocking_messages();
             let suggestions = report.all_suggestions();
 
             Ok(SafetyResult::Blocked {
@@ -147,8 +211,8 @@ impl SafetyPipeline {
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
-    use crate::safety::CheckType;
     use crate::safety::execution::get_stage_for_check;
+    use std::time::Duration;
     use tempfile::TempDir;
 
     #[tokio::test]
@@ -160,16 +224,51 @@ mod tests {
         assert!(pipeline.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_expired_stage_deadline_times_out_checks() {
+        let temp_dir = TempDir::new().unwrap();
+        let checks = [CheckType::Format, CheckType::Build];
+        let mut report = SafetyReport::new(PipelineStage::PreCommit);
+
+        ExecutionManager::new(false, false)
+            .execute_stage_checks(&checks, Duration::ZERO, &mut report, None, temp_dir.path())
+            .await
+            .unwrap();
+
+        assert!(!report.passed);
+        assert_eq!(report.timed_out_checks().len(), 2);
+        assert!(report.all_errors()[0].contains("deadline of 0s"));
+    }
+
+    #[test]
+    fn test_strict_mode_decides_whether_warnings_block() {
+        let mut config = SafetyConfig::default();
+        assert!(!config.warnings_block(PipelineStage::PrePush));
+
+        config.strict_mode = true;
+        assert!(config.warnings_block(PipelineStage::PrePush));
+
+        config.pre_push.continue_on_warning = true;
+        assert!(!config.warnings_block(PipelineStage::PrePush));
+        assert!(config.warnings_block(PipelineStage::Publish));
+
+        config.strict_mode = false;
+        assert!(!config.warnings_block(PipelineStage::Publish));
+    }
+
     #[test]
     fn test_get_stage_for_check() {
         // Test the get_stage_for_check function directly
         assert_eq!(
-            get_stage_for_check(CheckType::Format),
+            get_stage_for_check(&CheckType::Format),
             PipelineStage::PreCommit
         );
-        assert_eq!(get_stage_for_check(CheckType::Test), PipelineStage::PrePush);
         assert_eq!(
-            get_stage_for_check(CheckType::Semver),
+            get_stage_for_check(&CheckType::Test),
+            PipelineStage::PrePush
+        );
+        assert_eq!(
+            get_stage_for_check(&CheckType::Semver),
             PipelineStage::Publish
         );
     }
@@ -8,17 +8,50 @@ use tokio::fs;
 
 use super::{CheckType, PipelineStage};
 
+/// Outcome of a single safety check
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    /// The check found nothing to report
+    #[default]
+    Passed,
+    /// The check succeeded but reported warnings
+    Warning,
+    /// The check reported errors
+    Failed,
+    /// The check did not finish before the stage deadline
+    TimedOut,
+}
+
+impl CheckStatus {
+    /// Get the status name as a string
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Passed => "passed",
+            Self::Warning => "warning",
+            Self::Failed => "failed",
+            Self::TimedOut => "timed-out",
+        }
+    }
+}
+
 /// Result of a single safety check
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CheckResult {
     /// Type of check that was run
     pub check_type: CheckType,
-    /// Whether the check passed
+    /// Whether the check passed (warnings still count as passed)
     pub passed: bool,
+    /// Outcome of the check
+    #[serde(default)]
+    pub status: CheckStatus,
     /// Duration the check took to run
     pub duration: Duration,
     /// Error messages if check failed
     pub errors: Vec<String>,
+    /// Warning messages that do not fail the check on their own
+    #[serde(default)]
+    pub warnings: Vec<String>,
     /// Suggestions for fixing issues
     pub suggestions: Vec<String>,
     /// Additional context information
@@ -31,16 +64,62 @@ impl CheckResult {
         Self {
             check_type,
             passed: true,
+            status: CheckStatus::Passed,
             duration: Duration::default(),
             errors: Vec::new(),
+            warnings: Vec::new(),
             suggestions: Vec::new(),
             context: Vec::new(),
         }
     }
 
+    /// Create the result for a check that ran past the stage deadline
+    ///
+    /// `started` is false for checks that were never run because the
+    /// deadline had already expired.
+    pub fn timed_out(check_type: CheckType, timeout: Duration, started: bool) -> Self {
+        let mut result = Self::new(check_type);
+        result.passed = false;
+        result.status = CheckStatus::TimedOut;
+        if started {
+            result.errors.push(format!(
+                "Timed out: stage deadline of {}s exceeded",
+                timeout.as_secs()
+            ));
+        } else {
+            result.errors.push(format!(
+                "Not run: stage deadline of {}s already exceeded",
+                timeout.as_secs()
+            ));
+        }
+        result.add_suggestion(
+            "Raise the stage timeout with 'ferrous-forge safety config --set <stage>.timeout_seconds=<secs>'",
+        );
+        result
+    }
+
     /// Mark the check as failed
     pub fn fail(&mut self) {
         self.passed = false;
+        if self.status != CheckStatus::TimedOut {
+            self.status = CheckStatus::Failed;
+        }
+    }
+
+    /// Add a warning message
+    ///
+    /// A warning leaves the check passing; whether it blocks the operation
+    /// is decided by the pipeline's strict mode.
+    pub fn add_warning(&mut self, warning: impl Into<String>) {
+        self.warnings.push(warning.into());
+        if self.status == CheckStatus::Passed {
+            self.status = CheckStatus::Warning;
+        }
+    }
+
+    /// Whether the check passed with warnings
+    pub fn has_warnings(&self) -> bool {
+        self.passed && !self.warnings.is_empty()
     }
 
     /// Add an error message
@@ -66,15 +145,21 @@ impl CheckResult {
 
     /// Get a status emoji for display
     pub fn status_emoji(&self) -> &'static str {
-        if self.passed { "✅" } else { "❌" }
+        match self.status {
+            CheckStatus::Passed => "✅",
+            CheckStatus::Warning => "⚠️",
+            CheckStatus::Failed => "❌",
+            CheckStatus::TimedOut => "⏱️",
+        }
     }
 
     /// Get a colored status for display
     pub fn status_colored(&self) -> console::StyledObject<&'static str> {
-        if self.passed {
-            style("PASS").green().bold()
-        } else {
-            style("FAIL").red().bold()
+        match self.status {
+            CheckStatus::Passed => style("PASS").green().bold(),
+            CheckStatus::Warning => style("WARN").yellow().bold(),
+            CheckStatus::Failed => style("FAIL").red().bold(),
+            CheckStatus::TimedOut => style("TIMEOUT").red().bold(),
         }
     }
 }
@@ -86,8 +171,12 @@ pub struct SafetyReport {
     pub stage: PipelineStage,
     /// Individual check results
     pub checks: Vec<CheckResult>,
-    /// Whether all checks passed
+    /// Whether the operation is allowed: no check failed and, when warnings
+    /// block, no check warned
     pub passed: bool,
+    /// Whether checks that passed with warnings block the operation
+    #[serde(default)]
+    pub warnings_block: bool,
     /// Total duration for all checks
     pub total_duration: Duration,
     /// Timestamp when report was generated
@@ -101,14 +190,21 @@ impl SafetyReport {
             stage,
             checks: Vec::new(),
             passed: true,
+            warnings_block: false,
             total_duration: Duration::default(),
             timestamp: chrono::Utc::now(),
         }
     }
 
+    /// Make warnings block the operation, as strict mode does
+    pub fn block_on_warnings(mut self, warnings_block: bool) -> Self {
+        self.warnings_block = warnings_block;
+        self
+    }
+
     /// Add a check result to the report
     pub fn add_check(&mut self, check: CheckResult) {
-        if !check.passed {
+        if !check.passed || (self.warnings_block && check.has_warnings()) {
             self.passed = false;
         }
         self.total_duration += check.duration;
@@ -127,6 +223,37 @@ impl SafetyReport {
         self.checks.iter().filter(|c| !c.passed).collect()
     }
 
+    /// Get checks that passed with warnings
+    pub fn warning_checks(&self) -> Vec<&CheckResult> {
+        self.checks.iter().filter(|c| c.has_warnings()).collect()
+    }
+
+    /// Get checks that ran past the stage deadline
+    pub fn timed_out_checks(&self) -> Vec<&CheckResult> {
+        self.checks
+            .iter()
+            .filter(|c| c.status == CheckStatus::TimedOut)
+            .collect()
+    }
+
+    /// Get all warning messages
+    pub fn all_warnings(&self) -> Vec<String> {
+        self.checks
+            .iter()
+            .flat_map(|c| c.warnings.iter().cloned())
+            .collect()
+    }
+
+    /// Get the messages that block the operation: every error, plus every
+    /// warning when warnings block
+    pub fn blocking_messages(&self) -> Vec<String> {
+        let mut messages = self.all_errors();
+        if self.warnings_block {
+            messages.extend(self.all_warnings());
+        }
+        messages
+    }
+
     /// Get all error messages
     pub fn all_errors(&self) -> Vec<String> {
         self.checks
@@ -161,7 +288,18 @@ impl SafetyReport {
 
         println!("\nTotal time: {:.2}s", self.total_duration.as_secs_f64());
 
-        if self.passed {
+        let warned = self.warning_checks().len();
+        if self.passed && warned > 0 {
+            println!(
+                "{}",
+                style(format!(
+                    "🎉 Safety checks passed with warnings in {} check(s)",
+                    warned
+                ))
+                .yellow()
+                .bold()
+            );
+        } else if self.passed {
             println!("{}", style("🎉 All safety checks passed!").green().bold());
         } else {
             println!(
@@ -213,9 +351,37 @@ impl SafetyReport {
             }
         }
 
+        self.print_warnings();
+
         println!();
     }
 
+    /// Print the checks that passed with warnings
+    fn print_warnings(&self) {
+        let warned = self.warning_checks();
+        if warned.is_empty() {
+            return;
+        }
+
+        let heading = if self.warnings_block {
+            style("📋 Warnings (blocking in strict mode):").red().bold()
+        } else {
+            style("📋 Warnings:").yellow().bold()
+        };
+        println!("\n{}", heading);
+
+        for check in warned {
+            println!(
+                "\n  {} {}",
+                style("⚠️").yellow(),
+                style(check.check_type.display_name()).yellow().bold()
+            );
+            for warning in &check.warnings {
+                println!("    {}", style(warning).yellow());
+            }
+        }
+    }
+
     /// Save report to file for audit trail
     ///
     /// # Errors
@@ -276,6 +442,47 @@ mod tests {
         assert_eq!(report.failed_checks().len(), 1);
     }
 
+    #[test]
+    fn test_check_status_transitions() {
+        let mut result = CheckResult::new(CheckType::Build);
+        assert_eq!(result.status, CheckStatus::Passed);
+
+        result.add_warning("Build completed with 2 warnings");
+        assert!(result.passed);
+        assert!(result.has_warnings());
+        assert_eq!(result.status, CheckStatus::Warning);
+
+        result.add_error("linker failed");
+        assert!(!result.passed);
+        assert!(!result.has_warnings());
+        assert_eq!(result.status, CheckStatus::Failed);
+
+        let timed_out = CheckResult::timed_out(CheckType::Test, Duration::from_secs(5), true);
+        assert!(!timed_out.passed);
+        assert_eq!(timed_out.status, CheckStatus::TimedOut);
+        assert_eq!(timed_out.status.name(), "timed-out");
+    }
+
+    #[test]
+    fn test_warnings_only_block_when_requested() {
+        let mut warned = CheckResult::new(CheckType::Build);
+        warned.add_warning("unused import");
+
+        let mut lenient = SafetyReport::new(PipelineStage::PrePush);
+        lenient.add_check(warned.clone());
+        assert!(lenient.passed);
+        assert!(lenient.blocking_messages().is_empty());
+
+        let mut strict = SafetyReport::new(PipelineStage::PrePush).block_on_warnings(true);
+        strict.add_check(warned);
+        assert!(!strict.passed);
+        assert_eq!(
+            strict.blocking_messages(),
+            vec!["unused import".to_string()]
+        );
+        assert!(strict.failed_checks().is_empty());
+    }
+
     #[test]
     fn test_report_merge() {
         let mut report1 = SafetyReport::new(PipelineStage::PreCommit);
@@ -0,0 +1,258 @@
+//! Staged snapshots for pre-commit checks
+//!
+//! A commit records the git index, not the working tree, so checks run
+//! against the working tree can pass on unstaged fixes or fail on unstaged
+//! breakage. [`StagedSnapshot`] writes the index into a temporary directory
+//! with `git checkout-index`, leaving the working tree and the index
+//! untouched, and checks run there instead. Paths in the resulting report
+//! are then mapped back to the real repository.
+//!
+//! Cargo builds the snapshot into its own [`STAGED_TARGET_DIR`] under the
+//! project's `target` directory. Sharing `target` itself would let the
+//! snapshot's builds overwrite the project's binaries and fingerprints.
+
+use crate::validation::changed_files::git;
+use crate::{Error, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+use super::report::SafetyReport;
+
+/// Directory, inside the project's target directory, that staged snapshots
+/// build into; kept between runs so dependencies are not rebuilt
+pub const STAGED_TARGET_DIR: &str = "ferrous-forge-staged";
+
+/// The staged contents of a repository, materialized in a temporary
+/// directory that is removed on drop
+pub struct StagedSnapshot {
+    /// Temporary directory holding the snapshot
+    dir: TempDir,
+    /// Top level of the real repository
+    repo_root: PathBuf,
+    /// The project directory inside the snapshot
+    project_path: PathBuf,
+    /// `CARGO_TARGET_DIR` for builds of the snapshot
+    cargo_target_dir: PathBuf,
+}
+
+impl StagedSnapshot {
+    /// Materialize the index of the repository containing `project_path`
+    ///
+    /// An untracked `Cargo.lock` is copied from the working tree so
+    /// dependency resolution matches. Submodule contents are not included.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `project_path` is not inside a git repository, or
+    /// the snapshot cannot be written.
+    pub async fn create(project_path: &Path) -> Result<Self> {
+        let repo_root = PathBuf::from(
+            git(project_path, &["rev-parse", "--show-toplevel"])
+                .await?
+                .trim(),
+        );
+        let prefix = git(project_path, &["rev-parse", "--show-prefix"]).await?;
+        let dir = tempfile::Builder::new()
+            .prefix("ferrous-forge-staged-")
+            .tempdir()
+            .map_err(|e| Error::io(format!("Failed to create staged snapshot: {}", e)))?;
+
+        // checkout-index treats the prefix as a string, so it needs the slash
+        let target = format!("--prefix={}/", dir.path().display());
+        git(&repo_root, &["checkout-index", "--all", "--force", &target]).await?;
+
+        let snapshot = Self {
+            project_path: dir.path().join(prefix.trim()),
+            cargo_target_dir: staged_target_dir(&repo_root.join(prefix.trim())),
+            dir,
+            repo_root,
+        };
+        for relative in cargo_roots(prefix.trim()) {
+            snapshot.copy_lockfile(&relative)?;
+        }
+        Ok(snapshot)
+    }
+
+    /// The project directory inside the snapshot, where checks should run
+    pub fn project_path(&self) -> &Path {
+        &self.project_path
+    }
+
+    /// Top level of the real repository
+    pub fn repo_root(&self) -> &Path {
+        &self.repo_root
+    }
+
+    /// Where cargo should build the snapshot, for `CARGO_TARGET_DIR`
+    pub fn cargo_target_dir(&self) -> &Path {
+        &self.cargo_target_dir
+    }
+
+    /// Rewrite snapshot paths in every message of `report` to the real
+    /// repository
+    pub fn map_report(&self, report: &mut SafetyReport) {
+        // The canonical path may extend the raw one (`/private/var` on
+        // macOS), so it is replaced first
+        let mut snapshot_roots = Vec::new();
+        if let Ok(canonical) = self.dir.path().canonicalize() {
+            snapshot_roots.push(canonical.to_string_lossy().into_owned());
+        }
+        snapshot_roots.push(self.dir.path().to_string_lossy().into_owned());
+        let repo_root = self.repo_root.to_string_lossy();
+
+        for check in &mut report.checks {
+            for message in check
+                .errors
+                .iter_mut()
+                .chain(check.warnings.iter_mut())
+                .chain(check.suggestions.iter_mut())
+                .chain(check.context.iter_mut())
+            {
+                for root in &snapshot_roots {
+                    if message.contains(root.as_str()) {
+                        *message = message.replace(root.as_str(), &repo_root);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Bring an untracked `Cargo.lock` from the working tree into the
+    /// snapshot
+    fn copy_lockfile(&self, relative: &Path) -> Result<()> {
+        let real = self.repo_root.join(relative);
+        let snapshot = self.dir.path().join(relative);
+
+        let lockfile = real.join("Cargo.lock");
+        if lockfile.is_file() && !snapshot.join("Cargo.lock").exists() {
+            fs::copy(&lockfile, snapshot.join("Cargo.lock"))
+                .map_err(|e| Error::io(format!("Failed to copy Cargo.lock: {}", e)))?;
+        }
+        Ok(())
+    }
+}
+
+/// [`STAGED_TARGET_DIR`] inside the target directory `project` builds into
+fn staged_target_dir(project: &Path) -> PathBuf {
+    // Checks run inside the snapshot, so a relative directory is resolved
+    // here, against the directory it was set in
+    std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .map_or_else(
+            || project.join("target"),
+            |dir| std::path::absolute(&dir).unwrap_or(dir),
+        )
+        .join(STAGED_TARGET_DIR)
+}
+
+/// Directories, relative to the repository root, that may hold a
+/// `Cargo.lock`: the repository root and the project
+fn cargo_roots(prefix: &str) -> Vec<PathBuf> {
+    let mut roots = vec![PathBuf::new()];
+    if !prefix.is_empty() {
+        roots.push(PathBuf::from(prefix));
+    }
+    roots
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::safety::execution::ExecutionManager;
+    use crate::safety::report::CheckResult;
+    use crate::safety::{CheckType, PipelineStage};
+    use std::process::Command;
+    use std::time::Duration;
+
+    fn git_cmd(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_holds_the_index_not_the_working_tree() {
+        let repo = TempDir::new().unwrap();
+        git_cmd(repo.path(), &["init", "--quiet"]);
+        fs::create_dir_all(repo.path().join("crate/src")).unwrap();
+        fs::write(repo.path().join("crate/src/lib.rs"), "// staged\n").unwrap();
+        fs::write(repo.path().join("crate/Cargo.lock"), "# lock\n").unwrap();
+        git_cmd(repo.path(), &["add", "crate/src/lib.rs"]);
+        fs::write(repo.path().join("crate/src/lib.rs"), "// unstaged\n").unwrap();
+        fs::write(repo.path().join("crate/src/new.rs"), "// untracked\n").unwrap();
+
+        let snapshot = StagedSnapshot::create(&repo.path().join("crate"))
+            .await
+            .unwrap();
+
+        let project = snapshot.project_path();
+        assert_eq!(
+            fs::read_to_string(project.join("src/lib.rs")).unwrap(),
+            "// staged\n"
+        );
+        assert!(!project.join("src/new.rs").exists());
+        assert!(project.join("Cargo.lock").exists());
+        assert_eq!(
+            fs::read_to_string(repo.path().join("crate/src/lib.rs")).unwrap(),
+            "// unstaged\n"
+        );
+
+        let mut check = CheckResult::new(CheckType::Format);
+        check.add_error(format!("Diff in {}", project.join("src/lib.rs").display()));
+        let mut report = SafetyReport::new(PipelineStage::PreCommit);
+        report.add_check(check);
+        snapshot.map_report(&mut report);
+
+        let expected = snapshot.repo_root().join("crate/src/lib.rs");
+        assert_eq!(
+            report.checks[0].errors[0],
+            format!("Diff in {}", expected.display())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_builds_leave_the_project_target_alone() {
+        let repo = TempDir::new().unwrap();
+        git_cmd(repo.path(), &["init", "--quiet"]);
+        fs::create_dir_all(repo.path().join("src")).unwrap();
+        fs::write(
+            repo.path().join("Cargo.toml"),
+            "[package]\nname = \"staged_probe\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+        )
+        .unwrap();
+        fs::write(repo.path().join("src/lib.rs"), "pub fn probe() {}\n").unwrap();
+        git_cmd(repo.path(), &["add", "."]);
+        let real_debug = repo.path().join("target/debug");
+        fs::create_dir_all(&real_debug).unwrap();
+        fs::write(real_debug.join("marker"), "untouched").unwrap();
+
+        let snapshot = StagedSnapshot::create(repo.path()).await.unwrap();
+        assert!(!snapshot.project_path().join("target").exists());
+
+        let mut report = SafetyReport::new(PipelineStage::PreCommit);
+        ExecutionManager::new(false, false)
+            .with_cargo_target_dir(snapshot.cargo_target_dir().to_path_buf())
+            .execute_stage_checks(
+                &[CheckType::Build],
+                Duration::from_secs(300),
+                &mut report,
+                None,
+                snapshot.project_path(),
+            )
+            .await
+            .unwrap();
+
+        assert!(report.passed, "{:?}", report.all_errors());
+        assert!(snapshot.cargo_target_dir().join("release").is_dir());
+        let entries: Vec<_> = fs::read_dir(&real_debug)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(entries, vec!["marker"]);
+    }
+}
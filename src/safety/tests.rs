@@ -51,3 +51,80 @@ fn test_safety_result_is_allowed() {
         .is_allowed()
     );
 }
+
+#[test]
+fn test_check_type_from_str_round_trips() {
+    assert_eq!("clippy".parse::<CheckType>().unwrap(), CheckType::Clippy);
+    assert_eq!(
+        "doc-coverage".parse::<CheckType>().unwrap(),
+        CheckType::DocCoverage
+    );
+    assert_eq!(
+        "custom:deny".parse::<CheckType>().unwrap(),
+        CheckType::Custom("deny".to_string())
+    );
+    assert!("deny".parse::<CheckType>().is_err());
+
+    for check in checks::CheckRegistry::all_checks() {
+        assert_eq!(check.to_string().parse::<CheckType>().unwrap(), check);
+    }
+}
+
+#[test]
+fn test_custom_checks_in_config() {
+    let mut config: SafetyConfig = toml::from_str(
+        r#"
+enabled = true
+strict_mode = true
+show_progress = true
+parallel_checks = false
+
+[pre_commit]
+enabled = true
+timeout_seconds = 300
+checks = ["Format", { Custom = "deny" }]
+continue_on_warning = false
+
+[pre_push]
+enabled = true
+timeout_seconds = 600
+checks = ["Test"]
+continue_on_warning = false
+
+[publish]
+enabled = true
+timeout_seconds = 900
+checks = ["Build"]
+continue_on_warning = false
+
+[bypass]
+enabled = true
+require_reason = true
+require_confirmation = true
+log_bypasses = true
+max_bypasses_per_day = 3
+
+[custom_checks.deny]
+command = "cargo"
+args = ["deny", "check"]
+output = "json_lines"
+"#,
+    )
+    .unwrap();
+
+    let deny = &config.custom_checks["deny"];
+    assert_eq!(deny.command_line(), "cargo deny check");
+    assert_eq!(deny.success_exit_codes, vec![0]);
+    assert_eq!(deny.output, config::CustomOutputFormat::JsonLines);
+    assert_eq!(
+        config.get("pre_commit.checks").unwrap(),
+        "format,custom:deny"
+    );
+
+    config.set("pre_push.checks", "test, custom:deny").unwrap();
+    assert!(config.is_check_enabled(
+        PipelineStage::PrePush,
+        &CheckType::Custom("deny".to_string())
+    ));
+    assert!(config.set("pre_push.checks", "custom:machete").is_err());
+}
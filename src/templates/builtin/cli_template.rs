@@ -1,5 +1,6 @@
 //! CLI application template
 
+use crate::templates::builtin::CRATE_NAME_PATTERN;
 use crate::templates::{
     BuiltinTemplate, TemplateFile, TemplateKind, TemplateManifest, TemplateVariable,
 };
@@ -22,10 +23,13 @@ fn create_cli_manifest() -> TemplateManifest {
     manifest.author = "Ferrous Forge Team".to_string();
 
     // Add variables
-    manifest.add_variable(TemplateVariable::required(
-        "project_name".to_string(),
-        "Name of the project".to_string(),
-    ));
+    manifest.add_variable(
+        TemplateVariable::required(
+            "project_name".to_string(),
+            "Name of the project".to_string(),
+        )
+        .with_pattern(CRATE_NAME_PATTERN.to_string()),
+    );
 
     manifest.add_variable(TemplateVariable::optional(
         "author".to_string(),
@@ -112,7 +116,7 @@ fn main_rs_content() -> String {
 
 use anyhow::Result;
 use clap::Parser;
-use {{project_ident}}::{run, Config};
+use {{snake_case project_name}}::{run, Config};
 
 /// Command-line arguments
 #[derive(Debug, Parser)]
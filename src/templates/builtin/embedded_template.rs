@@ -1,5 +1,6 @@
 //! Embedded template for Rust embedded projects
 
+use crate::templates::builtin::CRATE_NAME_PATTERN;
 use crate::templates::{
     BuiltinTemplate, TemplateFile, TemplateKind, TemplateManifest, TemplateVariable,
 };
@@ -30,10 +31,13 @@ fn create_embedded_manifest() -> TemplateManifest {
 
 /// Add variables to the embedded manifest
 fn add_embedded_variables(manifest: &mut TemplateManifest) {
-    manifest.add_variable(TemplateVariable::required(
-        "project_name".to_string(),
-        "Name of the embedded project".to_string(),
-    ));
+    manifest.add_variable(
+        TemplateVariable::required(
+            "project_name".to_string(),
+            "Name of the embedded project".to_string(),
+        )
+        .with_pattern(CRATE_NAME_PATTERN.to_string()),
+    );
 
     manifest.add_variable(TemplateVariable::optional(
         "author".to_string(),
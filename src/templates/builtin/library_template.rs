@@ -1,5 +1,6 @@
 //! Library template
 
+use crate::templates::builtin::CRATE_NAME_PATTERN;
 use crate::templates::{
     BuiltinTemplate, TemplateFile, TemplateKind, TemplateManifest, TemplateVariable,
 };
@@ -22,10 +23,13 @@ fn create_library_manifest() -> TemplateManifest {
     manifest.author = "Ferrous Forge Team".to_string();
 
     // Add variables
-    manifest.add_variable(TemplateVariable::required(
-        "project_name".to_string(),
-        "Name of the library".to_string(),
-    ));
+    manifest.add_variable(
+        TemplateVariable::required(
+            "project_name".to_string(),
+            "Name of the library".to_string(),
+        )
+        .with_pattern(CRATE_NAME_PATTERN.to_string()),
+    );
 
     manifest.add_variable(TemplateVariable::optional(
         "author".to_string(),
@@ -160,18 +164,18 @@ use thiserror::Error;
 
 /// Library-specific errors
 #[derive(Debug, Error)]
-pub enum {{project_pascal}}Error {
+pub enum {{pascal_case project_name}}Error {
     /// An example error variant
     #[error("Example error: {0}")]
     Example(String),
 }
 
 /// Main library functionality
-pub struct {{project_pascal}} {
+pub struct {{pascal_case project_name}} {
     // Add fields here
 }
 
-impl {{project_pascal}} {
+impl {{pascal_case project_name}} {
     /// Create a new instance
     pub fn new() -> Self {
         Self {
@@ -180,7 +184,7 @@ impl {{project_pascal}} {
     }
 }
 
-impl Default for {{project_pascal}} {
+impl Default for {{pascal_case project_name}} {
     fn default() -> Self {
         Self::new()
     }
@@ -193,7 +197,7 @@ mod tests {
 
     #[test]
     fn test_new() {
-        let instance = {{project_pascal}}::new();
+        let instance = {{pascal_case project_name}}::new();
         // Add assertions
     }
 }
@@ -1,5 +1,9 @@
 //! Built-in template definitions
 
+/// Pattern for project names the built-in templates accept: a valid crate
+/// name
+pub const CRATE_NAME_PATTERN: &str = "^[A-Za-z][A-Za-z0-9_-]*$";
+
 /// Command-line application project template.
 pub mod cli_template;
 /// Embedded systems (no_std) project template.
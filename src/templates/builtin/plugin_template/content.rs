@@ -44,7 +44,7 @@ serde = { version = "1.0", features = ["derive"] }
 tempfile = "3.10"
 
 [lib]
-name = "{{project_name}}"
+name = "{{snake_case project_name}}"
 crate-type = ["cdylib", "rlib"]
 
 [[example]]
@@ -64,7 +64,7 @@ fn host_example_content() -> String {
     r#"//! Example host application that loads and uses plugins
 
 use anyhow::Result;
-use {{project_name}}::{DefaultPluginManager, PluginManager};
+use {{snake_case project_name}}::{DefaultPluginManager, PluginManager};
 
 fn main() -> Result<()> {
     println!("{{project_name}} Host Application");
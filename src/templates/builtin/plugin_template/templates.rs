@@ -189,7 +189,7 @@ pub fn plugin_example_content() -> String {
     r#"//! Example plugin implementation
 
 use anyhow::Result;
-use {{project_name}}::{Plugin, PluginInfo, PLUGIN_API_VERSION};
+use {{snake_case project_name}}::{Plugin, PluginInfo, PLUGIN_API_VERSION};
 
 /// Example plugin implementation
 pub struct ExamplePlugin {
@@ -1,5 +1,6 @@
 //! Web service template definition
 
+use crate::templates::builtin::CRATE_NAME_PATTERN;
 use crate::templates::manifest::{TemplateFile, TemplateKind, TemplateManifest, TemplateVariable};
 use crate::templates::registry::BuiltinTemplate;
 use std::collections::HashMap;
@@ -29,10 +30,13 @@ fn create_web_service_manifest() -> TemplateManifest {
 
 /// Add template variables to the manifest
 fn add_template_variables(manifest: &mut TemplateManifest) {
-    manifest.add_variable(TemplateVariable::required(
-        "project_name".to_string(),
-        "Name of the project".to_string(),
-    ));
+    manifest.add_variable(
+        TemplateVariable::required(
+            "project_name".to_string(),
+            "Name of the project".to_string(),
+        )
+        .with_pattern(CRATE_NAME_PATTERN.to_string()),
+    );
 
     manifest.add_variable(TemplateVariable::optional(
         "author".to_string(),
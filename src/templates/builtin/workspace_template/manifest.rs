@@ -1,5 +1,6 @@
 //! Workspace template manifest creation
 
+use crate::templates::builtin::CRATE_NAME_PATTERN;
 use crate::templates::{TemplateFile, TemplateKind, TemplateManifest, TemplateVariable};
 use std::path::PathBuf;
 
@@ -19,10 +20,13 @@ pub fn create_workspace_manifest() -> TemplateManifest {
 
 /// Add variables to the workspace manifest
 fn add_workspace_variables(manifest: &mut TemplateManifest) {
-    manifest.add_variable(TemplateVariable::required(
-        "workspace_name".to_string(),
-        "Name of the workspace".to_string(),
-    ));
+    manifest.add_variable(
+        TemplateVariable::required(
+            "workspace_name".to_string(),
+            "Name of the workspace".to_string(),
+        )
+        .with_pattern(CRATE_NAME_PATTERN.to_string()),
+    );
 
     manifest.add_variable(TemplateVariable::optional(
         "author".to_string(),
@@ -14,8 +14,8 @@ pub fn cli_main_content() -> String {
 
 use anyhow::Result;
 use clap::{Parser, Subcommand};
-use {{workspace_name}}_core::{Config, Core};
-use {{workspace_name}}_utils::setup_logging;
+use {{snake_case workspace_name}}_core::{Config, Core};
+use {{snake_case workspace_name}}_utils::setup_logging;
 
 /// {{workspace_name}} CLI application
 #[derive(Parser)]
@@ -1,11 +1,17 @@
 //! Template engine for processing and generating projects
+//!
+//! File contents, destination paths, file conditions and post-generation
+//! commands are Handlebars templates, rendered with the helpers from
+//! [`super::helpers`].
 
-use super::manifest::{TemplateFile, TemplateManifest};
+use super::helpers;
+use super::manifest::{TemplateFile, TemplateManifest, VariableKind, parse_bool};
 use crate::{Error, Result};
-use regex::Regex;
-use std::collections::HashMap;
+use handlebars::Handlebars;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 /// Template engine for processing templates
 pub struct TemplateEngine {
@@ -17,6 +23,9 @@ pub struct TemplateEngine {
 
     /// Source directory for template files
     source_dir: PathBuf,
+
+    /// Registry that renders every template
+    handlebars: Handlebars<'static>,
 }
 
 /// Variable for template substitution
@@ -29,6 +38,7 @@ impl TemplateEngine {
             variables: HashMap::new(),
             manifest,
             source_dir,
+            handlebars: helpers::registry(),
         }
     }
 
@@ -36,32 +46,14 @@ impl TemplateEngine {
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Validation`] if the value does not match the variable's pattern.
+    /// Returns [`Error::Validation`] if the value does not match the variable's pattern
+    /// or choices, or a boolean variable is given something other than a boolean.
     pub fn set_variable(&mut self, name: String, value: String) -> Result<()> {
-        // Validate against pattern if specified
-        if let Some(var_def) = self.manifest.variables.iter().find(|v| v.name == name)
-            && let Some(pattern) = &var_def.pattern
-        {
-            let regex = Regex::new(pattern)
-                .map_err(|e| Error::validation(format!("Invalid regex pattern: {}", e)))?;
-            if !regex.is_match(&value) {
-                return Err(Error::validation(format!(
-                    "Value '{}' does not match pattern for {}",
-                    value, name
-                )));
-            }
-        }
-
-        self.variables.insert(name.clone(), value.clone());
-
-        // Auto-generate derived variables
-        if name == "project_name" {
-            // Convert hyphenated names to valid Rust identifiers
-            let project_ident = value.replace('-', "_");
-            self.variables
-                .insert("project_ident".to_string(), project_ident);
+        if let Some(var_def) = self.manifest.variables.iter().find(|v| v.name == name) {
+            var_def.validate(&value)?;
         }
 
+        self.variables.insert(name, value);
         Ok(())
     }
 
@@ -79,11 +71,26 @@ impl TemplateEngine {
 
     /// Generate project from template
     ///
+    /// Writes the files with [`Self::write_files`], then runs the
+    /// post-generation commands with [`Self::run_post_generate`].
+    ///
     /// # Errors
     ///
     /// Returns [`Error::Validation`] if required variables are missing or the target
     /// directory already exists. Returns [`Error::Process`] if post-generation commands fail.
     pub fn generate(&self, target_dir: &Path) -> Result<()> {
+        self.write_files(target_dir)?;
+        self.run_post_generate(target_dir)
+    }
+
+    /// Write the project files into the new directory `target_dir`, without
+    /// running post-generation commands
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`] if required variables are missing or the target
+    /// directory already exists, or an error if a file cannot be rendered or written.
+    pub fn write_files(&self, target_dir: &Path) -> Result<()> {
         // Validate all required variables are set
         self.validate_variables()?;
 
@@ -97,14 +104,54 @@ impl TemplateEngine {
         fs::create_dir_all(target_dir)?;
 
         // Process each file
+        let context = self.context();
         for file in &self.manifest.files {
-            self.process_file(file, target_dir)?;
+            self.process_file(file, target_dir, &context)?;
         }
+        Ok(())
+    }
 
-        // Run post-generation commands
-        self.run_post_generate(target_dir)?;
+    /// Render the project files without writing them or running
+    /// post-generation commands
+    ///
+    /// Returns the contents by destination path, with `/` separators.
+    /// Files copied as-is that are not UTF-8 are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`] if required variables are missing, or an
+    /// error if a template file cannot be read or rendered.
+    pub fn render_files(&self) -> Result<BTreeMap<String, String>> {
+        self.validate_variables()?;
 
-        Ok(())
+        let context = self.context();
+        let mut files = BTreeMap::new();
+        for file in &self.manifest.files {
+            if !self.includes(file, &context)? {
+                continue;
+            }
+            let destination = self.destination(file, &context)?;
+            let key = destination
+                .components()
+                .filter_map(|c| match c {
+                    Component::Normal(part) => Some(part.to_string_lossy()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+                .join("/");
+
+            let source_path = self.source_dir.join(&file.source);
+            let content = if file.process {
+                self.render(&fs::read_to_string(&source_path)?, &context)?
+            } else {
+                match String::from_utf8(fs::read(&source_path)?) {
+                    Ok(text) => text,
+                    Err(_) => continue,
+                }
+            };
+            files.insert(key, content);
+        }
+        Ok(files)
     }
 
     /// Validate all required variables are set
@@ -121,12 +168,13 @@ impl TemplateEngine {
     }
 
     /// Process a single template file
-    fn process_file(&self, file: &TemplateFile, target_dir: &Path) -> Result<()> {
-        let source_path = self.source_dir.join(&file.source);
+    fn process_file(&self, file: &TemplateFile, target_dir: &Path, context: &Value) -> Result<()> {
+        if !self.includes(file, context)? {
+            return Ok(());
+        }
 
-        // Substitute variables in destination path
-        let dest_str = self.substitute_variables(&file.destination.to_string_lossy())?;
-        let dest_path = target_dir.join(dest_str);
+        let source_path = self.source_dir.join(&file.source);
+        let dest_path = target_dir.join(self.destination(file, context)?);
 
         // Create parent directories
         if let Some(parent) = dest_path.parent() {
@@ -134,9 +182,9 @@ impl TemplateEngine {
         }
 
         if file.process {
-            // Read and process content
+            // Read and render content
             let content = fs::read_to_string(&source_path)?;
-            let processed = self.substitute_variables(&content)?;
+            let processed = self.render(&content, context)?;
             fs::write(&dest_path, processed)?;
         } else {
             // Copy file as-is
@@ -154,45 +202,84 @@ impl TemplateEngine {
         Ok(())
     }
 
-    /// Substitute variables in text
-    fn substitute_variables(&self, text: &str) -> Result<String> {
-        let mut result = text.to_string();
+    /// Whether the condition of `file`, if any, holds
+    fn includes(&self, file: &TemplateFile, context: &Value) -> Result<bool> {
+        let Some(condition) = &file.condition else {
+            return Ok(true);
+        };
+        let rendered = self.render(&format!("{{{{#if {}}}}}1{{{{/if}}}}", condition), context)?;
+        Ok(!rendered.is_empty())
+    }
 
-        // Build a complete variable map with defaults
-        let mut vars = self.variables.clone();
-        for var_def in &self.manifest.variables {
-            if !vars.contains_key(&var_def.name)
-                && let Some(default) = &var_def.default
-            {
-                vars.insert(var_def.name.clone(), default.clone());
-            }
+    /// Render the destination of `file`, which must stay inside the
+    /// project
+    fn destination(&self, file: &TemplateFile, context: &Value) -> Result<PathBuf> {
+        let rendered = PathBuf::from(self.render(&file.destination.to_string_lossy(), context)?);
+        let escapes = rendered
+            .components()
+            .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir));
+        if rendered.as_os_str().is_empty() || escapes {
+            return Err(Error::template(format!(
+                "Destination '{}' of {} must be a relative path inside the project",
+                rendered.display(),
+                file.source.display()
+            )));
         }
+        Ok(rendered)
+    }
 
-        // Replace {{variable_name}} with values
-        for (name, value) in vars {
-            let pattern = format!("{{{{{}}}}}", name);
-            result = result.replace(&pattern, &value);
-        }
+    /// Render `text` with the template variables
+    fn render(&self, text: &str, context: &Value) -> Result<String> {
+        self.handlebars
+            .render_template(text, context)
+            .map_err(|e| Error::template(format!("Failed to render template: {}", e)))
+    }
 
-        // Check for unsubstituted variables
-        let unsubstituted = Regex::new(r"\{\{[^}]+\}\}")
-            .map_err(|e| Error::validation(format!("Regex error: {}", e)))?;
-        if unsubstituted.is_match(&result)
-            && let Some(m) = unsubstituted.find(&result)
-        {
-            return Err(Error::validation(format!(
-                "Unsubstituted variable found: {}",
-                m.as_str()
-            )));
+    /// Every variable, with defaults filled in, typed by its kind
+    ///
+    /// A list left unset is empty, so `{{#each}}` over it renders nothing.
+    fn context(&self) -> Value {
+        let mut context = Map::new();
+        for (name, value) in &self.variables {
+            context.insert(name.clone(), Value::String(value.clone()));
         }
-
-        Ok(result)
+        for var_def in &self.manifest.variables {
+            let Some(value) = self
+                .variables
+                .get(&var_def.name)
+                .or(var_def.default.as_ref())
+            else {
+                if var_def.kind == VariableKind::List {
+                    context.insert(var_def.name.clone(), Value::Array(Vec::new()));
+                }
+                continue;
+            };
+            let typed = match var_def.kind {
+                VariableKind::String => Value::String(value.clone()),
+                VariableKind::Boolean => Value::Bool(parse_bool(value).unwrap_or(false)),
+                VariableKind::List => Value::Array(
+                    value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|item| !item.is_empty())
+                        .map(|item| Value::String(item.to_string()))
+                        .collect(),
+                ),
+            };
+            context.insert(var_def.name.clone(), typed);
+        }
+        Value::Object(context)
     }
 
-    /// Run post-generation commands
-    fn run_post_generate(&self, target_dir: &Path) -> Result<()> {
+    /// Run the post-generation commands in `target_dir`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Process`] if a command cannot be run or fails.
+    pub fn run_post_generate(&self, target_dir: &Path) -> Result<()> {
+        let context = self.context();
         for command in &self.manifest.post_generate {
-            let processed = self.substitute_variables(command)?;
+            let processed = self.render(command, &context)?;
 
             // Parse command
             let parts: Vec<&str> = processed.split_whitespace().collect();
@@ -237,3 +324,149 @@ impl TemplateEngine {
             .collect()
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::templates::{TemplateKind, TemplateRegistry, TemplateVariable};
+    use tempfile::TempDir;
+
+    fn engine_for(manifest: TemplateManifest, files: &[(&str, &str)]) -> (TemplateEngine, TempDir) {
+        let source = TempDir::new().unwrap();
+        for (path, content) in files {
+            let path = source.path().join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        let engine = TemplateEngine::new(manifest, source.path().to_path_buf());
+        (engine, source)
+    }
+
+    #[test]
+    fn test_generate_with_conditions_loops_and_paths() {
+        let mut manifest = TemplateManifest::new("demo".to_string(), TemplateKind::Custom);
+        manifest.add_variable(TemplateVariable::required(
+            "project_name".to_string(),
+            "Name".to_string(),
+        ));
+        manifest.add_variable(
+            TemplateVariable::optional("use_async".to_string(), "Async".to_string(), "no".into())
+                .with_kind(VariableKind::Boolean),
+        );
+        manifest.add_variable(
+            TemplateVariable::optional("modules".to_string(), "Modules".to_string(), String::new())
+                .with_kind(VariableKind::List),
+        );
+        manifest.add_file(TemplateFile::new(
+            PathBuf::from("lib.rs"),
+            PathBuf::from("src/{{snake_case project_name}}.rs"),
+        ));
+        manifest.add_file(
+            TemplateFile::new(PathBuf::from("runtime.rs"), PathBuf::from("src/runtime.rs"))
+                .when("use_async"),
+        );
+        let lib = "{{#each modules}}mod {{this}};\n{{/each}}\
+                   {{#if use_async}}async {{/if}}fn {{snake_case project_name}}() {}\n";
+        let (mut engine, _source) = engine_for(manifest, &[("lib.rs", lib), ("runtime.rs", "")]);
+        engine
+            .set_variable("project_name".to_string(), "My-App".to_string())
+            .unwrap();
+        engine
+            .set_variable("modules".to_string(), "cli, http".to_string())
+            .unwrap();
+        assert!(
+            engine
+                .set_variable("use_async".to_string(), "maybe".to_string())
+                .is_err()
+        );
+
+        let target = TempDir::new().unwrap();
+        let out = target.path().join("sync");
+        engine.generate(&out).unwrap();
+        assert_eq!(
+            fs::read_to_string(out.join("src/my_app.rs")).unwrap(),
+            "mod cli;\nmod http;\nfn my_app() {}\n"
+        );
+        assert!(!out.join("src/runtime.rs").exists());
+
+        engine
+            .set_variable("use_async".to_string(), "true".to_string())
+            .unwrap();
+        let out = target.path().join("async");
+        engine.generate(&out).unwrap();
+        assert!(out.join("src/runtime.rs").exists());
+
+        let files = engine.render_files().unwrap();
+        assert_eq!(
+            files.keys().collect::<Vec<_>>(),
+            ["src/my_app.rs", "src/runtime.rs"]
+        );
+        assert_eq!(
+            files["src/my_app.rs"],
+            "mod cli;\nmod http;\nasync fn my_app() {}\n"
+        );
+    }
+
+    #[test]
+    fn test_unset_list_renders_empty() {
+        let mut manifest = TemplateManifest::new("demo".to_string(), TemplateKind::Custom);
+        let mut features = TemplateVariable::optional(
+            "features".to_string(),
+            "Features".to_string(),
+            String::new(),
+        )
+        .with_kind(VariableKind::List);
+        features.default = None;
+        manifest.add_variable(features);
+        manifest.add_file(TemplateFile::new(
+            PathBuf::from("lib.rs"),
+            PathBuf::from("src/lib.rs"),
+        ));
+        let lib = "{{#each features}}mod {{this}};\n{{/each}}pub fn run() {}\n";
+        let (engine, _source) = engine_for(manifest, &[("lib.rs", lib)]);
+
+        let files = engine.render_files().unwrap();
+        assert_eq!(files["src/lib.rs"], "pub fn run() {}\n");
+    }
+
+    #[test]
+    fn test_destination_must_stay_in_project() {
+        let mut manifest = TemplateManifest::new("demo".to_string(), TemplateKind::Custom);
+        manifest.add_file(TemplateFile::new(
+            PathBuf::from("a"),
+            PathBuf::from("../{{name}}"),
+        ));
+        let (mut engine, _source) = engine_for(manifest, &[("a", "")]);
+        engine
+            .set_variable("name".to_string(), "x".to_string())
+            .unwrap();
+        let target = TempDir::new().unwrap();
+        assert!(engine.generate(&target.path().join("out")).is_err());
+    }
+
+    #[test]
+    fn test_builtin_templates_render() {
+        let registry = TemplateRegistry::new();
+        for (name, _, _) in registry.list_templates() {
+            let template = registry.get_builtin(name).unwrap();
+            let mut manifest = template.manifest.clone();
+            manifest.post_generate.clear();
+            let files: Vec<(&str, &str)> = template
+                .files
+                .iter()
+                .map(|(path, content)| (path.as_str(), content.as_str()))
+                .collect();
+            let (mut engine, _source) = engine_for(manifest, &files);
+            for var in engine.manifest.variables.clone() {
+                engine
+                    .set_variable(var.name, "demo-project".to_string())
+                    .ok();
+            }
+
+            let target = TempDir::new().unwrap();
+            let result = engine.generate(&target.path().join("out"));
+            assert!(result.is_ok(), "{}: {:?}", name, result);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! Handlebars registry and case-conversion helpers for templates
+
+use handlebars::Handlebars;
+
+/// Helper types generated by `handlebars_helper!`, kept private
+mod case {
+    use handlebars::handlebars_helper;
+
+    handlebars_helper!(Snake: |text: str| super::snake_case(text));
+    handlebars_helper!(Pascal: |text: str| super::pascal_case(text));
+    handlebars_helper!(Kebab: |text: str| super::kebab_case(text));
+}
+
+/// A Handlebars registry set up for rendering project files
+///
+/// Strict mode turns a reference to an unset variable into an error, and
+/// output is not HTML-escaped. `snake_case`, `pascal_case` and
+/// `kebab_case` helpers convert between naming styles, e.g.
+/// `{{snake_case project_name}}` for a crate identifier.
+pub fn registry() -> Handlebars<'static> {
+    let mut handlebars = Handlebars::new();
+    handlebars.set_strict_mode(true);
+    handlebars.register_escape_fn(handlebars::no_escape);
+    handlebars.register_helper("snake_case", Box::new(case::Snake));
+    handlebars.register_helper("pascal_case", Box::new(case::Pascal));
+    handlebars.register_helper("kebab_case", Box::new(case::Kebab));
+    handlebars
+}
+
+/// `my-project` or `MyProject` as `my_project`
+pub fn snake_case(text: &str) -> String {
+    words(text).join("_").to_lowercase()
+}
+
+/// `my-project` or `my_project` as `MyProject`
+pub fn pascal_case(text: &str) -> String {
+    words(text)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first
+                    .to_uppercase()
+                    .chain(chars.flat_map(char::to_lowercase))
+                    .collect()
+            })
+        })
+        .collect()
+}
+
+/// `my_project` or `MyProject` as `my-project`
+pub fn kebab_case(text: &str) -> String {
+    words(text).join("-").to_lowercase()
+}
+
+/// Split `text` into words at separators and case changes
+///
+/// A run of capitals followed by a lowercase letter ends one word before
+/// the last capital, so `HTTPServer` is `HTTP` and `Server`.
+fn words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for part in text.split(|c: char| !c.is_alphanumeric()) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut start = 0;
+        for i in 1..chars.len() {
+            let (prev, current) = (chars[i - 1], chars[i]);
+            let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+            let boundary = current.is_uppercase()
+                && (prev.is_lowercase()
+                    || prev.is_numeric()
+                    || (prev.is_uppercase() && next_is_lower));
+            if boundary {
+                words.push(chars[start..i].iter().collect());
+                start = i;
+            }
+        }
+        if start < chars.len() {
+            words.push(chars[start..].iter().collect());
+        }
+    }
+    words
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_case_conversion() {
+        for name in ["my-web_app", "MyWebApp", "my web app", "myWebApp"] {
+            assert_eq!(snake_case(name), "my_web_app");
+            assert_eq!(pascal_case(name), "MyWebApp");
+            assert_eq!(kebab_case(name), "my-web-app");
+        }
+        assert_eq!(snake_case("HTTPServer2Go"), "http_server2_go");
+    }
+
+    #[test]
+    fn test_registry_renders_helpers_strictly() {
+        let handlebars = registry();
+        let data = json!({ "project_name": "demo-app" });
+        assert_eq!(
+            handlebars
+                .render_template("use {{snake_case project_name}}::<&>;", &data)
+                .unwrap(),
+            "use demo_app::<&>;"
+        );
+        assert!(handlebars.render_template("{{missing}}", &data).is_err());
+    }
+}
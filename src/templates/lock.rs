@@ -0,0 +1,152 @@
+//! Record of the template a project was generated from
+//!
+//! `.ferrous-forge/template.lock` holds the template name and version, the
+//! variable values used, and the files as the template rendered them. The
+//! rendered files are the common ancestor when `template apply` merges a
+//! newer version of the template into the project.
+
+use crate::error::{Error, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+
+/// Location of the lock file, relative to the project root
+pub const LOCK_FILE: &str = ".ferrous-forge/template.lock";
+
+/// Template, variables and rendered files a project was generated from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemplateLock {
+    /// Template name
+    pub template: String,
+    /// Template version
+    pub version: String,
+    /// When the project was generated or last updated from the template
+    pub generated_at: DateTime<Utc>,
+    /// Variable values used to render the template
+    #[serde(default)]
+    pub variables: BTreeMap<String, String>,
+    /// Rendered files by path, with `/` separators
+    ///
+    /// A hand-written lock may leave these out; `template apply` then
+    /// renders the template again when the version is unchanged.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub files: BTreeMap<String, String>,
+}
+
+impl TemplateLock {
+    /// Path of the lock file in `project`
+    pub fn path(project: &Path) -> PathBuf {
+        project.join(LOCK_FILE)
+    }
+
+    /// Read the lock file of `project`, if there is one
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock file exists but cannot be read or parsed,
+    /// or lists a file outside the project.
+    pub fn load(project: &Path) -> Result<Option<Self>> {
+        let path = Self::path(project);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| Error::template(format!("Failed to read {}: {e}", path.display())))?;
+        let lock: Self = toml::from_str(&content)
+            .map_err(|e| Error::template(format!("Invalid {}: {e}", path.display())))?;
+
+        // `template apply` writes and deletes these paths
+        if let Some(file) = lock.files.keys().find(|file| {
+            let file = Path::new(file.as_str());
+            file.as_os_str().is_empty()
+                || file
+                    .components()
+                    .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+        }) {
+            return Err(Error::template(format!(
+                "Invalid {}: file '{file}' must be a relative path inside the project",
+                path.display()
+            )));
+        }
+        Ok(Some(lock))
+    }
+
+    /// Write the lock file of `project`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock cannot be serialized or written.
+    pub fn save(&self, project: &Path) -> Result<()> {
+        let path = Self::path(project);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                Error::template(format!("Failed to create {}: {e}", parent.display()))
+            })?;
+        }
+        let content = toml::to_string_pretty(self)
+            .map_err(|e| Error::template(format!("Failed to serialize template lock: {e}")))?;
+        std::fs::write(&path, content)
+            .map_err(|e| Error::template(format!("Failed to write {}: {e}", path.display())))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_lock_round_trip() {
+        let project = TempDir::new().unwrap();
+        assert!(TemplateLock::load(project.path()).unwrap().is_none());
+
+        let lock = TemplateLock {
+            template: "web-service".to_string(),
+            version: "1.0.0".to_string(),
+            generated_at: Utc::now(),
+            variables: BTreeMap::from([("project_name".to_string(), "api".to_string())]),
+            files: BTreeMap::from([(
+                "src/main.rs".to_string(),
+                "fn main() {\n    println!(\"\"\"\");\n}\n".to_string(),
+            )]),
+        };
+        lock.save(project.path()).unwrap();
+        assert_eq!(TemplateLock::load(project.path()).unwrap(), Some(lock));
+    }
+
+    #[test]
+    fn test_hand_written_lock_needs_no_files() {
+        let project = TempDir::new().unwrap();
+        let path = TemplateLock::path(project.path());
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(
+            &path,
+            "template = \"web-service\"\nversion = \"1.0.0\"\n\
+             generated_at = \"2026-01-05T00:00:00Z\"\n\n[variables]\nproject_name = \"api\"\n",
+        )
+        .unwrap();
+
+        let lock = TemplateLock::load(project.path()).unwrap().unwrap();
+        assert_eq!(lock.variables["project_name"], "api");
+        assert!(lock.files.is_empty());
+    }
+
+    #[test]
+    fn test_lock_files_must_stay_in_project() {
+        let project = TempDir::new().unwrap();
+        let path = TemplateLock::path(project.path());
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let header = "template = \"web-service\"\nversion = \"1.0.0\"\n\
+                      generated_at = \"2026-01-05T00:00:00Z\"\n\n[files]\n";
+
+        for file in ["../outside.rs", "/etc/passwd", "src/../../outside.rs", ""] {
+            std::fs::write(&path, format!("{header}\"{file}\" = \"\"\n")).unwrap();
+            assert!(TemplateLock::load(project.path()).is_err(), "{file}");
+        }
+
+        std::fs::write(&path, format!("{header}\"./src/main.rs\" = \"\"\n")).unwrap();
+        assert!(TemplateLock::load(project.path()).is_ok());
+    }
+}
@@ -69,6 +69,29 @@ impl TemplateKind {
     }
 }
 
+/// Type of value a template variable holds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VariableKind {
+    /// Text, used as is
+    #[default]
+    String,
+    /// `true` or `false` (also `yes`/`no`, `on`/`off`, `1`/`0`), for
+    /// `{{#if}}` blocks
+    Boolean,
+    /// Comma-separated values, for `{{#each}}` blocks
+    List,
+}
+
+/// Parse a boolean variable value
+pub fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "true" | "yes" | "y" | "on" | "1" => Some(true),
+        "false" | "no" | "n" | "off" | "0" => Some(false),
+        _ => None,
+    }
+}
+
 /// Variable that can be substituted in templates
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TemplateVariable {
@@ -86,6 +109,14 @@ pub struct TemplateVariable {
 
     /// Validation regex pattern
     pub pattern: Option<String>,
+
+    /// Type of value the variable holds
+    #[serde(default)]
+    pub kind: VariableKind,
+
+    /// Values the variable is limited to, offered as a list when prompting
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub choices: Vec<String>,
 }
 
 /// File to be generated from template
@@ -94,7 +125,7 @@ pub struct TemplateFile {
     /// Source path in template
     pub source: PathBuf,
 
-    /// Destination path (can include variables)
+    /// Destination path, rendered as a template
     pub destination: PathBuf,
 
     /// Whether to process this file for variable substitution
@@ -102,6 +133,11 @@ pub struct TemplateFile {
 
     /// File permissions (Unix-style)
     pub permissions: Option<u32>,
+
+    /// Handlebars expression that must be truthy for the file to be
+    /// generated, e.g. `use_async` or `(eq database "postgres")`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
 }
 
 impl TemplateManifest {
@@ -177,6 +213,8 @@ impl TemplateVariable {
             default: None,
             required: true,
             pattern: None,
+            kind: VariableKind::String,
+            choices: Vec::new(),
         }
     }
 
@@ -188,6 +226,8 @@ impl TemplateVariable {
             default: Some(default),
             required: false,
             pattern: None,
+            kind: VariableKind::String,
+            choices: Vec::new(),
         }
     }
 
@@ -196,6 +236,60 @@ impl TemplateVariable {
         self.pattern = Some(pattern);
         self
     }
+
+    /// Set the type of value the variable holds
+    pub fn with_kind(mut self, kind: VariableKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Limit the variable to `choices`
+    pub fn with_choices(mut self, choices: Vec<String>) -> Self {
+        self.choices = choices;
+        self
+    }
+
+    /// Check `value` against the pattern, kind and choices of the variable
+    ///
+    /// An optional variable may be left empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::Error::Validation`] describing the first check the
+    /// value fails.
+    pub fn validate(&self, value: &str) -> crate::Result<()> {
+        if value.is_empty() && !self.required {
+            return Ok(());
+        }
+
+        if let Some(pattern) = &self.pattern {
+            let regex = regex::Regex::new(pattern)
+                .map_err(|e| crate::Error::validation(format!("Invalid regex pattern: {}", e)))?;
+            if !regex.is_match(value) {
+                return Err(crate::Error::validation(format!(
+                    "Value '{}' does not match pattern for {} ({})",
+                    value, self.name, pattern
+                )));
+            }
+        }
+
+        if self.kind == VariableKind::Boolean && parse_bool(value).is_none() {
+            return Err(crate::Error::validation(format!(
+                "Value '{}' for {} is not a boolean (true/false)",
+                value, self.name
+            )));
+        }
+
+        if !self.choices.is_empty() && !self.choices.iter().any(|c| c == value) {
+            return Err(crate::Error::validation(format!(
+                "Value '{}' for {} is not one of: {}",
+                value,
+                self.name,
+                self.choices.join(", ")
+            )));
+        }
+        Ok(())
+    }
 }
 
 impl TemplateFile {
@@ -206,6 +300,7 @@ impl TemplateFile {
             destination,
             process: true,
             permissions: None,
+            condition: None,
         }
     }
 
@@ -216,6 +311,7 @@ impl TemplateFile {
             destination,
             process: false,
             permissions: None,
+            condition: None,
         }
     }
 
@@ -224,4 +320,10 @@ impl TemplateFile {
         self.permissions = Some(permissions);
         self
     }
+
+    /// Only generate the file when `condition` is truthy
+    pub fn when(mut self, condition: impl Into<String>) -> Self {
+        self.condition = Some(condition.into());
+        self
+    }
 }
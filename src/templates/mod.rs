@@ -10,6 +10,10 @@
 pub mod builtin;
 /// Template rendering engine with variable substitution.
 pub mod engine;
+/// Handlebars registry and case-conversion helpers.
+pub mod helpers;
+/// Record of the template a project was generated from.
+pub mod lock;
 /// Template manifest and file specification types.
 pub mod manifest;
 /// Template discovery and registration.
@@ -20,7 +24,8 @@ pub mod repository;
 pub mod validation;
 
 pub use engine::{TemplateEngine, TemplateVariable};
-pub use manifest::{TemplateFile, TemplateKind, TemplateManifest};
+pub use lock::TemplateLock;
+pub use manifest::{TemplateFile, TemplateKind, TemplateManifest, VariableKind};
 pub use registry::BuiltinTemplate;
 pub use registry::TemplateRegistry;
 pub use repository::{CachedTemplate, TemplateIndex, TemplateRepository};
@@ -86,6 +86,9 @@ pub async fn validate_template_detailed(
     // Validate template files exist
     validate_template_files(template_dir, manifest, &mut result).await;
 
+    // Validate Handlebars syntax
+    validate_template_syntax(template_dir, manifest, &mut result).await;
+
     // Validate template structure
     validate_template_structure(template_dir, &mut result).await;
 
@@ -176,6 +179,39 @@ async fn validate_template_files(
     }
 }
 
+/// Validate that processed files, destinations and conditions are valid
+/// Handlebars templates
+async fn validate_template_syntax(
+    template_dir: &Path,
+    manifest: &TemplateManifest,
+    result: &mut ValidationResult,
+) {
+    let mut check = |what: String, template: &str| {
+        if let Err(e) = handlebars::Template::compile(template) {
+            result.add_error(format!("Invalid template syntax in {}: {}", what, e));
+        }
+    };
+
+    for file in &manifest.files {
+        let source = file.source.display();
+        check(
+            format!("destination of {}", source),
+            &file.destination.to_string_lossy(),
+        );
+        if let Some(condition) = &file.condition {
+            check(
+                format!("condition of {}", source),
+                &format!("{{{{#if {}}}}}{{{{/if}}}}", condition),
+            );
+        }
+        if file.process
+            && let Ok(content) = tokio::fs::read_to_string(template_dir.join(&file.source)).await
+        {
+            check(source.to_string(), &content);
+        }
+    }
+}
+
 /// Validate template structure
 async fn validate_template_structure(template_dir: &Path, result: &mut ValidationResult) {
     // Check for Cargo.toml in template (for Rust projects)
@@ -3,11 +3,12 @@
 use super::{
     github,
     types::{UpdateChannel, UpdateInfo, UpdateManager},
+    versions::{RetainedBinary, VersionStore, sha256_hex, verify_retained},
 };
 use crate::{Error, Result};
 use console::style;
 use semver::Version;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tokio::fs;
 
 impl UpdateManager {
@@ -64,6 +65,9 @@ impl UpdateManager {
             self.verify_download(&temp_path, expected_hash).await?;
         }
 
+        // Keep the running binary so the update can be rolled back
+        self.retain_current().await?;
+
         // Replace the current binary
         self.replace_binary(&temp_path).await?;
 
@@ -111,10 +115,7 @@ impl UpdateManager {
             .await
             .map_err(|e| Error::io(format!("Failed to read downloaded file: {}", e)))?;
 
-        use sha2::{Digest, Sha256};
-        let mut hasher = Sha256::new();
-        hasher.update(&contents);
-        let actual_hash = format!("{:x}", hasher.finalize());
+        let actual_hash = sha256_hex(&contents);
 
         if actual_hash != expected_hash {
             return Err(Error::security(format!(
@@ -126,42 +127,108 @@ impl UpdateManager {
         Ok(())
     }
 
+    /// Roll back to a retained binary
+    ///
+    /// Verifies the retained copy's checksum, retains the running binary so
+    /// the rollback can itself be undone, then atomically swaps the retained
+    /// binary into place.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the checksum does not match, the running binary
+    /// cannot be retained, or the swap fails.
+    pub async fn rollback(&self, target: &RetainedBinary) -> Result<()> {
+        verify_retained(target).await?;
+
+        // Stage first: retaining the running binary may prune the target
+        let staging_path = self.stage_binary(&target.path).await?;
+        if target.version != self.current_version
+            && let Err(e) = self.retain_current().await
+        {
+            drop(fs::remove_file(&staging_path).await);
+            return Err(e);
+        }
+        self.commit_staged(&staging_path).await?;
+
+        let installed = fs::read(&self.binary_path)
+            .await
+            .map_err(|e| Error::io(format!("Failed to read installed binary: {}", e)))?;
+        if sha256_hex(&installed) != target.sha256 {
+            return Err(Error::security(format!(
+                "Installed binary does not match the retained {} checksum",
+                target.version
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Keep a copy of the running binary for rollback
+    async fn retain_current(&self) -> Result<()> {
+        let mut store = VersionStore::load_default().await?;
+        let entry = store
+            .retain(&self.binary_path, &self.current_version)
+            .await?;
+        println!(
+            "💾 Retained {} for rollback ({})",
+            entry.version,
+            entry.path.display()
+        );
+        Ok(())
+    }
+
     /// Replace current binary with updated version
     async fn replace_binary(&self, temp_path: &PathBuf) -> Result<()> {
-        // Create backup of current binary
-        let backup_path = self.binary_path.with_extension("backup");
-        fs::copy(&self.binary_path, &backup_path)
-            .await
-            .map_err(|e| Error::io(format!("Failed to create backup: {}", e)))?;
+        self.install_binary(temp_path).await?;
 
-        // Replace with new binary
-        fs::copy(temp_path, &self.binary_path).await.map_err(|e| {
-            // Try to restore backup on failure
-            // Attempt to restore backup, ignore errors since we're already in error state
-            drop(std::fs::copy(&backup_path, &self.binary_path));
-            Error::io(format!("Failed to replace binary: {}", e))
-        })?;
+        // Clean up temporary file, ignore errors
+        drop(fs::remove_file(temp_path).await);
+
+        Ok(())
+    }
+
+    /// Atomically install `source` as the current binary
+    ///
+    /// The new binary is staged next to the current one and renamed over it,
+    /// so the binary path never points at a partially written file.
+    async fn install_binary(&self, source: &Path) -> Result<()> {
+        let staging_path = self.stage_binary(source).await?;
+        self.commit_staged(&staging_path).await
+    }
+
+    /// Copy `source` next to the current binary and make it executable
+    async fn stage_binary(&self, source: &Path) -> Result<PathBuf> {
+        let staging_path = self.binary_path.with_extension("staging");
+        fs::copy(source, &staging_path)
+            .await
+            .map_err(|e| Error::io(format!("Failed to stage binary: {}", e)))?;
 
         // Make executable on Unix systems
         #[cfg(unix)]
         {
             use std::os::unix::fs::PermissionsExt;
-            let mut perms = fs::metadata(&self.binary_path)
+            let mut perms = fs::metadata(&staging_path)
                 .await
                 .map_err(|e| Error::io(format!("Failed to get file metadata: {}", e)))?
                 .permissions();
             perms.set_mode(0o755);
-            fs::set_permissions(&self.binary_path, perms)
+            fs::set_permissions(&staging_path, perms)
                 .await
                 .map_err(|e| Error::io(format!("Failed to set permissions: {}", e)))?;
         }
 
-        // Clean up temporary file and backup
-        // Clean up temporary files, ignore errors
-        drop(fs::remove_file(temp_path).await);
-        drop(fs::remove_file(&backup_path).await);
+        Ok(staging_path)
+    }
 
-        Ok(())
+    /// Rename a staged binary over the current one
+    async fn commit_staged(&self, staging_path: &Path) -> Result<()> {
+        fs::rename(staging_path, &self.binary_path)
+            .await
+            .map_err(|e| {
+                // Leave the current binary untouched, ignore cleanup errors
+                drop(std::fs::remove_file(staging_path));
+                Error::io(format!("Failed to replace binary: {}", e))
+            })
     }
 
     /// Interactive update prompt
@@ -8,6 +8,9 @@ pub mod github;
 pub mod manager;
 /// Update data types for channels and release info.
 pub mod types;
+/// Retained previous binaries for rollback.
+pub mod versions;
 
 pub use auto::check_auto_update;
 pub use types::{UpdateChannel, UpdateInfo, UpdateManager};
+pub use versions::{RetainedBinary, VersionStore};
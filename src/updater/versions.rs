@@ -0,0 +1,280 @@
+//! Retained binaries for rollback
+//!
+//! Before an update replaces the running binary, the previous binary is
+//! copied into a versions directory together with its SHA-256 checksum and
+//! install timestamp. The most recent [`DEFAULT_RETAINED_VERSIONS`] copies are
+//! kept; `ferrous-forge rollback` restores one of them.
+
+use crate::{Error, Result};
+use chrono::{DateTime, Utc};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+/// Number of previous binaries kept for rollback
+pub const DEFAULT_RETAINED_VERSIONS: usize = 3;
+
+/// Name of the manifest file inside the versions directory
+const MANIFEST_FILE: &str = "versions.json";
+
+/// A previously installed binary kept for rollback
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RetainedBinary {
+    /// Version of the retained binary
+    pub version: Version,
+    /// SHA-256 of the retained binary (lowercase hex)
+    pub sha256: String,
+    /// When this version was installed
+    pub installed_at: DateTime<Utc>,
+    /// Location of the retained copy
+    pub path: PathBuf,
+}
+
+/// On-disk store of retained binaries, newest first
+#[derive(Debug)]
+pub struct VersionStore {
+    dir: PathBuf,
+    retain: usize,
+    entries: Vec<RetainedBinary>,
+}
+
+impl VersionStore {
+    /// Default versions directory (`<data dir>/ferrous-forge/versions`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user data directory cannot be determined.
+    pub fn default_dir() -> Result<PathBuf> {
+        Ok(dirs::data_dir()
+            .ok_or_else(|| Error::config("Could not determine data directory"))?
+            .join("ferrous-forge")
+            .join("versions"))
+    }
+
+    /// Load the store from the default versions directory
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data directory cannot be determined or the
+    /// manifest exists but cannot be read.
+    pub async fn load_default() -> Result<Self> {
+        Self::load(Self::default_dir()?, DEFAULT_RETAINED_VERSIONS).await
+    }
+
+    /// Load the store from `dir`, keeping at most `retain` binaries
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest exists but cannot be read or parsed.
+    pub async fn load(dir: PathBuf, retain: usize) -> Result<Self> {
+        let manifest = dir.join(MANIFEST_FILE);
+        let entries = if manifest.exists() {
+            let contents = fs::read_to_string(&manifest).await.map_err(|e| {
+                Error::io(format!(
+                    "Failed to read version manifest {}: {}",
+                    manifest.display(),
+                    e
+                ))
+            })?;
+            serde_json::from_str(&contents)?
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self {
+            dir,
+            retain,
+            entries,
+        })
+    }
+
+    /// Retained binaries, newest install first
+    pub fn entries(&self) -> &[RetainedBinary] {
+        &self.entries
+    }
+
+    /// Find the retained binary for `version`
+    pub fn find(&self, version: &Version) -> Option<&RetainedBinary> {
+        self.entries.iter().find(|e| &e.version == version)
+    }
+
+    /// Most recently installed binary whose version differs from `current`
+    pub fn previous(&self, current: &Version) -> Option<&RetainedBinary> {
+        self.entries.iter().find(|e| &e.version != current)
+    }
+
+    /// Copy `binary` into the store as `version` and prune old copies
+    ///
+    /// The install timestamp is taken from the binary's modification time.
+    /// Retaining a version that is already stored replaces the old copy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the binary cannot be read or the copy or manifest
+    /// cannot be written.
+    pub async fn retain(&mut self, binary: &Path, version: &Version) -> Result<RetainedBinary> {
+        fs::create_dir_all(&self.dir).await.map_err(|e| {
+            Error::io(format!(
+                "Failed to create versions directory {}: {}",
+                self.dir.display(),
+                e
+            ))
+        })?;
+
+        let contents = fs::read(binary)
+            .await
+            .map_err(|e| Error::io(format!("Failed to read {}: {}", binary.display(), e)))?;
+        let installed_at = fs::metadata(binary)
+            .await
+            .and_then(|m| m.modified())
+            .map(DateTime::<Utc>::from)
+            .unwrap_or_else(|_| Utc::now());
+
+        let path = self.dir.join(retained_file_name(version));
+        fs::write(&path, &contents)
+            .await
+            .map_err(|e| Error::io(format!("Failed to retain {}: {}", path.display(), e)))?;
+
+        let entry = RetainedBinary {
+            version: version.clone(),
+            sha256: sha256_hex(&contents),
+            installed_at,
+            path,
+        };
+        self.entries.retain(|e| e.version != entry.version);
+        self.entries.insert(0, entry.clone());
+        self.entries
+            .sort_by_key(|e| std::cmp::Reverse(e.installed_at));
+        self.prune().await;
+        self.save().await?;
+
+        Ok(entry)
+    }
+
+    /// Drop retained copies beyond the retention limit
+    async fn prune(&mut self) {
+        if self.entries.len() <= self.retain {
+            return;
+        }
+        for stale in self.entries.split_off(self.retain) {
+            if let Err(e) = fs::remove_file(&stale.path).await {
+                tracing::warn!("Failed to remove {}: {}", stale.path.display(), e);
+            }
+        }
+    }
+
+    /// Write the manifest
+    async fn save(&self) -> Result<()> {
+        let manifest = self.dir.join(MANIFEST_FILE);
+        let contents = serde_json::to_string_pretty(&self.entries)?;
+        fs::write(&manifest, contents).await.map_err(|e| {
+            Error::io(format!(
+                "Failed to write version manifest {}: {}",
+                manifest.display(),
+                e
+            ))
+        })
+    }
+}
+
+/// Check that a retained binary still matches its recorded checksum
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, or a security error if the
+/// checksum does not match.
+pub async fn verify_retained(entry: &RetainedBinary) -> Result<()> {
+    let contents = fs::read(&entry.path)
+        .await
+        .map_err(|e| Error::io(format!("Failed to read {}: {}", entry.path.display(), e)))?;
+    let actual = sha256_hex(&contents);
+    if actual != entry.sha256 {
+        return Err(Error::security(format!(
+            "Checksum mismatch for retained {}. Expected: {}, Got: {}",
+            entry.version, entry.sha256, actual
+        )));
+    }
+    Ok(())
+}
+
+/// Lowercase hex SHA-256 of `bytes`
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+fn retained_file_name(version: &Version) -> String {
+    format!("ferrous-forge-{}{}", version, std::env::consts::EXE_SUFFIX)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    async fn write_binary(dir: &Path, contents: &str) -> PathBuf {
+        let path = dir.join("ferrous-forge");
+        fs::write(&path, contents).await.unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_retain_records_checksum_and_persists() {
+        let temp = TempDir::new().unwrap();
+        let binary = write_binary(temp.path(), "v1 binary").await;
+        let versions = temp.path().join("versions");
+
+        let mut store = VersionStore::load(versions.clone(), 3).await.unwrap();
+        let entry = store.retain(&binary, &Version::new(1, 0, 0)).await.unwrap();
+
+        assert_eq!(entry.sha256, sha256_hex(b"v1 binary"));
+        assert!(entry.path.exists());
+        verify_retained(&entry).await.unwrap();
+
+        let reloaded = VersionStore::load(versions, 3).await.unwrap();
+        assert_eq!(reloaded.entries(), &[entry]);
+    }
+
+    #[tokio::test]
+    async fn test_retain_prunes_oldest_beyond_limit() {
+        let temp = TempDir::new().unwrap();
+        let binary = write_binary(temp.path(), "binary").await;
+        let mut store = VersionStore::load(temp.path().join("versions"), 2)
+            .await
+            .unwrap();
+
+        let oldest = store.retain(&binary, &Version::new(1, 0, 0)).await.unwrap();
+        // Distinct mtimes keep the newest-first ordering deterministic
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        fs::write(&binary, "binary 2").await.unwrap();
+        store.retain(&binary, &Version::new(1, 1, 0)).await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        fs::write(&binary, "binary 3").await.unwrap();
+        store.retain(&binary, &Version::new(1, 2, 0)).await.unwrap();
+
+        let versions: Vec<_> = store.entries().iter().map(|e| e.version.clone()).collect();
+        assert_eq!(versions, vec![Version::new(1, 2, 0), Version::new(1, 1, 0)]);
+        assert!(!oldest.path.exists());
+        assert_eq!(
+            store.previous(&Version::new(1, 2, 0)).unwrap().version,
+            Version::new(1, 1, 0)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verify_detects_tampering() {
+        let temp = TempDir::new().unwrap();
+        let binary = write_binary(temp.path(), "original").await;
+        let mut store = VersionStore::load(temp.path().join("versions"), 3)
+            .await
+            .unwrap();
+        let entry = store.retain(&binary, &Version::new(1, 0, 0)).await.unwrap();
+
+        fs::write(&entry.path, "tampered").await.unwrap();
+        assert!(verify_retained(&entry).await.is_err());
+    }
+}
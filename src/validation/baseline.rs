@@ -0,0 +1,258 @@
+//! Violation baseline for adopting Ferrous Forge in existing projects
+//!
+//! `ferrous-forge validate --write-baseline` records the current violations
+//! in `.ferrous-forge/baseline.json`. Later runs only fail on violations that
+//! are not in the baseline. Entries are fingerprinted by violation type,
+//! file and the whitespace-normalized source line, so code moving up or down
+//! a file does not invalidate them. Suppressions without a justification
+//! are never baselined, so they cannot be silenced for good.
+
+use crate::validation::{Violation, ViolationType};
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Location of the baseline file relative to the project root
+pub const BASELINE_FILE: &str = ".ferrous-forge/baseline.json";
+
+/// Current baseline file format version
+const BASELINE_FORMAT: u32 = 1;
+
+/// A recorded violation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BaselineEntry {
+    /// Stable hash of type, file and normalized snippet
+    pub fingerprint: String,
+    /// Violation type label (see [`crate::validation::ViolationType::label`])
+    pub violation_type: String,
+    /// File relative to the project root
+    pub file: PathBuf,
+    /// Whitespace-normalized source line (empty for whole-file violations)
+    pub snippet: String,
+    /// Message at the time the baseline was written
+    pub message: String,
+}
+
+/// Set of accepted violations for a project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Baseline {
+    /// File format version
+    pub format: u32,
+    /// When the baseline was written
+    pub generated_at: chrono::DateTime<chrono::Utc>,
+    /// Recorded violations
+    pub entries: Vec<BaselineEntry>,
+}
+
+/// Violations split by whether the baseline accepts them
+#[derive(Debug, Default)]
+pub struct BaselineComparison {
+    /// Violations not present in the baseline; these fail validation
+    pub new_violations: Vec<Violation>,
+    /// Violations accepted by the baseline
+    pub baselined: Vec<Violation>,
+    /// Baseline entries with no matching violation (since fixed)
+    pub fixed: Vec<BaselineEntry>,
+}
+
+impl BaselineComparison {
+    /// Treat every violation as new (no baseline present)
+    pub fn without_baseline(violations: Vec<Violation>) -> Self {
+        Self {
+            new_violations: violations,
+            ..Self::default()
+        }
+    }
+}
+
+impl Baseline {
+    /// Path of the baseline file for a project
+    pub fn path(project_root: &Path) -> PathBuf {
+        project_root.join(BASELINE_FILE)
+    }
+
+    /// Record `violations` as the project's baseline, leaving out invalid
+    /// suppressions
+    pub fn from_violations(project_root: &Path, violations: &[Violation]) -> Self {
+        let mut sources = SourceCache::default();
+        let mut entries: Vec<BaselineEntry> = violations
+            .iter()
+            .filter(|v| is_baselinable(&v.violation_type))
+            .map(|v| entry_for(project_root, v, &mut sources))
+            .collect();
+        entries.sort_by(|a, b| {
+            (&a.file, &a.violation_type, &a.fingerprint).cmp(&(
+                &b.file,
+                &b.violation_type,
+                &b.fingerprint,
+            ))
+        });
+
+        Self {
+            format: BASELINE_FORMAT,
+            generated_at: chrono::Utc::now(),
+            entries,
+        }
+    }
+
+    /// Load the project's baseline, if one exists
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the baseline file exists but cannot be read or
+    /// parsed.
+    pub fn load(project_root: &Path) -> Result<Option<Self>> {
+        let path = Self::path(project_root);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|e| Error::validation(format!("Failed to read {}: {}", path.display(), e)))?;
+        let baseline = serde_json::from_str(&contents).map_err(|e| {
+            Error::validation(format!("Invalid baseline {}: {}", path.display(), e))
+        })?;
+        Ok(Some(baseline))
+    }
+
+    /// Write the baseline to `.ferrous-forge/baseline.json`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, project_root: &Path) -> Result<PathBuf> {
+        let path = Self::path(project_root);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, serde_json::to_string_pretty(self)? + "\n")?;
+        Ok(path)
+    }
+
+    /// Split `violations` into new and baselined, and report fixed entries
+    ///
+    /// Each baseline entry accepts at most one violation, so adding a second
+    /// identical `.unwrap()` to a file is still reported.
+    pub fn compare(&self, project_root: &Path, violations: Vec<Violation>) -> BaselineComparison {
+        let mut remaining: HashMap<&str, Vec<&BaselineEntry>> = HashMap::new();
+        for entry in &self.entries {
+            remaining
+                .entry(entry.fingerprint.as_str())
+                .or_default()
+                .push(entry);
+        }
+
+        let mut sources = SourceCache::default();
+        let mut comparison = BaselineComparison::default();
+        for violation in violations {
+            if !is_baselinable(&violation.violation_type) {
+                comparison.new_violations.push(violation);
+                continue;
+            }
+            let fingerprint = entry_for(project_root, &violation, &mut sources).fingerprint;
+            let matched = remaining
+                .get_mut(fingerprint.as_str())
+                .and_then(|entries| entries.pop());
+            if matched.is_some() {
+                comparison.baselined.push(violation);
+            } else {
+                comparison.new_violations.push(violation);
+            }
+        }
+
+        comparison.fixed = remaining
+            .into_values()
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>();
+        comparison
+            .fixed
+            .sort_by(|a, b| (&a.file, &a.violation_type).cmp(&(&b.file, &b.violation_type)));
+        comparison
+    }
+}
+
+/// Build the baseline entry (and fingerprint) for a violation
+fn entry_for(
+    project_root: &Path,
+    violation: &Violation,
+    sources: &mut SourceCache,
+) -> BaselineEntry {
+    let file = violation
+        .file
+        .strip_prefix(project_root)
+        .unwrap_or(&violation.file)
+        .to_path_buf();
+    let violation_type = violation.violation_type.label();
+    let snippet = if violation.line == 0 || is_whole_file(&violation.violation_type) {
+        String::new()
+    } else {
+        sources
+            .line(&violation.file, violation.line)
+            .map(normalize_snippet)
+            .unwrap_or_default()
+    };
+
+    let mut hasher = Sha256::new();
+    hasher.update(violation_type.as_bytes());
+    hasher.update([0]);
+    hasher.update(file.to_string_lossy().replace('\\', "/").as_bytes());
+    hasher.update([0]);
+    hasher.update(snippet.as_bytes());
+
+    BaselineEntry {
+        fingerprint: format!("{:x}", hasher.finalize()),
+        violation_type,
+        file,
+        snippet,
+        message: violation.message.clone(),
+    }
+}
+
+/// Whether a baseline may accept violations of this type
+fn is_baselinable(violation_type: &ViolationType) -> bool {
+    *violation_type != ViolationType::InvalidSuppression
+}
+
+/// Whether violations of this type are about a whole file, so the line
+/// they point at says nothing about which violation it is
+///
+/// `FileTooLarge`, for one, points at the last line of the file.
+fn is_whole_file(violation_type: &ViolationType) -> bool {
+    matches!(
+        violation_type,
+        ViolationType::FileTooLarge
+            | ViolationType::MissingModuleDoc
+            | ViolationType::LockedSetting
+            | ViolationType::WrongEdition
+            | ViolationType::OldRustVersion
+    )
+}
+
+/// Collapse all runs of whitespace so indentation changes keep the fingerprint
+fn normalize_snippet(line: &str) -> String {
+    line.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Lazily read source files, once each
+#[derive(Default)]
+struct SourceCache {
+    files: HashMap<PathBuf, Option<Vec<String>>>,
+}
+
+impl SourceCache {
+    /// 1-based line of a file, if the file is readable and long enough
+    fn line(&mut self, file: &Path, line: usize) -> Option<&str> {
+        self.files
+            .entry(file.to_path_buf())
+            .or_insert_with(|| {
+                std::fs::read_to_string(file)
+                    .ok()
+                    .map(|c| c.lines().map(str::to_string).collect())
+            })
+            .as_ref()?
+            .get(line.checked_sub(1)?)
+            .map(String::as_str)
+    }
+}
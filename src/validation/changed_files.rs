@@ -0,0 +1,69 @@
+//! Files changed in the git working tree
+//!
+//! Drives `validate --changed-only`: the tracked files that differ from
+//! `HEAD` (staged or not) plus untracked files that are not ignored. In a
+//! repository without commits every staged file counts as changed.
+
+use crate::{Error, Result};
+use std::path::{Path, PathBuf};
+
+/// Files under `project_root` that changed since the last commit
+///
+/// Paths are joined onto `project_root` and deleted files are left out.
+///
+/// # Errors
+///
+/// Returns an error if git cannot be run or `project_root` is not inside a
+/// git repository.
+pub async fn changed_files(project_root: &Path) -> Result<Vec<PathBuf>> {
+    let has_head = git(project_root, &["rev-parse", "--verify", "--quiet", "HEAD"])
+        .await
+        .is_ok();
+    let diff: &[&str] = if has_head {
+        &["diff", "--name-only", "--relative", "HEAD"]
+    } else {
+        &["diff", "--name-only", "--relative", "--cached"]
+    };
+
+    let mut files: Vec<PathBuf> = git(project_root, diff)
+        .await?
+        .lines()
+        .chain(
+            git(
+                project_root,
+                &["ls-files", "--others", "--exclude-standard"],
+            )
+            .await?
+            .lines(),
+        )
+        .filter(|line| !line.is_empty())
+        .map(|line| project_root.join(line))
+        .filter(|path| path.exists())
+        .collect();
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+/// Run git in `dir` and return its stdout
+///
+/// # Errors
+///
+/// Returns an error if git cannot be run or exits unsuccessfully.
+pub(crate) async fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = tokio::process::Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .await
+        .map_err(|e| Error::process(format!("Failed to run git: {}", e)))?;
+
+    if !output.status.success() {
+        return Err(Error::process(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
@@ -0,0 +1,352 @@
+//! Compiler diagnostics from cargo's JSON message format
+//!
+//! `cargo build` and `cargo clippy` run with `--message-format=json` print
+//! one JSON object per line on stdout. The `compiler-message` lines carry
+//! rustc's structured diagnostics: level, lint code, primary span and the
+//! suggestions from `help:` children. This module parses those lines into
+//! [`CompilerDiagnostic`]s and converts them into [`Violation`]s, so clippy
+//! and build findings go through the same reports and baselines as the
+//! built-in checks.
+
+use crate::validation::{Severity, Violation, ViolationType};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Level of a compiler diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticLevel {
+    /// Hard error (including lints denied with `-D`)
+    Error,
+    /// Warning
+    Warning,
+    /// Note, help or any other informational level
+    Note,
+}
+
+impl DiagnosticLevel {
+    fn from_rustc(level: &str) -> Self {
+        match level {
+            "error" | "error: internal compiler error" => Self::Error,
+            "warning" => Self::Warning,
+            _ => Self::Note,
+        }
+    }
+}
+
+/// How safe it is to apply a suggestion without review, as rustc reports it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Applicability {
+    /// The suggestion is definitely what the user intended
+    MachineApplicable,
+    /// The suggestion may be what the user intended
+    MaybeIncorrect,
+    /// The suggestion contains placeholders like `(...)`
+    HasPlaceholders,
+    /// Applicability was not reported
+    Unspecified,
+}
+
+impl Applicability {
+    fn from_rustc(applicability: Option<&str>) -> Self {
+        match applicability {
+            Some("MachineApplicable") => Self::MachineApplicable,
+            Some("MaybeIncorrect") => Self::MaybeIncorrect,
+            Some("HasPlaceholders") => Self::HasPlaceholders,
+            _ => Self::Unspecified,
+        }
+    }
+}
+
+/// A replacement the compiler suggests for a span of source
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnosticSuggestion {
+    /// File the replacement applies to, as reported by cargo
+    pub file: PathBuf,
+    /// Byte offset where the replaced text starts
+    pub byte_start: usize,
+    /// Byte offset where the replaced text ends (exclusive)
+    pub byte_end: usize,
+    /// 1-based line where the replaced text starts
+    pub line: usize,
+    /// 1-based column where the replaced text starts
+    pub column: usize,
+    /// Text to put in place of the span
+    pub replacement: String,
+    /// The `help:` message that carried the suggestion
+    pub message: String,
+    /// Whether the suggestion can be applied automatically
+    pub applicability: Applicability,
+}
+
+/// A single rustc or clippy diagnostic
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompilerDiagnostic {
+    /// Severity level
+    pub level: DiagnosticLevel,
+    /// Lint or error code, e.g. `clippy::unwrap_used` or `E0308`
+    pub code: Option<String>,
+    /// Main message
+    pub message: String,
+    /// File of the primary span, as reported by cargo
+    pub file: Option<PathBuf>,
+    /// 1-based line of the primary span
+    pub line: usize,
+    /// 1-based column of the primary span
+    pub column: usize,
+    /// 1-based line where the primary span ends
+    pub end_line: usize,
+    /// 1-based column where the primary span ends
+    pub end_column: usize,
+    /// Suggested replacements from the diagnostic's children
+    pub suggestions: Vec<DiagnosticSuggestion>,
+    /// The diagnostic as rustc would print it
+    pub rendered: Option<String>,
+}
+
+impl CompilerDiagnostic {
+    /// Suggestions that are safe to apply without review
+    pub fn machine_applicable(&self) -> impl Iterator<Item = &DiagnosticSuggestion> {
+        self.suggestions
+            .iter()
+            .filter(|s| s.applicability == Applicability::MachineApplicable)
+    }
+
+    /// One-line `file:line:col: [code] message` summary
+    pub fn summary(&self) -> String {
+        let location = match &self.file {
+            Some(file) => format!("{}:{}:{}: ", file.display(), self.line, self.column),
+            None => String::new(),
+        };
+        match &self.code {
+            Some(code) => format!("{}[{}] {}", location, code, self.message),
+            None => format!("{}{}", location, self.message),
+        }
+    }
+
+    /// Convert the diagnostic into a [`Violation`]
+    ///
+    /// Relative span paths are joined onto `project_root`. Diagnostics
+    /// without a primary span and notes have no location to report and
+    /// return `None`.
+    pub fn to_violation(&self, project_root: &Path) -> Option<Violation> {
+        let file = self.file.as_ref()?;
+        let severity = match self.level {
+            DiagnosticLevel::Error => Severity::Error,
+            DiagnosticLevel::Warning => Severity::Warning,
+            DiagnosticLevel::Note => return None,
+        };
+        let code = self.code.clone().unwrap_or_else(|| "rustc".to_string());
+        let mut message = match &self.code {
+            Some(code) => format!("{}: {}", code, self.message),
+            None => self.message.clone(),
+        };
+        if let Some(suggestion) = self.machine_applicable().next() {
+            message.push_str(&format!(" (auto-fixable: {})", suggestion.message));
+        }
+
+        Some(
+            Violation::new(
+                ViolationType::CompilerDiagnostic { code },
+                project_root.join(file),
+                self.line,
+                message,
+                severity,
+            )
+            .with_column(self.column),
+        )
+    }
+}
+
+/// Parse the stdout of a cargo command run with `--message-format=json`
+///
+/// Lines that are not compiler messages (artifacts, build-script output,
+/// plain text) are skipped, as are the "aborting due to" and "warnings
+/// emitted" summaries. Cargo reports a diagnostic once per target that
+/// contains the file, so duplicates are dropped.
+pub fn parse_cargo_messages(stdout: &str) -> Vec<CompilerDiagnostic> {
+    let mut diagnostics: Vec<CompilerDiagnostic> = Vec::new();
+
+    for line in stdout.lines().filter(|l| l.starts_with('{')) {
+        let Ok(message) = serde_json::from_str::<CargoMessage>(line) else {
+            continue;
+        };
+        if message.reason != "compiler-message" {
+            continue;
+        }
+        let Some(raw) = message.message else {
+            continue;
+        };
+        if let Some(diagnostic) = convert(raw)
+            && !diagnostics.contains(&diagnostic)
+        {
+            diagnostics.push(diagnostic);
+        }
+    }
+
+    diagnostics
+}
+
+/// Convert diagnostics into violations; see [`CompilerDiagnostic::to_violation`]
+pub fn to_violations(diagnostics: &[CompilerDiagnostic], project_root: &Path) -> Vec<Violation> {
+    diagnostics
+        .iter()
+        .filter_map(|d| d.to_violation(project_root))
+        .collect()
+}
+
+/// Machine-applicable edits from `diagnostics`, grouped by file and sorted
+/// by position
+///
+/// A diagnostic's edits are taken together or not at all: when any of them
+/// overlaps an edit already taken from an earlier diagnostic, the whole
+/// diagnostic is left for a later run.
+pub fn machine_applicable_edits(
+    diagnostics: &[CompilerDiagnostic],
+) -> BTreeMap<PathBuf, Vec<DiagnosticSuggestion>> {
+    let mut edits: BTreeMap<PathBuf, Vec<DiagnosticSuggestion>> = BTreeMap::new();
+
+    for diagnostic in diagnostics {
+        let candidates: Vec<&DiagnosticSuggestion> = diagnostic.machine_applicable().collect();
+        let overlaps = candidates.iter().any(|candidate| {
+            edits.get(&candidate.file).is_some_and(|taken| {
+                taken.iter().any(|edit| {
+                    (candidate.byte_start < edit.byte_end && edit.byte_start < candidate.byte_end)
+                        || candidate.byte_start == edit.byte_start
+                })
+            })
+        });
+        if overlaps {
+            continue;
+        }
+        for candidate in candidates {
+            edits
+                .entry(candidate.file.clone())
+                .or_default()
+                .push(candidate.clone());
+        }
+    }
+
+    for file_edits in edits.values_mut() {
+        file_edits.sort_by_key(|e| (e.byte_start, e.byte_end));
+        file_edits.dedup();
+    }
+    edits
+}
+
+/// Apply sorted, non-overlapping edits to `source`
+///
+/// Returns `None` when an edit does not fit the source, which means the
+/// file changed since the diagnostics were produced.
+pub fn apply_edits(source: &str, edits: &[DiagnosticSuggestion]) -> Option<String> {
+    let mut fixed = String::with_capacity(source.len());
+    let mut cursor = 0;
+    for edit in edits {
+        if edit.byte_start < cursor
+            || !source.is_char_boundary(edit.byte_start)
+            || !source.is_char_boundary(edit.byte_end)
+        {
+            return None;
+        }
+        fixed.push_str(source.get(cursor..edit.byte_start)?);
+        fixed.push_str(&edit.replacement);
+        cursor = edit.byte_end;
+    }
+    fixed.push_str(source.get(cursor..)?);
+    Some(fixed)
+}
+
+fn convert(raw: RawDiagnostic) -> Option<CompilerDiagnostic> {
+    let level = DiagnosticLevel::from_rustc(&raw.level);
+    let primary = raw.spans.iter().find(|s| s.is_primary);
+    if primary.is_none() && raw.code.is_none() && is_summary(&raw.message) {
+        return None;
+    }
+
+    let mut suggestions = Vec::new();
+    collect_suggestions(&raw, &raw.message, &mut suggestions);
+
+    Some(CompilerDiagnostic {
+        level,
+        code: raw.code.map(|c| c.code),
+        message: raw.message,
+        file: primary.map(|s| PathBuf::from(&s.file_name)),
+        line: primary.map_or(0, |s| s.line_start),
+        column: primary.map_or(0, |s| s.column_start),
+        end_line: primary.map_or(0, |s| s.line_end),
+        end_column: primary.map_or(0, |s| s.column_end),
+        suggestions,
+        rendered: raw.rendered,
+    })
+}
+
+fn collect_suggestions(
+    diagnostic: &RawDiagnostic,
+    message: &str,
+    suggestions: &mut Vec<DiagnosticSuggestion>,
+) {
+    for span in &diagnostic.spans {
+        if let Some(replacement) = &span.suggested_replacement {
+            suggestions.push(DiagnosticSuggestion {
+                file: PathBuf::from(&span.file_name),
+                byte_start: span.byte_start,
+                byte_end: span.byte_end,
+                line: span.line_start,
+                column: span.column_start,
+                replacement: replacement.clone(),
+                message: message.to_string(),
+                applicability: Applicability::from_rustc(span.suggestion_applicability.as_deref()),
+            });
+        }
+    }
+    for child in &diagnostic.children {
+        collect_suggestions(child, &child.message, suggestions);
+    }
+}
+
+fn is_summary(message: &str) -> bool {
+    message.starts_with("aborting due to")
+        || message.ends_with("warning emitted")
+        || message.ends_with("warnings emitted")
+        || (message.contains("generated") && message.contains("warning"))
+}
+
+/// The subset of a cargo JSON message that we read
+#[derive(Deserialize)]
+struct CargoMessage {
+    reason: String,
+    message: Option<RawDiagnostic>,
+}
+
+#[derive(Deserialize)]
+struct RawDiagnostic {
+    message: String,
+    code: Option<RawCode>,
+    level: String,
+    #[serde(default)]
+    spans: Vec<RawSpan>,
+    #[serde(default)]
+    children: Vec<RawDiagnostic>,
+    rendered: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RawCode {
+    code: String,
+}
+
+#[derive(Deserialize)]
+struct RawSpan {
+    file_name: String,
+    byte_start: usize,
+    byte_end: usize,
+    line_start: usize,
+    line_end: usize,
+    column_start: usize,
+    column_end: usize,
+    is_primary: bool,
+    suggested_replacement: Option<String>,
+    suggestion_applicability: Option<String>,
+}
@@ -1,16 +1,36 @@
 //! Rust code validation engine
 
+/// Accepted-violation baseline for adopting standards in existing projects.
+pub mod baseline;
+/// Files changed in the git working tree, for `--changed-only`.
+pub mod changed_files;
+/// Compiler diagnostics parsed from cargo's JSON message format.
+pub mod diagnostics;
+/// Path-scoped `exclude` and `[[overrides]]` settings.
+pub mod path_scope;
+/// Per-rule severity overrides from the `[rules]` config table.
+pub mod rules;
 /// Rust source code and Cargo.toml validator.
 pub mod rust_validator;
+/// Inline `// ferrous-forge: allow(...)` suppression comments.
+pub mod suppression;
 /// Version consistency validation (SSoT enforcement)
 pub mod version_consistency;
 /// Violation types, severity levels, and diagnostics.
 pub mod violation;
+/// Cargo workspace members and `-p` / `--workspace` selection.
+pub mod workspace;
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests;
 
+pub use baseline::{Baseline, BaselineComparison, BaselineEntry};
+pub use diagnostics::{Applicability, CompilerDiagnostic, DiagnosticLevel, DiagnosticSuggestion};
+pub use path_scope::{FileLimits, PathScope};
+pub use rules::apply_rules;
 pub use rust_validator::{ClippyResult, RustValidator};
+pub use suppression::{Suppression, SuppressionScope};
 pub use version_consistency::{VersionConsistencyValidator, VersionValidationResult};
-pub use violation::{Severity, Violation, ViolationType};
+pub use violation::{RuleLevel, SYSTEM_FILE, Severity, Violation, ViolationType};
+pub use workspace::{PackageSelection, Workspace, WorkspaceMember};
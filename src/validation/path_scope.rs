@@ -0,0 +1,231 @@
+//! Path-scoped configuration
+//!
+//! The `exclude` list and `[[overrides]]` blocks of the configuration hold
+//! glob patterns relative to the project root. [`PathScope`] compiles them
+//! once per validator and answers, for any file, whether it is validated at
+//! all and which limits and rule levels apply to it.
+
+use crate::config::hierarchy::PartialConfig;
+use crate::config::{Config, PathOverride};
+use crate::validation::rules::apply_levels;
+use crate::validation::{RuleLevel, Violation};
+use crate::{Error, Result};
+use glob::{MatchOptions, Pattern};
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+
+/// `*` and `?` stay within one path component; `**` crosses them
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Size limits in effect for one file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileLimits {
+    /// File size limit in lines
+    pub max_file_lines: usize,
+    /// Function size limit in lines
+    pub max_function_lines: usize,
+}
+
+/// Glob patterns and the directory they are relative to
+#[derive(Debug, Clone)]
+struct Globs {
+    root: PathBuf,
+    patterns: Vec<Pattern>,
+}
+
+impl Globs {
+    /// Compile `patterns` relative to `root`, naming `key` in errors
+    fn new(root: &Path, patterns: &[String], key: &str) -> Result<Self> {
+        let patterns = patterns
+            .iter()
+            .map(|p| {
+                Pattern::new(p.trim_end_matches('/'))
+                    .map_err(|e| Error::config(format!("Invalid glob '{}' in {}: {}", p, key, e)))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            root: root.to_path_buf(),
+            patterns,
+        })
+    }
+
+    /// Whether a pattern matches `path` or one of its parent directories
+    fn matches(&self, path: &Path) -> bool {
+        if self.patterns.is_empty() {
+            return false;
+        }
+        let Some(rel) = relative(&self.root, path) else {
+            return false;
+        };
+        let mut candidate = rel.as_str();
+        loop {
+            if self
+                .patterns
+                .iter()
+                .any(|p| p.matches_with(candidate, MATCH_OPTIONS))
+            {
+                return true;
+            }
+            match candidate.rfind('/') {
+                Some(end) => candidate = &candidate[..end],
+                None => return false,
+            }
+        }
+    }
+}
+
+/// One compiled `[[overrides]]` block
+#[derive(Debug, Clone)]
+struct ScopedOverride {
+    paths: Globs,
+    max_file_lines: Option<usize>,
+    max_function_lines: Option<usize>,
+    rules: BTreeMap<String, RuleLevel>,
+}
+
+impl ScopedOverride {
+    fn new(root: &Path, settings: &PathOverride) -> Result<Self> {
+        Ok(Self {
+            paths: Globs::new(root, &settings.paths, "overrides.paths")?,
+            max_file_lines: settings.max_file_lines,
+            max_function_lines: settings.max_function_lines,
+            rules: settings.rules.clone(),
+        })
+    }
+}
+
+/// Compiled `exclude` and `[[overrides]]` settings for one project
+#[derive(Debug, Clone)]
+pub struct PathScope {
+    exclude: Vec<Globs>,
+    overrides: Vec<ScopedOverride>,
+    limits: FileLimits,
+    rules: BTreeMap<String, RuleLevel>,
+}
+
+impl PathScope {
+    /// Compile the path-scoped settings of `config` for the project at `root`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an `exclude` or `overrides` pattern is not a
+    /// valid glob.
+    pub fn new(root: &Path, config: &Config) -> Result<Self> {
+        Ok(Self {
+            exclude: vec![Globs::new(root, &config.exclude, "exclude")?],
+            overrides: config
+                .overrides
+                .iter()
+                .map(|o| ScopedOverride::new(root, o))
+                .collect::<Result<_>>()?,
+            limits: FileLimits {
+                max_file_lines: config.max_file_lines,
+                max_function_lines: config.max_function_lines,
+            },
+            rules: config.rules.clone(),
+        })
+    }
+
+    /// Add a workspace member's own config on top of this scope
+    ///
+    /// The member's globs are relative to `member_root`; its limits and
+    /// rule levels replace the inherited ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a pattern of the member config is not a valid
+    /// glob.
+    pub fn with_layer(mut self, member_root: &Path, layer: &PartialConfig) -> Result<Self> {
+        if let Some(exclude) = &layer.exclude {
+            self.exclude
+                .push(Globs::new(member_root, exclude, "exclude")?);
+        }
+        for settings in layer.overrides.iter().flatten() {
+            self.overrides
+                .push(ScopedOverride::new(member_root, settings)?);
+        }
+        if let Some(max) = layer.max_file_lines {
+            self.limits.max_file_lines = max;
+        }
+        if let Some(max) = layer.max_function_lines {
+            self.limits.max_function_lines = max;
+        }
+        if let Some(rules) = &layer.rules {
+            self.rules.extend(rules.clone());
+        }
+        Ok(self)
+    }
+
+    /// Whether `path`, or a directory containing it, is excluded
+    pub fn is_excluded(&self, path: &Path) -> bool {
+        self.exclude.iter().any(|globs| globs.matches(path))
+    }
+
+    /// Size limits for `path`, after every matching override
+    pub fn limits(&self, path: &Path) -> FileLimits {
+        let mut limits = self.limits;
+        for scoped in self.matching(path) {
+            if let Some(max) = scoped.max_file_lines {
+                limits.max_file_lines = max;
+            }
+            if let Some(max) = scoped.max_function_lines {
+                limits.max_function_lines = max;
+            }
+        }
+        limits
+    }
+
+    /// The configured level of `violation`'s rule in its file, if any
+    ///
+    /// The last matching override that sets the rule wins, then the
+    /// project-wide `[rules]` table.
+    pub fn rule_level(&self, violation: &Violation) -> Option<RuleLevel> {
+        self.rule_level_at(&violation.file, &violation.violation_type.rule_name())
+    }
+
+    /// The configured level of `rule` in the file at `path`, if any; see
+    /// [`Self::rule_level`]
+    pub fn rule_level_at(&self, path: &Path, rule: &str) -> Option<RuleLevel> {
+        self.matching(path)
+            .filter_map(|scoped| scoped.rules.get(rule))
+            .last()
+            .or_else(|| self.rules.get(rule))
+            .copied()
+    }
+
+    /// Drop violations in excluded files and apply each file's rule levels
+    pub fn apply(&self, violations: Vec<Violation>) -> Vec<Violation> {
+        let violations = violations
+            .into_iter()
+            .filter(|v| !self.is_excluded(&v.file))
+            .collect();
+        if self.rules.is_empty() && self.overrides.is_empty() {
+            return violations;
+        }
+        apply_levels(violations, |v| self.rule_level(v))
+    }
+
+    /// Overrides matching `path`, in configuration order
+    fn matching<'a>(&'a self, path: &'a Path) -> impl Iterator<Item = &'a ScopedOverride> {
+        self.overrides
+            .iter()
+            .filter(move |scoped| scoped.paths.matches(path))
+    }
+}
+
+/// `path` relative to `root`, with `/` separators, if it lies below `root`
+fn relative(root: &Path, path: &Path) -> Option<String> {
+    let rel = path.strip_prefix(root).ok()?;
+    let parts: Vec<_> = rel
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(part) => Some(part.to_string_lossy()),
+            _ => None,
+        })
+        .collect();
+    (!parts.is_empty()).then(|| parts.join("/"))
+}
@@ -0,0 +1,54 @@
+//! Per-rule severity overrides
+//!
+//! The `[rules]` table of the configuration maps a rule name — the
+//! snake_case name of a built-in violation type, a custom rule's name, or
+//! a compiler lint code such as `clippy::needless_return` — to a
+//! [`RuleLevel`]. Overrides are applied after the checks run, so every
+//! check keeps reporting its own default severity.
+//!
+//! Locked-setting violations (edition, rust-version and config locks) are
+//! not affected: they are governed by the lock system and always stay
+//! errors.
+
+use crate::validation::{RuleLevel, Violation};
+use std::collections::BTreeMap;
+
+/// Apply `rules` to `violations`
+///
+/// Violations of rules set to `off` are dropped; the others take the
+/// severity of their rule's level. Rules without an entry keep their
+/// default severity.
+pub fn apply_rules(
+    violations: Vec<Violation>,
+    rules: &BTreeMap<String, RuleLevel>,
+) -> Vec<Violation> {
+    if rules.is_empty() {
+        return violations;
+    }
+    apply_levels(violations, |violation| {
+        rules.get(&violation.violation_type.rule_name()).copied()
+    })
+}
+
+/// Apply the level `level_of` returns for each violation, as
+/// [`apply_rules`] does; `None` keeps the default severity
+pub fn apply_levels(
+    violations: Vec<Violation>,
+    level_of: impl Fn(&Violation) -> Option<RuleLevel>,
+) -> Vec<Violation> {
+    violations
+        .into_iter()
+        .filter_map(|mut violation| {
+            if violation.is_locked_setting() {
+                return Some(violation);
+            }
+            match level_of(&violation) {
+                Some(level) => {
+                    violation.severity = level.severity()?;
+                    Some(violation)
+                }
+                None => Some(violation),
+            }
+        })
+        .collect()
+}
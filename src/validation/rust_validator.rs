@@ -2,24 +2,87 @@
 
 /// Individual file validation checks for Rust source and Cargo.toml.
 pub mod file_checks;
-/// Regex-based validation patterns for code style enforcement.
+/// Compiled line patterns for user-defined custom rules.
 pub mod patterns;
 
-use crate::config::Config;
-use crate::validation::{Severity, Violation, ViolationType};
+use crate::config::{Config, HierarchicalConfig, PartialConfig};
+use crate::performance::cache::{
+    CachedFileResult, PersistentValidationCache, config_hash, sha256_hex,
+};
+use crate::performance::parallel::ParallelValidator;
+use crate::performance::{PerformanceConfig, PerformanceMetrics, PerformanceMonitor};
+use crate::validation::diagnostics::{self, CompilerDiagnostic, DiagnosticLevel};
+use crate::validation::rules::apply_levels;
+use crate::validation::{
+    Baseline, BaselineComparison, PackageSelection, PathScope, RuleLevel, SYSTEM_FILE, Severity,
+    Suppression, Violation, ViolationType, Workspace,
+};
 use crate::{Error, Result};
-use file_checks::{validate_cargo_toml_full, validate_rust_file};
+use file_checks::{validate_cargo_toml_full, validate_rust_source};
 use patterns::ValidationPatterns;
 use regex::Regex;
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
 
 /// Result from running clippy
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct ClippyResult {
     /// Whether clippy ran successfully
     pub success: bool,
     /// Output from clippy command
     pub output: String,
+    /// Structured diagnostics parsed from cargo's JSON messages
+    #[serde(default)]
+    pub diagnostics: Vec<CompilerDiagnostic>,
+}
+
+impl ClippyResult {
+    /// Clippy diagnostics as violations; see [`CompilerDiagnostic::to_violation`]
+    pub fn violations(&self, project_root: &Path) -> Vec<Violation> {
+        diagnostics::to_violations(&self.diagnostics, project_root)
+    }
+
+    /// Clippy diagnostics as violations, leaving out those that repeat a
+    /// violation in `reported`
+    ///
+    /// A diagnostic repeats a violation when its lint is one of the
+    /// violation type's [`covered_lints`](ViolationType::covered_lints) and
+    /// the violation's line lies in the diagnostic's span in the same file,
+    /// e.g. `clippy::unwrap_used` on an `.unwrap()` the AST check flagged.
+    pub fn violations_beyond(&self, project_root: &Path, reported: &[Violation]) -> Vec<Violation> {
+        let repeated = |d: &&CompilerDiagnostic| {
+            let (Some(code), Some(file)) = (&d.code, &d.file) else {
+                return false;
+            };
+            let file = project_root.join(file);
+            reported.iter().any(|v| {
+                v.file == file
+                    && (d.line..=d.end_line.max(d.line)).contains(&v.line)
+                    && v.violation_type.covered_lints().contains(&code.as_str())
+            })
+        };
+        self.diagnostics
+            .iter()
+            .filter(|d| !repeated(d))
+            .filter_map(|d| d.to_violation(project_root))
+            .collect()
+    }
+
+    /// Whether every reason clippy failed is reported as a violation
+    ///
+    /// False when clippy failed without a located diagnostic, e.g. because
+    /// the manifest does not parse.
+    pub fn failure_is_reported(&self) -> bool {
+        let mut errors = self
+            .diagnostics
+            .iter()
+            .filter(|d| d.level == DiagnosticLevel::Error)
+            .peekable();
+        self.success || (errors.peek().is_some() && errors.all(|d| d.file.is_some()))
+    }
 }
 
 /// Core Rust validator
@@ -30,6 +93,51 @@ pub struct RustValidator {
     patterns: ValidationPatterns,
     /// Active configuration (drives limits and locked settings)
     config: Config,
+    /// Compiled `exclude` and `[[overrides]]` settings
+    scope: PathScope,
+    /// Reuse per-file results from `.ferrous-forge/cache/validation.json`
+    use_cache: bool,
+    /// Only validate these Rust files (`--changed-only`)
+    only_files: Option<Vec<PathBuf>>,
+    /// Worker threads for file validation (0 = one per CPU)
+    jobs: usize,
+    /// Per-file and per-check timings, when enabled
+    monitor: Option<Arc<PerformanceMonitor>>,
+    /// Workspace members, innermost first
+    members: Vec<MemberContext>,
+    /// Members to validate (`-p` / `--exclude`); `None` validates all
+    selection: Option<BTreeSet<String>>,
+    /// `--config` and environment settings, which outrank member configs
+    command_overrides: PartialConfig,
+}
+
+/// A workspace member and the settings its files are validated with
+#[derive(Clone)]
+struct MemberContext {
+    name: String,
+    root: PathBuf,
+    /// The member's config, when it has its own `.ferrous-forge/config.toml`
+    config: Option<Config>,
+    scope: PathScope,
+    /// Patterns for the member's custom rules, when its config sets them
+    patterns: Option<ValidationPatterns>,
+}
+
+/// What validating one source file needs, owned so validation can run on
+/// a blocking thread
+struct SourceChecker {
+    scope: PathScope,
+    patterns: ValidationPatterns,
+    members: Vec<MemberContext>,
+    monitor: Option<Arc<PerformanceMonitor>>,
+}
+
+/// Outcome of validating one source file
+struct FileOutcome {
+    sha256: String,
+    violations: Vec<Violation>,
+    suppressions: Vec<Suppression>,
+    from_cache: bool,
 }
 
 impl RustValidator {
@@ -46,16 +154,162 @@ impl RustValidator {
     ///
     /// # Errors
     ///
-    /// Returns an error if the validation regex patterns fail to compile.
+    /// Returns an error if the validation regex patterns, any enabled
+    /// custom rule pattern, or an `exclude` or `overrides` glob fail to
+    /// compile.
     pub fn with_config(project_root: PathBuf, config: Config) -> Result<Self> {
-        let patterns = ValidationPatterns::new()?;
+        let patterns = ValidationPatterns::with_custom_rules(&config.custom_rules)?;
+        let scope = PathScope::new(&project_root, &config)?;
+        let jobs = config.validation.jobs.unwrap_or(0);
         Ok(Self {
             project_root,
             patterns,
             config,
+            scope,
+            use_cache: true,
+            only_files: None,
+            jobs,
+            monitor: None,
+            members: Vec::new(),
+            selection: None,
+            command_overrides: PartialConfig::default(),
         })
     }
 
+    /// Create a validator with the configuration resolved by `hierarchy`
+    ///
+    /// Workspace member configs added later with [`Self::with_workspace`]
+    /// stay beneath the hierarchy's `--config` and environment layers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the validation patterns, or an `exclude` or
+    /// `overrides` glob, fail to compile.
+    pub fn with_hierarchy(project_root: PathBuf, hierarchy: &HierarchicalConfig) -> Result<Self> {
+        let mut validator = Self::with_config(project_root, hierarchy.merged())?;
+        validator.command_overrides = hierarchy.command_overrides();
+        Ok(validator)
+    }
+
+    /// Create a validator for `project_root` with the configuration
+    /// resolved for that project
+    ///
+    /// When the project is part of a cargo workspace, each member's files
+    /// are validated with that member's config; see
+    /// [`Self::with_workspace`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration, a member configuration or
+    /// the validation patterns cannot be loaded.
+    pub async fn for_project(project_root: PathBuf) -> Result<Self> {
+        let hierarchy = HierarchicalConfig::load_for_project(&project_root).await?;
+        let validator = Self::with_hierarchy(project_root, &hierarchy)?;
+        match Workspace::load(&validator.project_root).await {
+            Ok(workspace) => {
+                validator
+                    .with_workspace(&workspace, &PackageSelection::default())
+                    .await
+            }
+            Err(e) => {
+                tracing::debug!("Validating without workspace members: {}", e);
+                Ok(validator)
+            }
+        }
+    }
+
+    /// Attribute files to the members of `workspace` and validate only the
+    /// members chosen by `selection`
+    ///
+    /// A member's own `.ferrous-forge/config.toml` is layered over the
+    /// validator's configuration for that member's files, except for
+    /// settings from `--config` or the environment; its globs are relative
+    /// to the member directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `selection` names a package outside the
+    /// workspace, or a member config cannot be loaded or compiled.
+    pub async fn with_workspace(
+        mut self,
+        workspace: &Workspace,
+        selection: &PackageSelection,
+    ) -> Result<Self> {
+        self.selection = workspace.select(selection)?;
+        let mut members = Vec::new();
+        for member in &workspace.members {
+            let mut context = MemberContext {
+                name: member.name.clone(),
+                root: member.root.clone(),
+                config: None,
+                scope: self.scope.clone(),
+                patterns: None,
+            };
+            if let Some(layer) =
+                HierarchicalConfig::load_member(&member.root, &self.project_root).await?
+            {
+                let layer = layer.shadowed_by(&self.command_overrides);
+                let config = layer.apply_to(&self.config);
+                if layer.custom_rules.is_some() {
+                    context.patterns =
+                        Some(ValidationPatterns::with_custom_rules(&config.custom_rules)?);
+                }
+                context.scope = context.scope.with_layer(&member.root, &layer)?;
+                context.config = Some(config);
+            }
+            members.push(context);
+        }
+        // Innermost first, so the first member containing a file owns it
+        members.sort_by_key(|m| Reverse(m.root.components().count()));
+        self.members = members;
+        Ok(self)
+    }
+
+    /// Validate files on `jobs` worker threads (0 = one per CPU),
+    /// overriding `validation.jobs` from the configuration
+    pub fn with_jobs(mut self, jobs: usize) -> Self {
+        self.jobs = jobs;
+        self
+    }
+
+    /// Record how long each file and each check takes; see [`Self::timings`]
+    pub fn with_timings(mut self) -> Self {
+        self.monitor = Some(Arc::new(PerformanceMonitor::new(
+            PerformanceConfig::default(),
+        )));
+        self
+    }
+
+    /// Recorded timings, keyed `file:<path>` and `check:<name>`
+    ///
+    /// Empty unless [`Self::with_timings`] was used.
+    pub fn timings(&self) -> Vec<(String, PerformanceMetrics)> {
+        self.monitor
+            .as_ref()
+            .map(|m| m.all_metrics())
+            .unwrap_or_default()
+    }
+
+    /// Disable the on-disk validation cache
+    pub fn without_cache(mut self) -> Self {
+        self.use_cache = false;
+        self
+    }
+
+    /// Restrict Rust source validation to `files`
+    ///
+    /// `Cargo.toml` and version consistency checks still cover the whole
+    /// project, since they are cheap and not tied to a single source file.
+    pub fn only_files(mut self, files: Vec<PathBuf>) -> Self {
+        self.only_files = Some(files);
+        self
+    }
+
+    /// Root directory of the project being validated
+    pub fn project_root(&self) -> &Path {
+        &self.project_root
+    }
+
     /// Get reference to validation patterns
     pub fn patterns(&self) -> &ValidationPatterns {
         &self.patterns
@@ -75,28 +329,22 @@ impl RustValidator {
 
         // Find and validate all Cargo.toml files
         let cargo_files = self.find_cargo_files().await?;
-        for cargo_file in cargo_files {
+        for cargo_file in cargo_files
+            .into_iter()
+            .filter(|file| self.is_validated(file))
+        {
+            let config = self.config_for(&cargo_file);
             validate_cargo_toml_full(
                 &cargo_file,
                 &mut violations,
-                &self.config.required_edition,
-                &self.config.required_rust_version,
+                &config.required_edition,
+                &config.required_rust_version,
             )
             .await?;
         }
 
         // Find and validate all Rust source files
-        let rust_files = self.find_rust_files().await?;
-        for rust_file in rust_files {
-            validate_rust_file(
-                &rust_file,
-                &mut violations,
-                &self.patterns,
-                self.config.max_file_lines,
-                self.config.max_function_lines,
-            )
-            .await?;
-        }
+        self.validate_sources(&mut violations).await?;
 
         // Check version consistency (SSoT)
         if self
@@ -113,7 +361,227 @@ impl RustValidator {
             violations.extend(version_result.violations);
         }
 
-        Ok(violations)
+        Ok(self.apply_rules(violations))
+    }
+
+    /// Apply the configured `exclude` list, member selection and rule
+    /// levels, including those of `[[overrides]]`, to violations found
+    /// outside [`Self::validate_project`], such as clippy's
+    pub fn apply_rules(&self, violations: Vec<Violation>) -> Vec<Violation> {
+        let violations = violations
+            .into_iter()
+            .filter(|v| self.is_validated(&v.file))
+            .collect();
+        apply_levels(violations, |v| self.rule_level(v))
+    }
+
+    /// Add the violations of `clippy_result` that no AST check already
+    /// reported to `violations`, with [`Self::apply_rules`] applied
+    pub fn add_clippy_violations(
+        &self,
+        violations: &mut Vec<Violation>,
+        clippy_result: &ClippyResult,
+    ) {
+        let clippy_violations = clippy_result.violations_beyond(&self.project_root, violations);
+        violations.extend(self.apply_rules(clippy_violations));
+    }
+
+    /// The level configured for `violation`'s rule in its file, if any
+    pub fn rule_level(&self, violation: &Violation) -> Option<RuleLevel> {
+        self.scope_for(&violation.file).rule_level(violation)
+    }
+
+    /// Name of the workspace member that `path` belongs to, if any
+    pub fn member_name(&self, path: &Path) -> Option<&str> {
+        self.member_for(path).map(|m| m.name.as_str())
+    }
+
+    /// Workspace members being validated, as name and directory
+    pub fn selected_members(&self) -> Vec<(&str, &Path)> {
+        let mut members: Vec<_> = self
+            .members
+            .iter()
+            .filter(|m| self.selection.as_ref().is_none_or(|s| s.contains(&m.name)))
+            .map(|m| (m.name.as_str(), m.root.as_path()))
+            .collect();
+        members.sort();
+        members
+    }
+
+    fn member_for(&self, path: &Path) -> Option<&MemberContext> {
+        self.members.iter().find(|m| path.starts_with(&m.root))
+    }
+
+    fn scope_for(&self, path: &Path) -> &PathScope {
+        self.member_for(path).map_or(&self.scope, |m| &m.scope)
+    }
+
+    /// Configuration in effect for `path`, including its member's layer
+    pub fn config_for(&self, path: &Path) -> &Config {
+        self.member_for(path)
+            .and_then(|m| m.config.as_ref())
+            .unwrap_or(&self.config)
+    }
+
+    /// Whether `path` is in a selected member (or outside every member)
+    /// and not excluded
+    fn is_validated(&self, path: &Path) -> bool {
+        let selected = match (&self.selection, self.member_for(path)) {
+            (Some(selection), Some(member)) => selection.contains(&member.name),
+            _ => true,
+        };
+        selected && !self.scope_for(path).is_excluded(path)
+    }
+
+    /// Hash of every configuration that shaped cached results
+    fn cache_config_hash(&self) -> String {
+        let mut key = config_hash(&self.config);
+        let member_configs: Vec<_> = self
+            .members
+            .iter()
+            .filter_map(|m| Some((&m.name, m.config.as_ref()?)))
+            .collect();
+        if member_configs.is_empty() {
+            return key;
+        }
+        for (name, config) in member_configs {
+            key.push_str(name);
+            key.push_str(&config_hash(config));
+        }
+        sha256_hex(key.as_bytes())
+    }
+
+    /// List every inline suppression in the project with its usage count
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Rust sources cannot be read.
+    pub async fn list_suppressions(&self) -> Result<Vec<Suppression>> {
+        self.validate_sources(&mut Vec::new()).await
+    }
+
+    /// Validate every Rust source file, returning their suppressions
+    ///
+    /// Files are validated in parallel; results are merged in path order so
+    /// output is the same for any number of jobs. Files whose contents are
+    /// unchanged since the last run reuse their cached result.
+    async fn validate_sources(&self, violations: &mut Vec<Violation>) -> Result<Vec<Suppression>> {
+        let mut rust_files = self.find_rust_files().await?;
+        rust_files.retain(|file| self.is_validated(file));
+        rust_files.sort();
+        let mut cache = self.use_cache.then(|| {
+            PersistentValidationCache::load_with_hash(&self.project_root, &self.cache_config_hash())
+        });
+        match &self.only_files {
+            Some(only) => {
+                let only: HashSet<&PathBuf> = only.iter().collect();
+                rust_files.retain(|file| only.contains(file));
+            }
+            None => {
+                if let Some(cache) = &mut cache {
+                    cache.retain_files(&rust_files);
+                }
+            }
+        }
+
+        // The rayon pool blocks until every file is done, so keep it off the
+        // async runtime's worker threads
+        let checker = SourceChecker {
+            scope: self.scope.clone(),
+            patterns: self.patterns.clone(),
+            members: self.members.clone(),
+            monitor: self.monitor.clone(),
+        };
+        let jobs = self.jobs;
+        let (rust_files, mut cache, outcomes) = tokio::task::spawn_blocking(move || {
+            let parallel = ParallelValidator::new(jobs)?;
+            let outcomes = parallel.map_files(&rust_files, |file| {
+                let start = Instant::now();
+                let outcome = checker.validate(file, cache.as_ref());
+                if let Some(monitor) = &checker.monitor {
+                    monitor.record(&format!("file:{}", file.display()), start.elapsed(), 1);
+                }
+                outcome
+            });
+            Ok::<_, Error>((rust_files, cache, outcomes))
+        })
+        .await
+        .map_err(|e| Error::process(format!("Task join error: {}", e)))??;
+
+        let mut suppressions = Vec::new();
+        for (rust_file, outcome) in rust_files.into_iter().zip(outcomes) {
+            let outcome = outcome?;
+            if let Some(cache) = cache.as_mut().filter(|_| !outcome.from_cache) {
+                cache.insert(
+                    rust_file,
+                    CachedFileResult {
+                        sha256: outcome.sha256,
+                        violations: outcome.violations.clone(),
+                        suppressions: outcome.suppressions.clone(),
+                    },
+                );
+            }
+            violations.extend(outcome.violations);
+            suppressions.extend(outcome.suppressions);
+        }
+
+        if let Some(cache) = &mut cache {
+            let stats = cache.stats();
+            tracing::debug!(
+                "Validation cache: {} hits, {} misses",
+                stats.hits,
+                stats.misses
+            );
+            // The cache is an optimization; failing to persist it is not fatal
+            if let Err(e) = cache.save() {
+                tracing::warn!("{}", e);
+            }
+        }
+        Ok(suppressions)
+    }
+
+    /// Validate the project and split violations against the baseline
+    ///
+    /// When `.ferrous-forge/baseline.json` exists, only violations missing
+    /// from it are returned as new. Without a baseline, every violation is
+    /// new.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation fails or the baseline file is
+    /// unreadable.
+    pub async fn validate_with_baseline(&self) -> Result<BaselineComparison> {
+        let violations = self.validate_project().await?;
+        self.compare_with_baseline(violations)
+    }
+
+    /// Split already collected violations (e.g. project violations plus
+    /// clippy's) by the project's baseline
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the baseline exists but cannot be read or parsed.
+    pub fn compare_with_baseline(
+        &self,
+        mut violations: Vec<Violation>,
+    ) -> Result<BaselineComparison> {
+        if let Some(only) = &self.only_files {
+            violations
+                .retain(|v| v.file.extension().is_none_or(|e| e != "rs") || only.contains(&v.file));
+        }
+        let Some(baseline) = Baseline::load(&self.project_root)? else {
+            return Ok(BaselineComparison::without_baseline(violations));
+        };
+
+        let mut comparison = baseline.compare(&self.project_root, violations);
+        // Entries in files that were not validated are not fixed, just unchecked
+        if let Some(only) = &self.only_files {
+            comparison.fixed.retain(|entry| {
+                entry.file.extension().is_none_or(|e| e != "rs")
+                    || only.contains(&self.project_root.join(&entry.file))
+            });
+        }
+        Ok(comparison)
     }
 
     /// Generate a human-readable report from violations
@@ -123,10 +591,17 @@ impl RustValidator {
                 .to_string();
         }
 
-        let mut report = format!(
-            "❌ Found {} violations of Ferrous Forge standards:\n\n",
-            violations.len()
-        );
+        let mut report = if violations.iter().any(|v| v.severity.is_blocking()) {
+            format!(
+                "❌ Found {} violations of Ferrous Forge standards:\n\n",
+                violations.len()
+            )
+        } else {
+            format!(
+                "⚠️  Found {} non-blocking findings (warnings and info):\n\n",
+                violations.len()
+            )
+        };
 
         let grouped_violations = self.group_violations_by_type(violations);
         self.add_violation_sections(&mut report, grouped_violations);
@@ -156,9 +631,14 @@ impl RustValidator {
         grouped_violations: std::collections::HashMap<&ViolationType, Vec<&Violation>>,
     ) {
         for (violation_type, violations) in grouped_violations {
-            let type_name = format!("{:?}", violation_type)
-                .to_uppercase()
-                .replace('_', " ");
+            // Lint codes and custom rule names are shown as written
+            let type_name = match violation_type {
+                ViolationType::CompilerDiagnostic { code } => {
+                    format!("COMPILER DIAGNOSTIC ({})", code)
+                }
+                ViolationType::CustomRule { name } => format!("CUSTOM RULE ({})", name),
+                other => other.label().to_uppercase().replace('_', " "),
+            };
 
             report.push_str(&format!(
                 "🚨 {} ({} violations):\n",
@@ -174,11 +654,16 @@ impl RustValidator {
     /// Add individual violation details to the report
     fn add_violation_details(&self, report: &mut String, violations: &[&Violation]) {
         for violation in violations.iter().take(10) {
+            let tag = match violation.severity {
+                Severity::Error => "",
+                Severity::Warning => " (warning)",
+                Severity::Info => " (info)",
+            };
             report.push_str(&format!(
-                "  {}:{} - {}\n",
-                violation.file.display(),
-                violation.line + 1,
-                violation.message
+                "  {} - {}{}\n",
+                violation.location(),
+                violation.message,
+                tag
             ));
         }
 
@@ -194,9 +679,9 @@ impl RustValidator {
     /// Returns an error if the clippy command fails to execute.
     pub async fn run_clippy(&self) -> Result<ClippyResult> {
         let output = tokio::process::Command::new("cargo")
-            .args(&[
-                "clippy",
-                "--all-features",
+            .args(["clippy", "--all-features", "--message-format=json"])
+            .args(self.clippy_package_args())
+            .args([
                 "--",
                 "-D",
                 "warnings",
@@ -216,13 +701,40 @@ impl RustValidator {
             .await
             .map_err(|e| Error::process(format!("Failed to run clippy: {}", e)))?;
 
+        let diagnostics =
+            diagnostics::parse_cargo_messages(&String::from_utf8_lossy(&output.stdout));
+        let rendered: String = diagnostics
+            .iter()
+            .filter_map(|d| d.rendered.as_deref())
+            .collect();
+
         Ok(ClippyResult {
             success: output.status.success(),
-            output: String::from_utf8_lossy(&output.stdout).to_string()
-                + &String::from_utf8_lossy(&output.stderr),
+            output: rendered + &String::from_utf8_lossy(&output.stderr),
+            diagnostics,
         })
     }
 
+    /// Package flags so clippy lints the members whose files are validated
+    fn clippy_package_args(&self) -> Vec<&str> {
+        if let Some(selection) = &self.selection {
+            return selection
+                .iter()
+                .flat_map(|name| ["--package", name.as_str()])
+                .collect();
+        }
+        let members_below_root = self
+            .members
+            .iter()
+            .filter(|m| m.root.starts_with(&self.project_root))
+            .count();
+        if members_below_root > 1 {
+            vec!["--workspace"]
+        } else {
+            Vec::new()
+        }
+    }
+
     async fn check_rust_version(&self, violations: &mut Vec<Violation>) -> Result<()> {
         let output = tokio::process::Command::new("rustc")
             .arg("--version")
@@ -245,8 +757,9 @@ impl RustValidator {
             if major < 1 || (major == 1 && minor < min_minor) {
                 violations.push(Violation {
                     violation_type: ViolationType::OldRustVersion,
-                    file: PathBuf::from("<system>"),
+                    file: PathBuf::from(SYSTEM_FILE),
                     line: 0,
+                    column: 0,
                     message: format!(
                         "Rust version {}.{} is too old. Minimum required: {}",
                         major, minor, self.config.required_rust_version
@@ -257,8 +770,9 @@ impl RustValidator {
         } else {
             violations.push(Violation {
                 violation_type: ViolationType::OldRustVersion,
-                file: PathBuf::from("<system>"),
+                file: PathBuf::from(SYSTEM_FILE),
                 line: 0,
+                column: 0,
                 message: "Could not parse Rust version".to_string(),
                 severity: Severity::Error,
             });
@@ -300,12 +814,55 @@ impl RustValidator {
     }
 }
 
+impl SourceChecker {
+    /// Validate one source file, or reuse its cached result
+    fn validate(
+        &self,
+        rust_file: &Path,
+        cache: Option<&PersistentValidationCache>,
+    ) -> Result<FileOutcome> {
+        let content = std::fs::read_to_string(rust_file)?;
+        let sha256 = sha256_hex(content.as_bytes());
+        if let Some(cached) = cache.and_then(|c| c.get(rust_file, &sha256)) {
+            return Ok(FileOutcome {
+                sha256,
+                violations: cached.violations,
+                suppressions: cached.suppressions,
+                from_cache: true,
+            });
+        }
+
+        let member = self.members.iter().find(|m| rust_file.starts_with(&m.root));
+        let limits = member.map_or(&self.scope, |m| &m.scope).limits(rust_file);
+        let patterns = member
+            .and_then(|m| m.patterns.as_ref())
+            .unwrap_or(&self.patterns);
+        let mut violations = Vec::new();
+        let suppressions = validate_rust_source(
+            rust_file,
+            &content,
+            &mut violations,
+            patterns,
+            limits.max_file_lines,
+            limits.max_function_lines,
+            self.monitor.as_deref(),
+        )?;
+        Ok(FileOutcome {
+            sha256,
+            violations,
+            suppressions,
+            from_cache: false,
+        })
+    }
+}
+
 /// Directories that should never be scanned for Rust sources.
 const SKIP_DIRS: &[&str] = &[
     "target",
     "node_modules",
     ".git",
     ".claude",
+    ".ferrous-forge",
     ".next",
     "dist",
     "build",
@@ -378,4 +935,4 @@ fn collect_cargo_files_recursive(path: &Path, cargo_files: &mut Vec<PathBuf>) ->
 }
 
 // Re-export for backwards compatibility
-pub use patterns::is_in_string_literal;
+pub use patterns::{is_in_string_literal, is_offset_in_string_literal};
@@ -1,21 +1,26 @@
 //! File-level validation checks
 
+mod ast_validation;
 mod cargo_validation;
+mod custom_rule_validation;
 mod doc_validation;
-mod pattern_validation;
 mod size_validation;
 mod test_utils;
 
+use ast_validation::validate_ast;
 use cargo_validation::validate_cargo_toml_content;
+use custom_rule_validation::validate_custom_rules;
 use doc_validation::{validate_cargo_doc_config, validate_doc_presence};
-use pattern_validation::validate_patterns;
 use size_validation::validate_file_size;
-use test_utils::is_test_file;
+use test_utils::is_non_production_file;
 
 use super::patterns::ValidationPatterns;
 use crate::Result;
+use crate::performance::PerformanceMonitor;
 use crate::validation::Violation;
+use crate::validation::suppression::{Suppression, apply_suppressions, parse_suppressions};
 use std::path::Path;
+use std::time::Instant;
 use tokio::fs;
 
 // Re-export for tests
@@ -23,6 +28,10 @@ pub use cargo_validation::validate_cargo_toml;
 
 /// Validates a Rust source file for standards compliance
 ///
+/// Violations allowed by justified `// ferrous-forge: allow(...)` comments
+/// are dropped, and problems with the comments themselves are reported.
+/// Returns the file's suppressions with their usage counts.
+///
 /// # Errors
 ///
 /// Returns an error if the file cannot be read or a validation check fails.
@@ -32,22 +41,82 @@ pub async fn validate_rust_file(
     patterns: &ValidationPatterns,
     max_file_lines: usize,
     max_function_lines: usize,
-) -> Result<()> {
+) -> Result<Vec<Suppression>> {
     let content = fs::read_to_string(rust_file).await?;
-    let lines: Vec<&str> = content.lines().collect();
+    validate_rust_source(
+        rust_file,
+        &content,
+        violations,
+        patterns,
+        max_file_lines,
+        max_function_lines,
+        None,
+    )
+}
 
-    let _ = is_test_file(rust_file); // retained for potential future use
+/// Validates already-read Rust source; see [`validate_rust_file`]
+///
+/// When a `monitor` is given, the time spent in each check is recorded
+/// under `check:<name>`.
+///
+/// # Errors
+///
+/// Returns an error if a validation check fails.
+pub fn validate_rust_source(
+    rust_file: &Path,
+    content: &str,
+    violations: &mut Vec<Violation>,
+    patterns: &ValidationPatterns,
+    max_file_lines: usize,
+    max_function_lines: usize,
+    monitor: Option<&PerformanceMonitor>,
+) -> Result<Vec<Suppression>> {
+    let lines: Vec<&str> = content.lines().collect();
+    let (mut suppressions, mut file_violations) = timed(monitor, "suppressions", || {
+        parse_suppressions(rust_file, content)
+    });
 
     // Validate file size (config-driven)
-    validate_file_size(rust_file, &lines, violations, max_file_lines)?;
+    timed(monitor, "file_size", || {
+        validate_file_size(rust_file, &lines, &mut file_violations, max_file_lines)
+    })?;
 
-    // Validate code patterns (function size, underscore bandaid)
-    validate_patterns(rust_file, &lines, patterns, violations, max_function_lines)?;
+    // Validate code structure (unwrap/expect, function size, underscore bandaid)
+    timed(monitor, "ast", || {
+        validate_ast(
+            rust_file,
+            content,
+            is_non_production_file(rust_file),
+            &mut file_violations,
+            max_function_lines,
+        )
+    });
 
     // Validate documentation presence for module roots
-    validate_doc_presence(rust_file, &lines, violations)?;
+    timed(monitor, "doc_presence", || {
+        validate_doc_presence(rust_file, &lines, &mut file_violations)
+    })?;
 
-    Ok(())
+    // Apply user-defined rules from config
+    timed(monitor, "custom_rules", || {
+        validate_custom_rules(rust_file, &lines, patterns, &mut file_violations)
+    });
+
+    // Drop violations allowed by inline suppressions
+    violations.extend(apply_suppressions(&mut suppressions, file_violations));
+
+    Ok(suppressions)
+}
+
+/// Run one check, recording its duration when timings are enabled
+fn timed<T>(monitor: Option<&PerformanceMonitor>, check: &str, f: impl FnOnce() -> T) -> T {
+    let Some(monitor) = monitor else {
+        return f();
+    };
+    let start = Instant::now();
+    let result = f();
+    monitor.record(&format!("check:{}", check), start.elapsed(), 1);
+    result
 }
 
 /// Validates a `Cargo.toml` file: edition/version locks + doc config
@@ -0,0 +1,302 @@
+//! Syntax-tree based code validation
+//!
+//! Parses each Rust file with `syn` and walks it with a `syn::visit::Visit`
+//! pass, so checks see real structure instead of individual lines:
+//! - `.unwrap()` / `.expect(..)` calls outside test code, including calls
+//!   split across lines and calls inside macro arguments
+//! - Underscore bandaid parameters (`_unused: T`) and `let _ = ..` discards
+//! - Function size (config-driven, measured from `fn` to the closing brace)
+//!
+//! Test scoping is structural: items under `#[cfg(test)]`, `#[test]`-style
+//! attributes, or `#[allow(clippy::unwrap_used)]` / `expect_used` are exempt
+//! from the unwrap/expect check. Every violation carries a 1-based line and
+//! column taken from the token span.
+
+use crate::validation::{Severity, Violation, ViolationType};
+use proc_macro2::Span;
+use std::path::Path;
+use syn::punctuated::Punctuated;
+use syn::visit::{self, Visit};
+use syn::{Attribute, Expr, FnArg, Pat, Signature, Token};
+
+/// Validate a parsed Rust file for structural code patterns
+///
+/// Files that fail to parse are skipped; the compiler reports those errors.
+pub fn validate_ast(
+    rust_file: &Path,
+    content: &str,
+    is_test_file: bool,
+    violations: &mut Vec<Violation>,
+    max_function_lines: usize,
+) {
+    let syntax = match syn::parse_file(content) {
+        Ok(syntax) => syntax,
+        Err(e) => {
+            tracing::debug!("Skipping AST checks for {}: {}", rust_file.display(), e);
+            return;
+        }
+    };
+
+    let mut visitor = AstValidator {
+        rust_file,
+        violations,
+        max_function_lines,
+        scope: Scope::default(),
+        in_trait_impl: false,
+    };
+    visitor.scope.test |= is_test_file;
+    visitor.scope.apply(&syntax.attrs);
+    visitor.visit_file(&syntax);
+}
+
+/// Attribute-derived exemptions inherited by nested items
+#[derive(Debug, Clone, Copy, Default)]
+struct Scope {
+    /// Inside test-only code (`#[cfg(test)]`, `#[test]`, or a test file)
+    test: bool,
+    /// `clippy::unwrap_used` is allowed for this item
+    allow_unwrap: bool,
+    /// `clippy::expect_used` is allowed for this item
+    allow_expect: bool,
+}
+
+impl Scope {
+    /// Narrow the scope with an item's attributes
+    fn apply(&mut self, attrs: &[Attribute]) {
+        for attr in attrs {
+            if is_test_attr(attr) {
+                self.test = true;
+            }
+            if is_lint_allow(attr) {
+                let tokens = attr_tokens(attr);
+                self.allow_unwrap |= tokens.contains("unwrap_used");
+                self.allow_expect |= tokens.contains("expect_used");
+            }
+        }
+    }
+}
+
+/// Visitor collecting structural violations for one file
+struct AstValidator<'a> {
+    rust_file: &'a Path,
+    violations: &'a mut Vec<Violation>,
+    max_function_lines: usize,
+    scope: Scope,
+    in_trait_impl: bool,
+}
+
+impl AstValidator<'_> {
+    /// Run `f` with the scope narrowed by `attrs`, restoring it afterwards
+    fn scoped(&mut self, attrs: &[Attribute], f: impl FnOnce(&mut Self)) {
+        let saved = self.scope;
+        self.scope.apply(attrs);
+        f(self);
+        self.scope = saved;
+    }
+
+    fn push(&mut self, violation_type: ViolationType, span: Span, message: String) {
+        let start = span.start();
+        self.violations.push(Violation {
+            violation_type,
+            file: self.rust_file.to_path_buf(),
+            line: start.line,
+            column: start.column + 1,
+            message,
+            severity: Severity::Error,
+        });
+    }
+
+    /// Check a function's length and parameters
+    fn check_function(&mut self, sig: &Signature, body: &syn::Block) {
+        let start = sig.fn_token.span.start().line;
+        let end = body.brace_token.span.close().end().line;
+        let func_lines = end.saturating_sub(start) + 1;
+        if func_lines > self.max_function_lines {
+            self.push(
+                ViolationType::FunctionTooLarge,
+                sig.fn_token.span,
+                format!(
+                    "Function `{}` has {} lines, maximum allowed is {}",
+                    sig.ident, func_lines, self.max_function_lines
+                ),
+            );
+        }
+
+        // Trait implementations must keep the trait's signature, so an
+        // underscore-prefixed parameter there is not a design bandaid.
+        if self.scope.test || self.in_trait_impl {
+            return;
+        }
+        for input in &sig.inputs {
+            if let FnArg::Typed(pat_type) = input
+                && let Pat::Ident(pat_ident) = pat_type.pat.as_ref()
+                && is_underscore_ident(&pat_ident.ident)
+            {
+                self.push(
+                    ViolationType::UnderscoreBandaid,
+                    pat_ident.ident.span(),
+                    format!(
+                        "BANNED: Underscore parameter ({}) - \
+                         fix the design instead of hiding warnings",
+                        pat_ident.ident
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Visit the expressions inside a macro invocation when they parse as a
+    /// comma-separated expression list (`println!`, `vec!`, `assert!`, ...)
+    fn visit_macro_args(&mut self, mac: &syn::Macro) {
+        let parser = Punctuated::<Expr, Token![,]>::parse_terminated;
+        if let Ok(args) = mac.parse_body_with(parser) {
+            for expr in &args {
+                self.visit_expr(expr);
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for AstValidator<'_> {
+    fn visit_item_fn(&mut self, node: &'ast syn::ItemFn) {
+        self.scoped(&node.attrs, |v| {
+            let saved = v.in_trait_impl;
+            v.in_trait_impl = false;
+            v.check_function(&node.sig, &node.block);
+            visit::visit_item_fn(v, node);
+            v.in_trait_impl = saved;
+        });
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast syn::ImplItemFn) {
+        self.scoped(&node.attrs, |v| {
+            v.check_function(&node.sig, &node.block);
+            visit::visit_impl_item_fn(v, node);
+        });
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'ast syn::TraitItemFn) {
+        self.scoped(&node.attrs, |v| {
+            if let Some(body) = &node.default {
+                // Trait declarations define the signature, so check them
+                let saved = v.in_trait_impl;
+                v.in_trait_impl = false;
+                v.check_function(&node.sig, body);
+                v.in_trait_impl = saved;
+            }
+            visit::visit_trait_item_fn(v, node);
+        });
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast syn::ItemImpl) {
+        self.scoped(&node.attrs, |v| {
+            let saved = v.in_trait_impl;
+            v.in_trait_impl = node.trait_.is_some();
+            visit::visit_item_impl(v, node);
+            v.in_trait_impl = saved;
+        });
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast syn::ItemMod) {
+        self.scoped(&node.attrs, |v| visit::visit_item_mod(v, node));
+    }
+
+    fn visit_item_trait(&mut self, node: &'ast syn::ItemTrait) {
+        self.scoped(&node.attrs, |v| visit::visit_item_trait(v, node));
+    }
+
+    fn visit_item_const(&mut self, node: &'ast syn::ItemConst) {
+        self.scoped(&node.attrs, |v| visit::visit_item_const(v, node));
+    }
+
+    fn visit_item_static(&mut self, node: &'ast syn::ItemStatic) {
+        self.scoped(&node.attrs, |v| visit::visit_item_static(v, node));
+    }
+
+    fn visit_stmt(&mut self, node: &'ast syn::Stmt) {
+        if let syn::Stmt::Local(local) = node {
+            self.scoped(&local.attrs, |v| {
+                if !v.scope.test && local.init.is_some() && matches!(local.pat, Pat::Wild(_)) {
+                    v.push(
+                        ViolationType::UnderscoreBandaid,
+                        local.let_token.span,
+                        "BANNED: `let _ =` discards a value - \
+                         handle the result explicitly instead of hiding it"
+                            .to_string(),
+                    );
+                }
+                visit::visit_stmt(v, node);
+            });
+            return;
+        }
+        visit::visit_stmt(self, node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'ast syn::ExprMethodCall) {
+        self.scoped(&node.attrs, |v| {
+            let method = node.method.to_string();
+            let flagged = match method.as_str() {
+                "unwrap" => node.args.is_empty() && !v.scope.allow_unwrap,
+                "expect" => node.args.len() == 1 && !v.scope.allow_expect,
+                _ => false,
+            };
+            if flagged && !v.scope.test {
+                v.push(
+                    ViolationType::UnwrapInProduction,
+                    node.method.span(),
+                    format!(
+                        "BANNED: `.{}()` in production code - \
+                         propagate the error with `?` or handle it explicitly",
+                        method
+                    ),
+                );
+            }
+            visit::visit_expr_method_call(v, node);
+        });
+    }
+
+    fn visit_expr_closure(&mut self, node: &'ast syn::ExprClosure) {
+        self.scoped(&node.attrs, |v| visit::visit_expr_closure(v, node));
+    }
+
+    fn visit_macro(&mut self, node: &'ast syn::Macro) {
+        self.visit_macro_args(node);
+        visit::visit_macro(self, node);
+    }
+}
+
+/// Returns true for `_name` identifiers (but not a bare `_` or `__`)
+fn is_underscore_ident(ident: &syn::Ident) -> bool {
+    let name = ident.to_string();
+    let name = name.strip_prefix("r#").unwrap_or(&name);
+    name.len() > 1 && name.starts_with('_') && !name[1..].starts_with('_')
+}
+
+/// Returns true for `#[cfg(test)]` and test-harness attributes
+fn is_test_attr(attr: &Attribute) -> bool {
+    let path = attr.path();
+    if path.is_ident("cfg") {
+        let tokens = attr_tokens(attr);
+        return !tokens.contains("not")
+            && tokens
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .any(|word| word == "test");
+    }
+
+    path.segments
+        .last()
+        .is_some_and(|seg| matches!(seg.ident.to_string().as_str(), "test" | "rstest" | "bench"))
+}
+
+/// Returns true for `#[allow(..)]` and `#[expect(..)]` lint attributes
+fn is_lint_allow(attr: &Attribute) -> bool {
+    attr.path().is_ident("allow") || attr.path().is_ident("expect")
+}
+
+/// The attribute's argument tokens rendered as a string
+fn attr_tokens(attr: &Attribute) -> String {
+    match &attr.meta {
+        syn::Meta::List(list) => list.tokens.to_string(),
+        _ => String::new(),
+    }
+}
@@ -136,6 +136,7 @@ fn validate_edition(
                     violation_type: ViolationType::WrongEdition,
                     file: cargo_file.to_path_buf(),
                     line: find_field_line(lines, "edition"),
+                    column: 0,
                     message: format!(
                         "FERROUS FORGE [LOCKED SETTING] — Edition Violation\n\
                          ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n\
@@ -171,6 +172,7 @@ fn validate_edition(
                     violation_type: ViolationType::WrongEdition,
                     file: cargo_file.to_path_buf(),
                     line: 0,
+                    column: 0,
                     message: format!(
                         "FERROUS FORGE [LOCKED SETTING] — Missing Edition\n\
                          ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n\
@@ -206,6 +208,7 @@ fn validate_rust_version_field(
                     violation_type: ViolationType::OldRustVersion,
                     file: cargo_file.to_path_buf(),
                     line: find_field_line(lines, "rust-version"),
+                    column: 0,
                     message: format!(
                         "FERROUS FORGE [LOCKED SETTING] — Rust Version Violation\n\
                          ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n\
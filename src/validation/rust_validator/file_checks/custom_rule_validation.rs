@@ -0,0 +1,49 @@
+//! User-defined rule validation
+//!
+//! Applies the enabled `Config::custom_rules` to every line of a Rust file.
+//! Matches that fall inside string literals or comments are ignored.
+
+use super::super::patterns::{CompiledCustomRule, ValidationPatterns, is_offset_in_string_literal};
+use crate::validation::{Violation, ViolationType};
+use std::path::Path;
+
+/// Validate each line of a file against the compiled custom rules
+pub fn validate_custom_rules(
+    rust_file: &Path,
+    lines: &[&str],
+    patterns: &ValidationPatterns,
+    violations: &mut Vec<Violation>,
+) {
+    if patterns.custom_rules.is_empty() {
+        return;
+    }
+
+    for (i, line) in lines.iter().enumerate() {
+        if line.trim_start().starts_with("//") {
+            continue;
+        }
+
+        for rule in &patterns.custom_rules {
+            if let Some(column) = first_match_in_code(line, rule) {
+                violations.push(Violation {
+                    violation_type: ViolationType::CustomRule {
+                        name: rule.name.clone(),
+                    },
+                    file: rust_file.to_path_buf(),
+                    line: i + 1,
+                    column,
+                    message: format!("[{}] {}", rule.name, rule.message),
+                    severity: rule.severity.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// Returns the 1-based column of the first match outside strings and comments
+fn first_match_in_code(line: &str, rule: &CompiledCustomRule) -> Option<usize> {
+    rule.regex
+        .find_iter(line)
+        .find(|m| !m.as_str().is_empty() && !is_offset_in_string_literal(line, m.start()))
+        .map(|m| line[..m.start()].chars().count() + 1)
+}
@@ -42,6 +42,7 @@ pub fn validate_doc_presence(
             violation_type: ViolationType::MissingModuleDoc,
             file: rust_file.to_path_buf(),
             line: 1,
+            column: 0,
             message: format!(
                 "FERROUS FORGE [DOC STANDARD] — Missing Module Documentation\n  \
                  File: {}\n  \
@@ -67,6 +68,7 @@ pub fn validate_cargo_doc_config(
             violation_type: ViolationType::MissingDocConfig,
             file: cargo_file.to_path_buf(),
             line: 0,
+            column: 0,
             message: "FERROUS FORGE [DOC STANDARD] — Missing rustdoc lint configuration\n  \
                      Cargo.toml is missing [lints.rustdoc] section.\n  \
                      Run 'ferrous-forge init --project' to inject the full rustdoc lint block."
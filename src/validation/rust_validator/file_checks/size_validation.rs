@@ -18,6 +18,7 @@ pub fn validate_file_size(
             violation_type: ViolationType::FileTooLarge,
             file: rust_file.to_path_buf(),
             line: line_count,
+            column: 0,
             message: format!(
                 "File has {} lines, maximum allowed is {}",
                 line_count, max_lines
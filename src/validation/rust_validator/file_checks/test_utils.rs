@@ -6,6 +6,22 @@ use std::path::Path;
 pub fn is_test_file(rust_file: &Path) -> bool {
     rust_file
         .to_str()
-        .map(|s| s.contains("/tests/") || s.contains("\\tests\\") || s.ends_with("_test.rs"))
+        .map(|s| {
+            s.contains("/tests/")
+                || s.contains("\\tests\\")
+                || s.ends_with("_test.rs")
+                || s.ends_with("/tests.rs")
+                || s.ends_with("\\tests.rs")
+        })
         .unwrap_or(false)
 }
+
+/// Check if a file is outside production code (tests, benches, examples)
+pub fn is_non_production_file(rust_file: &Path) -> bool {
+    is_test_file(rust_file)
+        || rust_file.components().any(|c| {
+            c.as_os_str()
+                .to_str()
+                .is_some_and(|name| name == "benches" || name == "examples")
+        })
+}
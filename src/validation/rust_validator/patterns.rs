@@ -1,46 +1,78 @@
 //! Validation patterns for Rust code
+//!
+//! Structural checks (unwrap/expect, underscore bandaids, function size) run
+//! on the syntax tree in `file_checks::ast_validation`. This module holds the
+//! line-based patterns that remain: user-defined custom rules.
 
+use crate::config::CustomRule;
+use crate::validation::Severity;
 use crate::{Error, Result};
 use regex::Regex;
 
 /// Compiled regex patterns for validation
+#[derive(Clone)]
 pub struct ValidationPatterns {
-    /// Pattern for detecting function definitions
-    pub function_def: Regex,
-    /// Pattern for detecting underscore parameters
-    pub underscore_param: Regex,
-    /// Pattern for detecting underscore in let bindings
-    pub underscore_let: Regex,
-    /// Pattern for detecting `unwrap()` calls
-    pub unwrap_call: Regex,
-    /// Pattern for detecting `expect()` calls
-    pub expect_call: Regex,
+    /// Enabled user-defined rules from `Config::custom_rules`
+    pub custom_rules: Vec<CompiledCustomRule>,
+}
+
+/// A user-defined rule with its pattern compiled once up front
+#[derive(Debug, Clone)]
+pub struct CompiledCustomRule {
+    /// Name of the rule
+    pub name: String,
+    /// Compiled pattern to match against each source line
+    pub regex: Regex,
+    /// Message reported when the rule matches
+    pub message: String,
+    /// Severity assigned to violations of this rule
+    pub severity: Severity,
 }
 
 impl ValidationPatterns {
-    /// Create and compile all validation patterns
+    /// Create the validation patterns with no custom rules
     ///
     /// # Errors
     ///
     /// Returns an error if any regex pattern fails to compile.
     pub fn new() -> Result<Self> {
         Ok(Self {
-            function_def: Regex::new(r"^\s*(pub\s+)?(async\s+)?fn\s+")
-                .map_err(|e| Error::validation(format!("Invalid function regex: {}", e)))?,
-
-            underscore_param: Regex::new(r"fn\s+\w+[^{]*\b_\w+\s*:")
-                .map_err(|e| Error::validation(format!("Invalid underscore param regex: {}", e)))?,
-
-            underscore_let: Regex::new(r"^\s*let\s+_\s*=")
-                .map_err(|e| Error::validation(format!("Invalid underscore let regex: {}", e)))?,
-
-            unwrap_call: Regex::new(r"\.unwrap\(\)")
-                .map_err(|e| Error::validation(format!("Invalid unwrap regex: {}", e)))?,
-
-            expect_call: Regex::new(r"\.expect\(")
-                .map_err(|e| Error::validation(format!("Invalid expect regex: {}", e)))?,
+            custom_rules: Vec::new(),
         })
     }
+
+    /// Create all built-in patterns plus the enabled custom rules
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a built-in pattern or an enabled custom rule's
+    /// pattern fails to compile.
+    pub fn with_custom_rules(rules: &[CustomRule]) -> Result<Self> {
+        let mut patterns = Self::new()?;
+        patterns.custom_rules = rules
+            .iter()
+            .filter(|rule| rule.enabled)
+            .map(compile_custom_rule)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(patterns)
+    }
+}
+
+/// Compile a single custom rule's pattern
+fn compile_custom_rule(rule: &CustomRule) -> Result<CompiledCustomRule> {
+    let regex = Regex::new(&rule.pattern).map_err(|e| {
+        Error::validation(format!(
+            "Invalid pattern for custom rule '{}': {}",
+            rule.name, e
+        ))
+    })?;
+
+    Ok(CompiledCustomRule {
+        name: rule.name.clone(),
+        regex,
+        message: rule.message.clone(),
+        severity: rule.severity.clone(),
+    })
 }
 
 /// Helper function to check if a pattern is inside a string literal or comment
@@ -50,59 +82,47 @@ pub fn is_in_string_literal(line: &str, pattern: &str) -> bool {
         return false;
     }
 
-    // Find all occurrences of the pattern
-    let pattern_positions: Vec<usize> = line.match_indices(pattern).map(|(i, _)| i).collect();
-    if pattern_positions.is_empty() {
-        return false;
-    }
+    // Every occurrence must be in a string or comment
+    line.match_indices(pattern)
+        .all(|(pattern_pos, _)| is_offset_in_string_literal(line, pattern_pos))
+}
 
-    // Check each occurrence to see if it's in a string or comment
-    for pattern_pos in pattern_positions {
-        let mut in_string = false;
-        let mut in_raw_string = false;
-        let mut escaped = false;
-        let mut pos = 0;
+/// Whether the byte offset `offset` of `line` is inside a string literal or
+/// comment
+pub fn is_offset_in_string_literal(line: &str, offset: usize) -> bool {
+    // Check if we're in a comment
+    if line
+        .find("//")
+        .is_some_and(|comment_pos| offset >= comment_pos)
+    {
+        return true;
+    }
 
-        // Check if we're in a comment
-        if let Some(comment_pos) = line.find("//")
-            && pattern_pos >= comment_pos
-        {
-            continue; // This occurrence is in a comment, check next
+    let mut in_string = false;
+    let mut in_raw_string = false;
+    let mut escaped = false;
+    for (pos, c) in line.char_indices() {
+        if pos >= offset {
+            break;
         }
 
-        for c in line.chars() {
-            if pos >= pattern_pos {
-                // We've reached the pattern position
-                // If we're not in a string, it's a real occurrence
-                if !in_string && !in_raw_string {
-                    return false;
-                }
-                break;
-            }
-
-            if escaped {
-                escaped = false;
-                pos += c.len_utf8();
-                continue;
-            }
+        if escaped {
+            escaped = false;
+            continue;
+        }
 
-            match c {
-                '\\' if in_string && !in_raw_string => escaped = true,
-                '"' if !in_raw_string => in_string = !in_string,
-                'r' if !in_string && !in_raw_string => {
-                    // Check for raw string
-                    let remaining = &line[pos..];
-                    if remaining.starts_with("r\"") || remaining.starts_with("r#\"") {
-                        in_raw_string = true;
-                    }
+        match c {
+            '\\' if in_string && !in_raw_string => escaped = true,
+            '"' if !in_raw_string => in_string = !in_string,
+            'r' if !in_string && !in_raw_string => {
+                // Check for raw string
+                let remaining = &line[pos..];
+                if remaining.starts_with("r\"") || remaining.starts_with("r#\"") {
+                    in_raw_string = true;
                 }
-                _ => {}
             }
-
-            pos += c.len_utf8();
+            _ => {}
         }
     }
-
-    // All occurrences are in strings or comments
-    true
+    in_string || in_raw_string
 }
@@ -0,0 +1,481 @@
+//! Inline suppression comments
+//!
+//! A justified violation can be allowed in place instead of weakening the
+//! configuration for the whole project:
+//!
+//! ```text
+//! let cfg = CONFIG.get().expect("set in main"); // ferrous-forge: allow(unwrap_in_production, reason = "initialized before use")
+//!
+//! // ferrous-forge: allow(function_too_large, reason = "generated match table")
+//! fn dispatch(op: Op) -> Handler { .. }
+//!
+//! // ferrous-forge: allow-file(file_too_large, reason = "lookup table")
+//! ```
+//!
+//! A comment after code on the same line covers that line. A comment on a
+//! line of its own covers the next item or statement (including its
+//! attributes and doc comments). `allow-file` covers the whole file.
+//!
+//! Every suppression must carry a non-empty `reason`. Suppressions without
+//! one, suppressions that no longer match any violation and malformed
+//! suppression comments are reported as
+//! [`ViolationType::InvalidSuppression`].
+
+use crate::validation::{Severity, Violation, ViolationType};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+
+/// Prefix that marks a comment as a suppression
+pub const SUPPRESSION_MARKER: &str = "ferrous-forge:";
+
+/// Matches `allow(...)` / `allow-file(...)` after the marker
+static DIRECTIVE: LazyLock<Option<Regex>> =
+    LazyLock::new(|| Regex::new(r"^(allow|allow-file)\s*\((.*)\)\s*$").ok());
+
+/// Matches the `reason = "..."` argument
+static REASON: LazyLock<Option<Regex>> =
+    LazyLock::new(|| Regex::new(r#"reason\s*=\s*"((?:[^"\\]|\\.)*)""#).ok());
+
+/// What a suppression comment covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuppressionScope {
+    /// The line the comment trails
+    Line,
+    /// The item or statement following the comment
+    NextItem,
+    /// The whole file (`allow-file`)
+    File,
+}
+
+impl std::fmt::Display for SuppressionScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Line => write!(f, "line"),
+            Self::NextItem => write!(f, "next-item"),
+            Self::File => write!(f, "file"),
+        }
+    }
+}
+
+/// A parsed suppression comment
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Suppression {
+    /// File containing the comment
+    pub file: PathBuf,
+    /// 1-based line of the comment
+    pub line: usize,
+    /// What the comment covers
+    pub scope: SuppressionScope,
+    /// Rule names allowed (see [`ViolationType::rule_name`])
+    pub rules: Vec<String>,
+    /// Justification; suppressions without one have no effect
+    pub reason: Option<String>,
+    /// First covered line
+    pub start_line: usize,
+    /// Last covered line
+    pub end_line: usize,
+    /// Number of violations this suppression allowed
+    pub used: usize,
+}
+
+impl Suppression {
+    /// Whether the suppression has a non-empty reason
+    pub fn is_justified(&self) -> bool {
+        self.reason.as_deref().is_some_and(|r| !r.trim().is_empty())
+    }
+
+    /// Whether this suppression allows `violation`
+    fn covers(&self, violation: &Violation) -> bool {
+        let in_range = self.scope == SuppressionScope::File
+            || (self.start_line..=self.end_line).contains(&violation.line);
+        in_range && self.rules.contains(&violation.violation_type.rule_name())
+    }
+}
+
+/// Find the suppression comments in a Rust file
+///
+/// Malformed suppression comments are returned as violations.
+pub fn parse_suppressions(file: &Path, content: &str) -> (Vec<Suppression>, Vec<Violation>) {
+    let comments = line_comments(content);
+    if !comments
+        .iter()
+        .any(|c| c.text.trim_start().starts_with(SUPPRESSION_MARKER))
+    {
+        return (Vec::new(), Vec::new());
+    }
+
+    let lines: Vec<&str> = content.lines().collect();
+    let spans = syn::parse_file(content)
+        .map(|syntax| {
+            let mut collector = SpanCollector::default();
+            collector.visit_file(&syntax);
+            collector.spans
+        })
+        .unwrap_or_default();
+
+    let mut suppressions = Vec::new();
+    let mut invalid = Vec::new();
+    for comment in comments {
+        let Some(directive) = comment.text.trim_start().strip_prefix(SUPPRESSION_MARKER) else {
+            continue;
+        };
+        let Some((file_scoped, rules, reason)) = parse_directive(directive.trim()) else {
+            invalid.push(invalid_suppression(
+                file,
+                comment.line,
+                "Malformed suppression comment; expected \
+                 `// ferrous-forge: allow(<rule>, reason = \"...\")`"
+                    .to_string(),
+                Severity::Error,
+            ));
+            continue;
+        };
+
+        let (scope, start_line, end_line) = if file_scoped {
+            (SuppressionScope::File, 1, lines.len().max(1))
+        } else if comment.trailing {
+            (SuppressionScope::Line, comment.line, comment.line)
+        } else {
+            let target = next_code_line(&lines, comment.line);
+            let end = spans
+                .iter()
+                .filter(|(start, _)| *start == target)
+                .map(|(_, end)| *end)
+                .max()
+                .unwrap_or(target);
+            (SuppressionScope::NextItem, target, end)
+        };
+
+        suppressions.push(Suppression {
+            file: file.to_path_buf(),
+            line: comment.line,
+            scope,
+            rules,
+            reason,
+            start_line,
+            end_line,
+            used: 0,
+        });
+    }
+
+    (suppressions, invalid)
+}
+
+/// Drop the violations allowed by justified suppressions
+///
+/// Returns the remaining violations followed by an
+/// [`ViolationType::InvalidSuppression`] violation for every suppression
+/// that has no reason or allowed nothing. Suppression problems cannot
+/// themselves be suppressed.
+pub fn apply_suppressions(
+    suppressions: &mut [Suppression],
+    violations: Vec<Violation>,
+) -> Vec<Violation> {
+    let mut remaining = Vec::with_capacity(violations.len());
+    for violation in violations {
+        let allowed_by = if violation.violation_type == ViolationType::InvalidSuppression {
+            None
+        } else {
+            suppressions
+                .iter_mut()
+                .find(|s| s.is_justified() && s.covers(&violation))
+        };
+        match allowed_by {
+            Some(suppression) => suppression.used += 1,
+            None => remaining.push(violation),
+        }
+    }
+
+    for suppression in suppressions.iter() {
+        if !suppression.is_justified() {
+            remaining.push(invalid_suppression(
+                &suppression.file,
+                suppression.line,
+                format!(
+                    "Suppression of {} has no reason; add `reason = \"...\"` \
+                     explaining why the violation is acceptable",
+                    suppression.rules.join(", ")
+                ),
+                Severity::Error,
+            ));
+        } else if suppression.used == 0 {
+            remaining.push(invalid_suppression(
+                &suppression.file,
+                suppression.line,
+                format!(
+                    "Unused suppression of {}; no matching violation in its {} scope",
+                    suppression.rules.join(", "),
+                    suppression.scope
+                ),
+                Severity::Warning,
+            ));
+        }
+    }
+
+    remaining
+}
+
+/// Parse `allow(rule, ..., reason = "...")`, returning
+/// `(file_scoped, rules, reason)`
+fn parse_directive(directive: &str) -> Option<(bool, Vec<String>, Option<String>)> {
+    let captures = DIRECTIVE.as_ref()?.captures(directive)?;
+    let file_scoped = &captures[1] == "allow-file";
+    let args = &captures[2];
+
+    let (reason, rest) = match REASON.as_ref()?.captures(args) {
+        Some(reason) => {
+            let whole = reason.get(0)?;
+            let text = reason[1].replace("\\\"", "\"");
+            let rest = format!("{}{}", &args[..whole.start()], &args[whole.end()..]);
+            (Some(text), rest)
+        }
+        None => (None, args.to_string()),
+    };
+
+    let rules: Vec<String> = rest
+        .split(',')
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .map(str::to_string)
+        .collect();
+    let valid_rule = |r: &String| {
+        r.chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    };
+    if rules.is_empty() || !rules.iter().all(valid_rule) {
+        return None;
+    }
+
+    Some((file_scoped, rules, reason))
+}
+
+/// First line after `comment_line` that is not blank or a plain comment
+fn next_code_line(lines: &[&str], comment_line: usize) -> usize {
+    lines
+        .iter()
+        .enumerate()
+        .skip(comment_line)
+        .find(|(_, line)| {
+            let trimmed = line.trim_start();
+            let plain_comment = trimmed.starts_with("//")
+                && !trimmed.starts_with("///")
+                && !trimmed.starts_with("//!");
+            !trimmed.is_empty() && !plain_comment
+        })
+        .map_or(comment_line + 1, |(i, _)| i + 1)
+}
+
+fn invalid_suppression(file: &Path, line: usize, message: String, severity: Severity) -> Violation {
+    Violation::new(
+        ViolationType::InvalidSuppression,
+        file.to_path_buf(),
+        line,
+        message,
+        severity,
+    )
+}
+
+/// A `//` comment (not a doc comment) found outside string literals
+#[derive(Debug)]
+struct LineComment {
+    /// 1-based line of the comment
+    line: usize,
+    /// Whether code precedes the comment on the same line
+    trailing: bool,
+    /// Text after the `//`
+    text: String,
+}
+
+/// Collect the plain line comments of a file
+///
+/// A small lexer skips string, raw string, char and block comment contents
+/// so that `// ferrous-forge:` inside a literal is not treated as a comment.
+fn line_comments(content: &str) -> Vec<LineComment> {
+    let chars: Vec<char> = content.chars().collect();
+    let mut comments = Vec::new();
+    let mut line = 1;
+    let mut code_on_line = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            '\n' => {
+                line += 1;
+                code_on_line = false;
+                i += 1;
+            }
+            '/' if next == Some('/') => {
+                let end = chars[i..]
+                    .iter()
+                    .position(|&c| c == '\n')
+                    .map_or(chars.len(), |p| i + p);
+                let text: String = chars[i + 2..end].iter().collect();
+                if !text.starts_with('/') && !text.starts_with('!') {
+                    comments.push(LineComment {
+                        line,
+                        trailing: code_on_line,
+                        text,
+                    });
+                }
+                i = end;
+            }
+            '/' if next == Some('*') => {
+                let mut depth = 0;
+                while i < chars.len() {
+                    match (chars[i], chars.get(i + 1).copied()) {
+                        ('/', Some('*')) => {
+                            depth += 1;
+                            i += 2;
+                        }
+                        ('*', Some('/')) => {
+                            depth -= 1;
+                            i += 2;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        ('\n', _) => {
+                            line += 1;
+                            i += 1;
+                        }
+                        _ => i += 1,
+                    }
+                }
+            }
+            '"' => {
+                code_on_line = true;
+                i = skip_string(&chars, i + 1, &mut line);
+            }
+            'r' if raw_string_hashes(&chars, i).is_some() => {
+                code_on_line = true;
+                let hashes = raw_string_hashes(&chars, i).unwrap_or(0);
+                i = skip_raw_string(&chars, i + hashes + 2, hashes, &mut line);
+            }
+            '\'' => {
+                code_on_line = true;
+                i = skip_char_literal(&chars, i);
+            }
+            c => {
+                code_on_line |= !c.is_whitespace();
+                i += 1;
+            }
+        }
+    }
+
+    comments
+}
+
+/// Index just past the closing quote of a string starting at `i`
+fn skip_string(chars: &[char], mut i: usize, line: &mut usize) -> usize {
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            '"' => return i + 1,
+            '\n' => {
+                *line += 1;
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+    i
+}
+
+/// Number of `#`s when a raw string (`r"`, `r#"`, `br"`) starts at `i`
+fn raw_string_hashes(chars: &[char], i: usize) -> Option<usize> {
+    let prev = i.checked_sub(1).map(|p| chars[p]);
+    let starts_token = match prev {
+        Some('b') => i < 2 || !is_ident_char(chars[i - 2]),
+        Some(p) => !is_ident_char(p),
+        None => true,
+    };
+    if !starts_token {
+        return None;
+    }
+    let hashes = chars[i + 1..].iter().take_while(|&&c| c == '#').count();
+    (chars.get(i + 1 + hashes) == Some(&'"')).then_some(hashes)
+}
+
+/// Index just past the end of a raw string whose contents start at `i`
+fn skip_raw_string(chars: &[char], mut i: usize, hashes: usize, line: &mut usize) -> usize {
+    while i < chars.len() {
+        if chars[i] == '"'
+            && chars[i + 1..]
+                .iter()
+                .take(hashes)
+                .filter(|&&c| c == '#')
+                .count()
+                == hashes
+        {
+            return i + 1 + hashes;
+        }
+        if chars[i] == '\n' {
+            *line += 1;
+        }
+        i += 1;
+    }
+    i
+}
+
+/// Skip a char literal at `i`, or just the quote of a lifetime
+fn skip_char_literal(chars: &[char], i: usize) -> usize {
+    match (chars.get(i + 1), chars.get(i + 2)) {
+        (Some('\\'), _) => chars[i + 2..]
+            .iter()
+            .position(|&c| c == '\'')
+            .map_or(chars.len(), |p| i + 2 + p + 1),
+        (Some(_), Some('\'')) => i + 3,
+        _ => i + 1,
+    }
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Line ranges of every item and statement, attributes included
+#[derive(Default)]
+struct SpanCollector {
+    spans: Vec<(usize, usize)>,
+}
+
+impl SpanCollector {
+    fn record(&mut self, node: &impl Spanned) {
+        let span = node.span();
+        self.spans.push((span.start().line, span.end().line));
+    }
+}
+
+impl<'ast> Visit<'ast> for SpanCollector {
+    fn visit_item(&mut self, node: &'ast syn::Item) {
+        self.record(node);
+        visit::visit_item(self, node);
+    }
+
+    fn visit_impl_item(&mut self, node: &'ast syn::ImplItem) {
+        self.record(node);
+        visit::visit_impl_item(self, node);
+    }
+
+    fn visit_trait_item(&mut self, node: &'ast syn::TraitItem) {
+        self.record(node);
+        visit::visit_trait_item(self, node);
+    }
+
+    fn visit_stmt(&mut self, node: &'ast syn::Stmt) {
+        self.record(node);
+        visit::visit_stmt(self, node);
+    }
+
+    fn visit_field(&mut self, node: &'ast syn::Field) {
+        self.record(node);
+        visit::visit_field(self, node);
+    }
+}
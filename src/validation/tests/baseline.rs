@@ -0,0 +1,119 @@
+//! Tests for the violation baseline
+
+use crate::validation::baseline::Baseline;
+use crate::validation::violation::*;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn unwrap_violation(root: &Path, line: usize) -> Violation {
+    Violation::new(
+        ViolationType::UnwrapInProduction,
+        root.join("src/lib.rs"),
+        line,
+        "unwrap in production".to_string(),
+        Severity::Error,
+    )
+}
+
+fn write_lib(root: &Path, contents: &str) {
+    std::fs::create_dir_all(root.join("src")).expect("Failed to create src");
+    std::fs::write(root.join("src/lib.rs"), contents).expect("Failed to write lib.rs");
+}
+
+#[test]
+fn test_baseline_survives_line_shifts() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let root = temp_dir.path();
+    write_lib(root, "fn a() {\n    x.unwrap();\n}\n");
+
+    let baseline = Baseline::from_violations(root, &[unwrap_violation(root, 2)]);
+    baseline.save(root).expect("Failed to save baseline");
+    let baseline = Baseline::load(root)
+        .expect("Failed to load baseline")
+        .expect("Baseline should exist");
+
+    // Two lines inserted above and indentation changed
+    write_lib(root, "// one\n// two\nfn a() {\n        x.unwrap();\n}\n");
+    let comparison = baseline.compare(root, vec![unwrap_violation(root, 4)]);
+
+    assert!(comparison.new_violations.is_empty());
+    assert_eq!(comparison.baselined.len(), 1);
+    assert!(comparison.fixed.is_empty());
+}
+
+#[test]
+fn test_baseline_reports_new_and_fixed_violations() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let root = temp_dir.path();
+    write_lib(root, "fn a() {\n    x.unwrap();\n    y.unwrap();\n}\n");
+
+    let baseline = Baseline::from_violations(root, &[unwrap_violation(root, 2)]);
+
+    // `x.unwrap()` was fixed, `y.unwrap()` is new, and a duplicate of the
+    // new line is not absorbed by the single baseline entry
+    write_lib(root, "fn a() {\n    y.unwrap();\n    y.unwrap();\n}\n");
+    let comparison = baseline.compare(
+        root,
+        vec![unwrap_violation(root, 2), unwrap_violation(root, 3)],
+    );
+
+    assert_eq!(comparison.new_violations.len(), 2);
+    assert!(comparison.baselined.is_empty());
+    assert_eq!(comparison.fixed.len(), 1);
+    assert_eq!(comparison.fixed[0].snippet, "x.unwrap();");
+}
+
+#[test]
+fn test_whole_file_violations_ignore_the_line() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let root = temp_dir.path();
+    write_lib(root, "fn a() {}\nfn b() {}\n");
+    let too_large = |line| {
+        Violation::new(
+            ViolationType::FileTooLarge,
+            root.join("src/lib.rs"),
+            line,
+            "file too large".to_string(),
+            Severity::Error,
+        )
+    };
+
+    let baseline = Baseline::from_violations(root, &[too_large(2)]);
+    assert_eq!(baseline.entries[0].snippet, "");
+
+    // The file grew, so the reported last line changed
+    write_lib(root, "fn a() {}\nfn b() {}\nfn c() {}\n");
+    let comparison = baseline.compare(root, vec![too_large(3)]);
+    assert!(comparison.new_violations.is_empty());
+    assert_eq!(comparison.baselined.len(), 1);
+}
+
+#[test]
+fn test_invalid_suppressions_are_never_baselined() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let root = temp_dir.path();
+    write_lib(root, "#[allow(dead_code)]\nfn a() {}\n");
+    let suppression = Violation::new(
+        ViolationType::InvalidSuppression,
+        root.join("src/lib.rs"),
+        1,
+        "suppression without a reason".to_string(),
+        Severity::Error,
+    );
+
+    let baseline = Baseline::from_violations(root, std::slice::from_ref(&suppression));
+    assert!(baseline.entries.is_empty());
+
+    let comparison = baseline.compare(root, vec![suppression]);
+    assert_eq!(comparison.new_violations.len(), 1);
+}
+
+#[test]
+fn test_missing_baseline_loads_as_none() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    assert!(
+        Baseline::load(temp_dir.path())
+            .expect("Loading should succeed")
+            .is_none()
+    );
+}
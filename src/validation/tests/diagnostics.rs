@@ -0,0 +1,150 @@
+//! Tests for cargo JSON diagnostics
+
+use crate::validation::diagnostics::{
+    apply_edits, machine_applicable_edits, parse_cargo_messages, to_violations,
+};
+use crate::validation::{ClippyResult, DiagnosticLevel, Severity, Violation, ViolationType};
+use std::path::{Path, PathBuf};
+
+const SOURCE: &str = "pub fn f(x: Option<u8>) -> u8 {\n    let y = x.unwrap();\n    return y;\n}\n";
+
+fn span(start: usize, end: usize, col: usize, replacement: Option<&str>) -> String {
+    let (replacement, applicability) = match replacement {
+        Some(r) => (format!("\"{}\"", r), "\"MachineApplicable\""),
+        None => ("null".to_string(), "null"),
+    };
+    format!(
+        r#"{{"file_name":"src/lib.rs","byte_start":{start},"byte_end":{end},"line_start":3,"line_end":3,"column_start":{col},"column_end":{},"is_primary":true,"suggested_replacement":{replacement},"suggestion_applicability":{applicability}}}"#,
+        col + end - start
+    )
+}
+
+fn needless_return() -> String {
+    format!(
+        r#"{{"reason":"compiler-message","message":{{"message":"unneeded `return` statement","code":{{"code":"clippy::needless_return","explanation":null}},"level":"warning","spans":[{}],"children":[{{"message":"remove `return`","code":null,"level":"help","spans":[{},{}],"children":[],"rendered":null}}],"rendered":"warning: unneeded `return` statement\n"}}}}"#,
+        span(60, 68, 5, None),
+        span(60, 68, 5, Some("y")),
+        span(68, 69, 13, Some("")),
+    )
+}
+
+fn cargo_output() -> String {
+    [
+        r#"{"reason":"compiler-artifact","package_id":"dj 0.1.0"}"#.to_string(),
+        needless_return(),
+        // Cargo repeats diagnostics for every target containing the file
+        needless_return(),
+        r#"{"reason":"compiler-message","message":{"message":"aborting due to 1 previous error","code":null,"level":"error","spans":[],"children":[],"rendered":null}}"#.to_string(),
+        r#"{"reason":"build-finished","success":false}"#.to_string(),
+    ]
+    .join("\n")
+}
+
+#[test]
+fn test_parse_cargo_messages() {
+    let diagnostics = parse_cargo_messages(&cargo_output());
+
+    assert_eq!(diagnostics.len(), 1);
+    let diagnostic = &diagnostics[0];
+    assert_eq!(diagnostic.level, DiagnosticLevel::Warning);
+    assert_eq!(diagnostic.code.as_deref(), Some("clippy::needless_return"));
+    assert_eq!(diagnostic.file, Some(PathBuf::from("src/lib.rs")));
+    assert_eq!((diagnostic.line, diagnostic.column), (3, 5));
+    assert_eq!(diagnostic.machine_applicable().count(), 2);
+    assert_eq!(
+        diagnostic.summary(),
+        "src/lib.rs:3:5: [clippy::needless_return] unneeded `return` statement"
+    );
+}
+
+#[test]
+fn test_diagnostics_become_violations() {
+    let root = Path::new("/project");
+    let violations = to_violations(&parse_cargo_messages(&cargo_output()), root);
+
+    assert_eq!(violations.len(), 1);
+    let violation = &violations[0];
+    assert_eq!(
+        violation.violation_type,
+        ViolationType::CompilerDiagnostic {
+            code: "clippy::needless_return".to_string()
+        }
+    );
+    assert_eq!(
+        violation.violation_type.rule_name(),
+        "clippy::needless_return"
+    );
+    assert_eq!(violation.file, root.join("src/lib.rs"));
+    assert_eq!((violation.line, violation.column), (3, 5));
+    assert_eq!(violation.severity, Severity::Warning);
+    assert!(violation.message.contains("auto-fixable: remove `return`"));
+}
+
+#[test]
+fn test_lints_the_ast_checks_reported_are_not_repeated() {
+    let root = Path::new("/project");
+    let mut diagnostics = parse_cargo_messages(&cargo_output());
+    let mut unwrap_used = diagnostics[0].clone();
+    unwrap_used.code = Some("clippy::unwrap_used".to_string());
+    unwrap_used.message = "used `unwrap()` on an `Option` value".to_string();
+    (unwrap_used.line, unwrap_used.end_line) = (2, 2);
+    let mut elsewhere = unwrap_used.clone();
+    elsewhere.file = Some(PathBuf::from("src/main.rs"));
+    diagnostics.extend([unwrap_used, elsewhere]);
+    let clippy = ClippyResult {
+        success: false,
+        output: String::new(),
+        diagnostics,
+    };
+    let reported = [Violation::new(
+        ViolationType::UnwrapInProduction,
+        root.join("src/lib.rs"),
+        2,
+        "BANNED: `.unwrap()` in production code".to_string(),
+        Severity::Error,
+    )];
+
+    let codes: Vec<String> = clippy
+        .violations_beyond(root, &reported)
+        .iter()
+        .map(|v| format!("{}:{}", v.violation_type.rule_name(), v.file.display()))
+        .collect();
+    assert_eq!(
+        codes,
+        vec![
+            "clippy::needless_return:/project/src/lib.rs",
+            "clippy::unwrap_used:/project/src/main.rs",
+        ]
+    );
+}
+
+#[test]
+fn test_machine_applicable_edits_apply_cleanly() {
+    let diagnostics = parse_cargo_messages(&cargo_output());
+    let edits = machine_applicable_edits(&diagnostics);
+    let file_edits = &edits[&PathBuf::from("src/lib.rs")];
+
+    let fixed = apply_edits(SOURCE, file_edits).unwrap();
+    assert_eq!(
+        fixed,
+        "pub fn f(x: Option<u8>) -> u8 {\n    let y = x.unwrap();\n    y\n}\n"
+    );
+
+    // Edits that no longer fit the file are refused
+    assert!(apply_edits("fn f() {}\n", file_edits).is_none());
+}
+
+#[test]
+fn test_overlapping_diagnostics_are_left_for_a_later_run() {
+    let mut diagnostics = parse_cargo_messages(&cargo_output());
+    let mut overlapping = diagnostics[0].clone();
+    overlapping.code = Some("clippy::other".to_string());
+    overlapping.suggestions.truncate(1);
+    overlapping.suggestions[0].replacement = "z".to_string();
+    diagnostics.push(overlapping);
+
+    let edits = machine_applicable_edits(&diagnostics);
+    let file_edits = &edits[&PathBuf::from("src/lib.rs")];
+    assert_eq!(file_edits.len(), 2);
+    assert!(file_edits.iter().all(|e| e.replacement != "z"));
+}
@@ -1,5 +1,6 @@
 //! Tests for file validation checks
 
+use crate::config::CustomRule;
 use crate::validation::rust_validator::{
     file_checks::{validate_cargo_toml, validate_rust_file},
     patterns::ValidationPatterns,
@@ -193,3 +194,211 @@ async fn test_validate_rust_file_underscore_bandaid() {
             .any(|v| matches!(v.violation_type, ViolationType::UnderscoreBandaid))
     );
 }
+
+fn no_println_rule(enabled: bool) -> CustomRule {
+    CustomRule {
+        name: "no-println".to_string(),
+        pattern: r"println!\(".to_string(),
+        message: "Use tracing instead of println!".to_string(),
+        enabled,
+        severity: Severity::Warning,
+    }
+}
+
+#[tokio::test]
+async fn test_validate_rust_file_custom_rule() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let rust_file = temp_dir.path().join("test.rs");
+
+    let content = "fn main() {\n    // println!(\"commented\");\n    \
+                   let s = \"println!(\";\n    println!(\"{}\", s);\n}\n";
+    fs::write(&rust_file, content)
+        .await
+        .expect("Failed to write Rust file");
+
+    let mut violations = Vec::new();
+    let patterns = ValidationPatterns::with_custom_rules(&[no_println_rule(true)])
+        .expect("Failed to create patterns");
+    validate_rust_file(&rust_file, &mut violations, &patterns, 300, 50)
+        .await
+        .expect("Validation should succeed");
+
+    // Only the real call on line 4 should match, not the comment or string
+    let custom: Vec<_> = violations
+        .iter()
+        .filter(|v| matches!(v.violation_type, ViolationType::CustomRule { .. }))
+        .collect();
+    assert_eq!(custom.len(), 1);
+    assert_eq!(custom[0].line, 4);
+    assert_eq!(custom[0].severity, Severity::Warning);
+    assert_eq!(
+        custom[0].violation_type,
+        ViolationType::CustomRule {
+            name: "no-println".to_string()
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_custom_rule_match_is_judged_by_its_own_position() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let rust_file = temp_dir.path().join("test.rs");
+
+    let content = "fn main() {\n    let s = \"println!(\"; println!(\"{}\", s);\n}\n";
+    fs::write(&rust_file, content)
+        .await
+        .expect("Failed to write Rust file");
+
+    let mut violations = Vec::new();
+    let patterns = ValidationPatterns::with_custom_rules(&[no_println_rule(true)])
+        .expect("Failed to create patterns");
+    validate_rust_file(&rust_file, &mut violations, &patterns, 300, 50)
+        .await
+        .expect("Validation should succeed");
+
+    // The match inside the string is skipped; the call after it is reported
+    let custom: Vec<_> = violations
+        .iter()
+        .filter(|v| matches!(v.violation_type, ViolationType::CustomRule { .. }))
+        .collect();
+    assert_eq!(custom.len(), 1);
+    assert_eq!(custom[0].line, 2);
+    assert_eq!(custom[0].column, 26);
+}
+
+#[tokio::test]
+async fn test_validate_rust_file_disabled_custom_rule() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let rust_file = temp_dir.path().join("test.rs");
+
+    fs::write(&rust_file, "fn main() {\n    println!(\"hi\");\n}\n")
+        .await
+        .expect("Failed to write Rust file");
+
+    let mut violations = Vec::new();
+    let patterns = ValidationPatterns::with_custom_rules(&[no_println_rule(false)])
+        .expect("Failed to create patterns");
+    validate_rust_file(&rust_file, &mut violations, &patterns, 300, 50)
+        .await
+        .expect("Validation should succeed");
+
+    assert!(
+        !violations
+            .iter()
+            .any(|v| matches!(v.violation_type, ViolationType::CustomRule { .. }))
+    );
+}
+
+#[test]
+fn test_invalid_custom_rule_pattern_is_rejected() {
+    let mut rule = no_println_rule(true);
+    rule.pattern = "(unclosed".to_string();
+
+    let result = ValidationPatterns::with_custom_rules(&[rule]);
+    assert!(result.is_err());
+}
+
+async fn validate_source(content: &str) -> Vec<Violation> {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let rust_file = temp_dir.path().join("lib_code.rs");
+    fs::write(&rust_file, content)
+        .await
+        .expect("Failed to write Rust file");
+
+    let mut violations = Vec::new();
+    let patterns = ValidationPatterns::new().expect("Failed to create patterns");
+    validate_rust_file(&rust_file, &mut violations, &patterns, 300, 50)
+        .await
+        .expect("Validation should succeed");
+    violations
+}
+
+fn of_type<'a>(violations: &'a [Violation], vtype: &ViolationType) -> Vec<&'a Violation> {
+    violations
+        .iter()
+        .filter(|v| &v.violation_type == vtype)
+        .collect()
+}
+
+#[tokio::test]
+async fn test_ast_detects_unwrap_in_split_chain_and_macro() {
+    let content = r#"pub fn load(path: &str) -> String {
+    let text = std::fs::read_to_string(path)
+        .ok()
+        .unwrap();
+    println!("{}", text.parse::<u32>().expect("number"));
+    text
+}
+"#;
+    let violations = validate_source(content).await;
+    let unwraps = of_type(&violations, &ViolationType::UnwrapInProduction);
+
+    assert_eq!(unwraps.len(), 2);
+    assert_eq!((unwraps[0].line, unwraps[0].column), (4, 10));
+    assert_eq!(unwraps[1].line, 5);
+    assert!(unwraps[1].column > 0);
+}
+
+#[tokio::test]
+async fn test_ast_ignores_unwrap_in_strings_and_test_code() {
+    let content = r##"pub fn help() -> &'static str {
+    r#"call .unwrap() on it"#
+}
+
+#[allow(clippy::unwrap_used)]
+pub fn allowed() -> u32 {
+    "1".parse().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn parses() {
+        assert_eq!("1".parse::<u32>().unwrap(), 1);
+    }
+}
+"##;
+    let violations = validate_source(content).await;
+
+    assert!(of_type(&violations, &ViolationType::UnwrapInProduction).is_empty());
+}
+
+#[tokio::test]
+async fn test_ast_detects_multiline_underscore_param_and_let_discard() {
+    let content = r#"pub fn run(
+    input: &str,
+    _unused: u32,
+) {
+    let _ = input.len();
+}
+
+pub struct Handler;
+
+impl std::fmt::Display for Handler {
+    fn fmt(&self, _f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Ok(())
+    }
+}
+"#;
+    let violations = validate_source(content).await;
+    let bandaids = of_type(&violations, &ViolationType::UnderscoreBandaid);
+
+    // Trait impl parameters are dictated by the trait and are not flagged
+    assert_eq!(bandaids.len(), 2);
+    assert_eq!((bandaids[0].line, bandaids[0].column), (3, 5));
+    assert_eq!((bandaids[1].line, bandaids[1].column), (5, 5));
+}
+
+#[tokio::test]
+async fn test_ast_function_length_spans_signature_to_closing_brace() {
+    let body: String = (0..55)
+        .map(|i| format!("    let v{} = {};\n", i, i))
+        .collect();
+    let content = format!("pub fn long(\n    a: u32,\n) {{\n{}}}\n", body);
+    let violations = validate_source(&content).await;
+    let too_large = of_type(&violations, &ViolationType::FunctionTooLarge);
+
+    assert_eq!(too_large.len(), 1);
+    assert_eq!((too_large[0].line, too_large[0].column), (1, 5));
+    assert!(too_large[0].message.contains("59 lines"));
+}
@@ -1,5 +1,10 @@
 //! Tests for the validation module
 
+mod baseline;
+mod diagnostics;
 mod file_checks;
+mod path_scope;
+mod rules;
+mod suppression;
 mod types;
 mod validator;
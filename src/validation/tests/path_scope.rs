@@ -0,0 +1,97 @@
+//! Tests for path-scoped configuration
+
+use crate::config::{Config, PathOverride};
+use crate::validation::{PathScope, RuleLevel, Severity, Violation, ViolationType};
+use std::path::{Path, PathBuf};
+
+fn violation(file: &str, violation_type: ViolationType) -> Violation {
+    Violation::new(
+        violation_type,
+        Path::new("/repo").join(file),
+        1,
+        "message".to_string(),
+        Severity::Error,
+    )
+}
+
+fn scope() -> PathScope {
+    let config = Config {
+        exclude: vec!["benches".to_string(), "src/*_ffi.rs".to_string()],
+        rules: [("line_too_long".to_string(), RuleLevel::Warning)].into(),
+        overrides: vec![
+            PathOverride {
+                paths: vec!["src/generated/**".to_string()],
+                max_file_lines: Some(2000),
+                rules: [
+                    ("unwrap_in_production".to_string(), RuleLevel::Off),
+                    ("line_too_long".to_string(), RuleLevel::Info),
+                ]
+                .into(),
+                ..Default::default()
+            },
+            PathOverride {
+                paths: vec!["src/generated/schema.rs".to_string()],
+                max_function_lines: Some(200),
+                rules: [("unwrap_in_production".to_string(), RuleLevel::Error)].into(),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+    PathScope::new(Path::new("/repo"), &config).unwrap()
+}
+
+#[test]
+fn test_exclude_matches_files_and_directories() {
+    let scope = scope();
+    assert!(scope.is_excluded(Path::new("/repo/benches/bench.rs")));
+    assert!(scope.is_excluded(Path::new("/repo/src/sys_ffi.rs")));
+    assert!(!scope.is_excluded(Path::new("/repo/src/nested/sys_ffi.rs")));
+    assert!(!scope.is_excluded(Path::new("/repo/src/lib.rs")));
+}
+
+#[test]
+fn test_later_overrides_win() {
+    let scope = scope();
+    let limits = scope.limits(Path::new("/repo/src/generated/schema.rs"));
+    assert_eq!(
+        (limits.max_file_lines, limits.max_function_lines),
+        (2000, 200)
+    );
+    assert_eq!(
+        scope.limits(Path::new("/repo/src/lib.rs")).max_file_lines,
+        300
+    );
+
+    let violations = scope.apply(vec![
+        violation("src/generated/api.rs", ViolationType::UnwrapInProduction),
+        violation("src/generated/schema.rs", ViolationType::UnwrapInProduction),
+        violation("src/generated/api.rs", ViolationType::LineTooLong),
+        violation("src/lib.rs", ViolationType::LineTooLong),
+        violation("benches/bench.rs", ViolationType::LineTooLong),
+    ]);
+    let summary: Vec<(PathBuf, Severity)> = violations
+        .into_iter()
+        .map(|v| (v.file, v.severity))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (
+                PathBuf::from("/repo/src/generated/schema.rs"),
+                Severity::Error
+            ),
+            (PathBuf::from("/repo/src/generated/api.rs"), Severity::Info),
+            (PathBuf::from("/repo/src/lib.rs"), Severity::Warning),
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_glob_is_a_config_error() {
+    let config = Config {
+        exclude: vec!["src/[".to_string()],
+        ..Default::default()
+    };
+    assert!(PathScope::new(Path::new("/repo"), &config).is_err());
+}
@@ -0,0 +1,63 @@
+//! Tests for per-rule severity overrides
+
+use crate::validation::{RuleLevel, Severity, Violation, ViolationType, apply_rules};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+fn violation(violation_type: ViolationType) -> Violation {
+    Violation::new(
+        violation_type,
+        PathBuf::from("src/lib.rs"),
+        1,
+        "message".to_string(),
+        Severity::Error,
+    )
+}
+
+#[test]
+fn test_rules_change_severity_and_drop_off_rules() {
+    let rules = BTreeMap::from([
+        ("line_too_long".to_string(), RuleLevel::Off),
+        ("unwrap_in_production".to_string(), RuleLevel::Info),
+        ("file_too_large".to_string(), RuleLevel::Warning),
+    ]);
+    let violations = vec![
+        violation(ViolationType::LineTooLong),
+        violation(ViolationType::UnwrapInProduction),
+        violation(ViolationType::FileTooLarge),
+        violation(ViolationType::FunctionTooLarge),
+    ];
+
+    let severities: Vec<(ViolationType, Severity)> = apply_rules(violations, &rules)
+        .into_iter()
+        .map(|v| (v.violation_type, v.severity))
+        .collect();
+    assert_eq!(
+        severities,
+        vec![
+            (ViolationType::UnwrapInProduction, Severity::Info),
+            (ViolationType::FileTooLarge, Severity::Warning),
+            (ViolationType::FunctionTooLarge, Severity::Error),
+        ]
+    );
+    assert!(Severity::Error.is_blocking());
+    assert!(!Severity::Warning.is_blocking());
+    assert!(!Severity::Info.is_blocking());
+}
+
+#[test]
+fn test_rules_do_not_affect_locked_settings() {
+    let rules = BTreeMap::from([("wrong_edition".to_string(), RuleLevel::Off)]);
+    let violations = apply_rules(vec![violation(ViolationType::WrongEdition)], &rules);
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].severity, Severity::Error);
+}
+
+#[test]
+fn test_rule_level_parsing() {
+    assert_eq!("warn".parse::<RuleLevel>().ok(), Some(RuleLevel::Warning));
+    assert_eq!("OFF".parse::<RuleLevel>().ok(), Some(RuleLevel::Off));
+    assert!("fatal".parse::<RuleLevel>().is_err());
+    assert_eq!(RuleLevel::Info.to_string(), "info");
+}
@@ -0,0 +1,134 @@
+//! Tests for inline suppression comments
+
+use crate::validation::rust_validator::{
+    file_checks::validate_rust_file, patterns::ValidationPatterns,
+};
+use crate::validation::{Severity, Suppression, SuppressionScope, Violation, ViolationType};
+use tempfile::TempDir;
+use tokio::fs;
+
+async fn validate_source(source: &str) -> (Vec<Violation>, Vec<Suppression>) {
+    let temp_dir = TempDir::new().unwrap();
+    let rust_file = temp_dir.path().join("service.rs");
+    fs::write(&rust_file, source).await.unwrap();
+
+    let mut violations = Vec::new();
+    let patterns = ValidationPatterns::new().unwrap();
+    let suppressions = validate_rust_file(&rust_file, &mut violations, &patterns, 300, 5)
+        .await
+        .unwrap();
+    (violations, suppressions)
+}
+
+fn count(violations: &[Violation], violation_type: &ViolationType) -> usize {
+    violations
+        .iter()
+        .filter(|v| &v.violation_type == violation_type)
+        .count()
+}
+
+#[tokio::test]
+async fn test_justified_suppressions_by_scope() {
+    let source = r#"
+fn port() -> u16 {
+    std::env::var("PORT").unwrap().parse().unwrap() // ferrous-forge: allow(unwrap_in_production, reason = "validated at startup")
+}
+
+// ferrous-forge: allow(function_too_large, unwrap_in_production, reason = "generated table")
+/// Dispatch table
+fn dispatch(op: u8) -> u8 {
+    let a = Some(op).unwrap();
+    match a {
+        0 => 1,
+        1 => 2,
+        _ => 3,
+    }
+}
+
+fn other() -> u8 {
+    Some(1).unwrap()
+}
+"#;
+    let (violations, suppressions) = validate_source(source).await;
+
+    // Only the unwrap in `other` is outside every suppression
+    assert_eq!(count(&violations, &ViolationType::UnwrapInProduction), 1);
+    assert_eq!(count(&violations, &ViolationType::FunctionTooLarge), 0);
+    assert_eq!(count(&violations, &ViolationType::InvalidSuppression), 0);
+
+    assert_eq!(suppressions.len(), 2);
+    assert_eq!(suppressions[0].scope, SuppressionScope::Line);
+    assert_eq!(suppressions[0].used, 2);
+    assert_eq!(suppressions[1].scope, SuppressionScope::NextItem);
+    assert_eq!(
+        (suppressions[1].start_line, suppressions[1].end_line),
+        (7, 15)
+    );
+    assert_eq!(suppressions[1].used, 2);
+}
+
+#[tokio::test]
+async fn test_suppression_without_reason_is_rejected() {
+    let source = r#"
+// ferrous-forge: allow-file(unwrap_in_production)
+fn value() -> u8 {
+    Some(1).unwrap()
+}
+"#;
+    let (violations, suppressions) = validate_source(source).await;
+
+    assert_eq!(suppressions[0].scope, SuppressionScope::File);
+    assert!(!suppressions[0].is_justified());
+    // The unjustified suppression has no effect and is itself reported
+    assert_eq!(count(&violations, &ViolationType::UnwrapInProduction), 1);
+    let invalid: Vec<_> = violations
+        .iter()
+        .filter(|v| v.violation_type == ViolationType::InvalidSuppression)
+        .collect();
+    assert_eq!(invalid.len(), 1);
+    assert_eq!(invalid[0].line, 2);
+    assert_eq!(invalid[0].severity, Severity::Error);
+}
+
+#[tokio::test]
+async fn test_unused_and_malformed_suppressions_are_reported() {
+    let source = r#"
+const HELP: &str = "// ferrous-forge: allow(anything)";
+
+// ferrous-forge: allow(unwrap_in_production, reason = "no longer needed")
+fn value() -> u8 {
+    1
+}
+
+// ferrous-forge: allow unwrap_in_production
+fn other() -> u8 {
+    2
+}
+"#;
+    let (violations, suppressions) = validate_source(source).await;
+
+    // The marker inside the string literal is not a comment
+    assert_eq!(suppressions.len(), 1);
+    let invalid: Vec<_> = violations
+        .iter()
+        .filter(|v| v.violation_type == ViolationType::InvalidSuppression)
+        .map(|v| (v.line, v.severity.clone()))
+        .collect();
+    assert_eq!(invalid, vec![(9, Severity::Error), (4, Severity::Warning)]);
+}
+
+#[test]
+fn test_rule_names_are_snake_case() {
+    assert_eq!(
+        ViolationType::UnwrapInProduction.rule_name(),
+        "unwrap_in_production"
+    );
+    assert_eq!(ViolationType::FileTooLarge.rule_name(), "file_too_large");
+    assert_eq!(
+        ViolationType::CustomRule {
+            name: "no-println".to_string()
+        }
+        .rule_name(),
+        "no-println"
+    );
+}
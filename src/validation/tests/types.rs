@@ -42,6 +42,7 @@ fn test_violation_creation() {
         violation_type: ViolationType::UnderscoreBandaid,
         file: std::path::PathBuf::from("test.rs"),
         line: 42,
+        column: 0,
         message: "Test violation".to_string(),
         severity: Severity::Error,
     };
@@ -58,6 +59,7 @@ fn test_clippy_result() {
     let result = super::super::ClippyResult {
         success: false,
         output: "Some clippy warnings".to_string(),
+        diagnostics: Vec::new(),
     };
 
     assert!(!result.success);
@@ -70,6 +72,7 @@ fn test_serialization() {
         violation_type: ViolationType::UnderscoreBandaid,
         file: std::path::PathBuf::from("test.rs"),
         line: 42,
+        column: 0,
         message: "Test violation".to_string(),
         severity: Severity::Error,
     };
@@ -36,6 +36,7 @@ async fn test_generate_report_with_violations() {
             violation_type: ViolationType::UnderscoreBandaid,
             file: PathBuf::from("test.rs"),
             line: 10,
+            column: 0,
             message: "Test violation".to_string(),
             severity: Severity::Error,
         },
@@ -43,17 +44,331 @@ async fn test_generate_report_with_violations() {
             violation_type: ViolationType::LineTooLong,
             file: PathBuf::from("other.rs"),
             line: 20,
+            column: 0,
             message: "Line too long".to_string(),
             severity: Severity::Warning,
         },
+        Violation {
+            violation_type: ViolationType::CompilerDiagnostic {
+                code: "clippy::needless_return".to_string(),
+            },
+            file: PathBuf::from("other.rs"),
+            line: 30,
+            column: 0,
+            message: "unneeded `return` statement".to_string(),
+            severity: Severity::Warning,
+        },
     ];
 
     let report = validator.generate_report(&violations);
 
     // Check that report contains violation information
-    assert!(report.contains("2 violations"));
+    assert!(report.contains("3 violations"));
     assert!(report.contains("UNDERSCOREBANDAID"));
     assert!(report.contains("LINETOOLONG"));
+    assert!(report.contains("COMPILER DIAGNOSTIC (clippy::needless_return)"));
     assert!(report.contains("test.rs"));
     assert!(report.contains("other.rs"));
 }
+
+fn write_manifest(root: &std::path::Path) {
+    std::fs::write(
+        root.join("Cargo.toml"),
+        "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+    )
+    .expect("Failed to write Cargo.toml");
+}
+
+async fn unwrap_count(validator: &RustValidator) -> usize {
+    validator
+        .validate_project()
+        .await
+        .expect("Validation should succeed")
+        .iter()
+        .filter(|v| v.violation_type == ViolationType::UnwrapInProduction)
+        .count()
+}
+
+#[tokio::test]
+async fn test_validate_reuses_cached_results_for_unchanged_files() {
+    use crate::config::Config;
+    use crate::performance::cache::{CachedFileResult, PersistentValidationCache, sha256_hex};
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let root = temp_dir.path().to_path_buf();
+    write_manifest(&root);
+    let source = "fn a() -> u8 {\n    Some(1).unwrap()\n}\n";
+    let file = root.join("service.rs");
+    std::fs::write(&file, source).expect("Failed to write source");
+
+    let validator = RustValidator::new(root.clone()).expect("Failed to create validator");
+    assert_eq!(unwrap_count(&validator).await, 1);
+    assert!(PersistentValidationCache::path(&root).exists());
+
+    // Replace the stored result for the same contents; a cache hit returns it
+    let mut cache = PersistentValidationCache::load(&root, &Config::default());
+    cache.insert(
+        file.clone(),
+        CachedFileResult {
+            sha256: sha256_hex(source.as_bytes()),
+            violations: vec![],
+            suppressions: vec![],
+        },
+    );
+    cache.save().expect("Failed to save cache");
+    assert_eq!(unwrap_count(&validator).await, 0);
+
+    // Changing the file invalidates its entry
+    std::fs::write(&file, source.replace("Some(1)", "Some(2)")).expect("Failed to write");
+    assert_eq!(unwrap_count(&validator).await, 1);
+}
+
+#[tokio::test]
+async fn test_only_files_restricts_source_validation() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let root = temp_dir.path().to_path_buf();
+    write_manifest(&root);
+    for name in ["changed.rs", "untouched.rs"] {
+        std::fs::write(root.join(name), "fn a() -> u8 {\n    Some(1).unwrap()\n}\n")
+            .expect("Failed to write source");
+    }
+
+    let validator = RustValidator::new(root.clone())
+        .expect("Failed to create validator")
+        .without_cache()
+        .only_files(vec![root.join("changed.rs")]);
+    assert_eq!(unwrap_count(&validator).await, 1);
+    assert!(!crate::performance::cache::PersistentValidationCache::path(&root).exists());
+}
+
+#[tokio::test]
+async fn test_migration_backups_are_not_validated() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let root = temp_dir.path().to_path_buf();
+    write_manifest(&root);
+    let backup = root.join(".ferrous-forge/backups/edition-1/files");
+    std::fs::create_dir_all(backup.join("src")).expect("Failed to create backup dir");
+    std::fs::write(
+        backup.join("Cargo.toml"),
+        "[package]\nname = \"old\"\nedition = \"2018\"\n",
+    )
+    .expect("Failed to write backup manifest");
+    std::fs::write(
+        backup.join("src/lib.rs"),
+        "fn a() -> u8 {\n    Some(1).unwrap()\n}\n",
+    )
+    .expect("Failed to write backup source");
+
+    let validator = RustValidator::new(root.clone())
+        .expect("Failed to create validator")
+        .without_cache();
+    let violations = validator.validate_project().await.expect("validation");
+    assert!(
+        !violations
+            .iter()
+            .any(|v| v.file.starts_with(root.join(".ferrous-forge"))),
+        "{violations:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_parallel_results_are_ordered_and_timed() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let root = temp_dir.path().to_path_buf();
+    write_manifest(&root);
+    for i in 0..12 {
+        std::fs::write(
+            root.join(format!("m{i:02}.rs")),
+            "fn a() -> u8 {\n    Some(1).unwrap()\n}\n",
+        )
+        .expect("Failed to write source");
+    }
+
+    let run = |jobs| {
+        RustValidator::new(root.clone())
+            .expect("Failed to create validator")
+            .without_cache()
+            .with_jobs(jobs)
+            .with_timings()
+    };
+    let serial = run(1);
+    let parallel = run(4);
+    let serial_violations = serial.validate_project().await.expect("serial run");
+    let parallel_violations = parallel.validate_project().await.expect("parallel run");
+
+    assert_eq!(serial_violations, parallel_violations);
+    let files: Vec<_> = parallel_violations
+        .iter()
+        .filter(|v| v.violation_type == ViolationType::UnwrapInProduction)
+        .map(|v| v.file.clone())
+        .collect();
+    let mut sorted = files.clone();
+    sorted.sort();
+    assert_eq!(files, sorted);
+
+    let timings = parallel.timings();
+    assert!(
+        timings
+            .iter()
+            .any(|(name, m)| name == "check:ast" && m.files_processed == 12)
+    );
+    assert_eq!(
+        timings
+            .iter()
+            .filter(|(name, _)| name.starts_with("file:"))
+            .count(),
+        12
+    );
+}
+
+#[tokio::test]
+async fn test_overrides_and_exclude_apply_per_file() {
+    use crate::config::{Config, PathOverride};
+    use crate::validation::RuleLevel;
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let root = temp_dir.path().to_path_buf();
+    write_manifest(&root);
+    let source = "fn a() -> u8 {\n    Some(1).unwrap()\n}\n";
+    for dir in ["src/generated", "src/ffi", "vendored"] {
+        std::fs::create_dir_all(root.join(dir)).expect("Failed to create dir");
+        std::fs::write(root.join(dir).join("mod.rs"), source).expect("Failed to write source");
+    }
+
+    let config = Config {
+        exclude: vec!["vendored".to_string()],
+        overrides: vec![PathOverride {
+            paths: vec!["src/generated/**".to_string()],
+            max_file_lines: Some(2),
+            rules: [("unwrap_in_production".to_string(), RuleLevel::Off)].into(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let validator = RustValidator::with_config(root.clone(), config)
+        .expect("Failed to create validator")
+        .without_cache();
+    let violations = validator.validate_project().await.expect("validation");
+
+    let in_dir = |dir: &str, violation_type: ViolationType| {
+        violations
+            .iter()
+            .any(|v| v.file.starts_with(root.join(dir)) && v.violation_type == violation_type)
+    };
+    assert!(in_dir("src/ffi", ViolationType::UnwrapInProduction));
+    assert!(!in_dir("src/generated", ViolationType::UnwrapInProduction));
+    assert!(in_dir("src/generated", ViolationType::FileTooLarge));
+    assert!(!in_dir("src/ffi", ViolationType::FileTooLarge));
+    assert!(
+        !violations
+            .iter()
+            .any(|v| v.file.starts_with(root.join("vendored")))
+    );
+}
+
+#[tokio::test]
+async fn test_workspace_members_have_their_own_config_and_selection() {
+    use crate::validation::{PackageSelection, Workspace, WorkspaceMember};
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let root = temp_dir.path().to_path_buf();
+    write_manifest(&root);
+    let ffi = root.join("crates/ffi");
+    std::fs::create_dir_all(ffi.join(".ferrous-forge")).expect("Failed to create dirs");
+    std::fs::write(
+        ffi.join(".ferrous-forge/config.toml"),
+        "exclude = [\"bindings\"]\n\n[rules]\nunwrap_in_production = \"off\"\n",
+    )
+    .expect("Failed to write member config");
+    let source = "fn a() -> u8 {\n    Some(1).unwrap()\n}\n";
+    for file in ["app.rs", "crates/ffi/shim.rs"] {
+        std::fs::write(root.join(file), source).expect("Failed to write source");
+    }
+    // Member globs are relative to the member directory
+    std::fs::create_dir_all(ffi.join("bindings")).expect("Failed to create dir");
+    std::fs::write(ffi.join("bindings/raw.rs"), "fn _x() {}\n").expect("Failed to write");
+
+    let workspace = Workspace {
+        root: root.clone(),
+        members: vec![
+            WorkspaceMember {
+                name: "app".to_string(),
+                root: root.clone(),
+            },
+            WorkspaceMember {
+                name: "ffi".to_string(),
+                root: ffi.clone(),
+            },
+        ],
+    };
+    let validate = |selection: PackageSelection| {
+        let workspace = &workspace;
+        let root = root.clone();
+        async move {
+            RustValidator::new(root)
+                .expect("Failed to create validator")
+                .without_cache()
+                .with_workspace(workspace, &selection)
+                .await
+                .expect("Failed to load workspace")
+                .validate_project()
+                .await
+                .expect("validation")
+        }
+    };
+
+    let violations = validate(PackageSelection::default()).await;
+    let unwraps: Vec<_> = violations
+        .iter()
+        .filter(|v| v.violation_type == ViolationType::UnwrapInProduction)
+        .map(|v| v.file.clone())
+        .collect();
+    assert_eq!(unwraps, vec![root.join("app.rs")]);
+    assert!(
+        !violations
+            .iter()
+            .any(|v| v.file.starts_with(ffi.join("bindings")))
+    );
+
+    let only_ffi = validate(PackageSelection {
+        packages: vec!["ffi".to_string()],
+        ..Default::default()
+    })
+    .await;
+    assert!(
+        !only_ffi
+            .iter()
+            .any(|v| v.file.starts_with(&root) && !v.file.starts_with(&ffi))
+    );
+}
+
+#[tokio::test]
+async fn test_literal_unwrap_is_reported_once() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let root = temp_dir.path();
+    std::fs::create_dir_all(root.join("src")).expect("Failed to create src");
+    write_manifest(root);
+    std::fs::write(
+        root.join("src/lib.rs"),
+        "//! Demo\n\n/// One\npub fn one() -> i32 {\n    Some(1).unwrap()\n}\n",
+    )
+    .expect("Failed to write lib.rs");
+
+    let validator = RustValidator::new(root.to_path_buf()).expect("Failed to create validator");
+    let mut violations = validator
+        .validate_project()
+        .await
+        .expect("Validation failed");
+    let clippy_result = validator.run_clippy().await.expect("Clippy failed");
+    validator.add_clippy_violations(&mut violations, &clippy_result);
+
+    let on_unwrap: Vec<_> = violations
+        .iter()
+        .filter(|v| v.file.ends_with("src/lib.rs") && v.line == 5)
+        .collect();
+    assert_eq!(on_unwrap.len(), 1, "{:#?}", on_unwrap);
+    assert_eq!(
+        on_unwrap[0].violation_type,
+        ViolationType::UnwrapInProduction
+    );
+}
@@ -4,7 +4,7 @@
 //! Supports both SemVer and CalVer version formats.
 
 use crate::config::Config;
-use crate::validation::{Severity, Violation, ViolationType};
+use crate::validation::{PathScope, Severity, Violation, ViolationType};
 use crate::{Error, Result};
 use regex::Regex;
 use std::collections::HashSet;
@@ -60,6 +60,8 @@ pub struct VersionConsistencyValidator {
     version_regex: Regex,
     /// Files/directories to exclude from checking
     exclusions: HashSet<PathBuf>,
+    /// The configuration's `exclude` globs
+    scope: PathScope,
     /// Config for validation settings
     config: Config,
     /// Changelog requirements
@@ -103,6 +105,7 @@ impl VersionConsistencyValidator {
     /// - Cargo.toml cannot be read
     /// - Version cannot be parsed from Cargo.toml
     /// - Regex compilation fails
+    /// - An `exclude` or `overrides` glob is invalid
     pub fn new(project_root: PathBuf, config: Config) -> Result<Self> {
         let source_version = Self::extract_version_from_cargo(&project_root)?;
         let version_format = Self::detect_version_format(&source_version);
@@ -150,6 +153,8 @@ impl VersionConsistencyValidator {
             }
         }
 
+        let scope = PathScope::new(&project_root, &config)?;
+
         // Get changelog requirements from config
         let changelog_requirements = ChangelogRequirements {
             enforce_keep_a_changelog: config.validation.enforce_keep_a_changelog.unwrap_or(true),
@@ -174,6 +179,7 @@ impl VersionConsistencyValidator {
             version_format,
             version_regex,
             exclusions,
+            scope,
             config,
             changelog_requirements,
         })
@@ -313,6 +319,7 @@ impl VersionConsistencyValidator {
                 violation_type: ViolationType::MissingChangelogEntry,
                 file: self.project_root.join("CHANGELOG.md"),
                 line: 1,
+                column: 0,
                 message: format!(
                     "Version {} is not documented in CHANGELOG.md. Add entry following Keep a Changelog format.",
                     self.source_version
@@ -328,6 +335,7 @@ impl VersionConsistencyValidator {
                 violation_type: ViolationType::InvalidChangelogFormat,
                 file: self.project_root.join("CHANGELOG.md"),
                 line: 1,
+                column: 0,
                 message: "CHANGELOG.md does not follow Keep a Changelog format. See https://keepachangelog.com/".to_string(),
                 severity: Severity::Warning,
             });
@@ -342,6 +350,7 @@ impl VersionConsistencyValidator {
                     violation_type: ViolationType::MissingChangelogEntry,
                     file: self.project_root.join("CHANGELOG.md"),
                     line: 1,
+                    column: 0,
                     message: format!(
                         "Cannot create tag for version {}: No changelog entry found. Document changes before tagging.",
                         self.source_version
@@ -363,6 +372,7 @@ impl VersionConsistencyValidator {
     async fn check_hardcoded_versions(&self, violations: &mut Vec<Violation>) -> Result<()> {
         let root = self.project_root.clone();
         let exclusions = self.exclusions.clone();
+        let scope = self.scope.clone();
 
         // Collect file paths in a blocking context to avoid stalling the async
         // runtime (WalkDir is synchronous and holds directory handles).
@@ -399,7 +409,7 @@ impl VersionConsistencyValidator {
             }) {
                 let Ok(e) = entry else { continue };
                 let p = e.path();
-                if exclusions.iter().any(|ex| p.starts_with(ex)) {
+                if exclusions.iter().any(|ex| p.starts_with(ex)) || scope.is_excluded(p) {
                     continue;
                 }
                 let s = p.to_string_lossy();
@@ -455,6 +465,7 @@ impl VersionConsistencyValidator {
                                 violation_type: ViolationType::HardcodedVersion,
                                 file: path.to_path_buf(),
                                 line: line_num + 1,
+                                column: line[..version_match.start()].chars().count() + 1,
                                 message: format!(
                                     "Hardcoded version '{}' found. Use env!(\"CARGO_PKG_VERSION\") or clap::crate_version!() for SSoT.",
                                     found_version
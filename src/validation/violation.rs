@@ -36,6 +36,97 @@ pub enum ViolationType {
     MissingChangelogEntry,
     /// Changelog does not follow Keep a Changelog format
     InvalidChangelogFormat,
+    /// Suppression comment without a reason, unused, or malformed
+    InvalidSuppression,
+    /// A rustc or clippy diagnostic from cargo's JSON message format
+    CompilerDiagnostic {
+        /// Lint or error code, e.g. `clippy::unwrap_used`, `E0308`, or
+        /// `rustc` when the compiler gave none
+        code: String,
+    },
+    /// A user-defined rule from `Config::custom_rules` matched
+    CustomRule {
+        /// Name of the custom rule that matched
+        name: String,
+    },
+}
+
+impl ViolationType {
+    /// Stable label used to group and display violations of this type
+    ///
+    /// Built-in types use their variant name; custom rules are labelled
+    /// `CustomRule(<name>)` so each rule is reported separately.
+    pub fn label(&self) -> String {
+        match self {
+            Self::CustomRule { name } => format!("CustomRule({})", name),
+            Self::CompilerDiagnostic { code } => format!("CompilerDiagnostic({})", code),
+            other => format!("{:?}", other),
+        }
+    }
+
+    /// Name used for this type in `// ferrous-forge: allow(...)` comments
+    ///
+    /// Built-in types use the variant name in snake case
+    /// (`unwrap_in_production`); custom rules use their configured name and
+    /// compiler diagnostics their lint or error code.
+    pub fn rule_name(&self) -> String {
+        match self {
+            Self::CustomRule { name } => name.clone(),
+            Self::CompilerDiagnostic { code } => code.clone(),
+            other => {
+                let mut name = String::new();
+                for (i, c) in format!("{:?}", other).chars().enumerate() {
+                    if c.is_ascii_uppercase() && i > 0 {
+                        name.push('_');
+                    }
+                    name.push(c.to_ascii_lowercase());
+                }
+                name
+            }
+        }
+    }
+
+    /// Compiler and clippy lints that flag the same thing as this type
+    ///
+    /// A diagnostic for one of these lints at a spot this type already
+    /// reported is a duplicate; see [`ClippyResult::violations_beyond`].
+    ///
+    /// [`ClippyResult::violations_beyond`]: crate::validation::ClippyResult::violations_beyond
+    pub fn covered_lints(&self) -> &'static [&'static str] {
+        match self {
+            Self::UnwrapInProduction => &[
+                "clippy::unwrap_used",
+                "clippy::expect_used",
+                "clippy::unnecessary_literal_unwrap",
+            ],
+            Self::MissingDocs | Self::MissingModuleDoc => &["missing_docs"],
+            _ => &[],
+        }
+    }
+
+    /// One-line description of what this violation type flags
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::UnderscoreBandaid => "Underscore-prefixed parameter or `let _ =` discard",
+            Self::WrongEdition => "Crate does not use the required Rust edition",
+            Self::FileTooLarge => "File exceeds the configured line limit",
+            Self::FunctionTooLarge => "Function exceeds the configured line limit",
+            Self::LineTooLong => "Line exceeds the configured length limit",
+            Self::UnwrapInProduction => "`.unwrap()` or `.expect()` in production code",
+            Self::MissingDocs => "Public item is missing documentation",
+            Self::MissingDependencies => "Required dependency is missing",
+            Self::OldRustVersion => "`rust-version` is older than the required minimum",
+            Self::LockedSetting => "Locked configuration value was changed",
+            Self::MissingModuleDoc => "Module is missing `//!` documentation",
+            Self::MissingDocConfig => "Cargo.toml is missing `[lints.rustdoc]` configuration",
+            Self::HardcodedVersion => "Hardcoded version string instead of `CARGO_PKG_VERSION`",
+            Self::MissingChangelogEntry => "CHANGELOG has no entry for the current version",
+            Self::InvalidChangelogFormat => "CHANGELOG does not follow Keep a Changelog",
+            Self::InvalidSuppression => "Suppression comment is unjustified, unused or malformed",
+            Self::CompilerDiagnostic { .. } => "Compiler or clippy diagnostic reported by cargo",
+            Self::CustomRule { .. } => "User-defined rule from `custom_rules`",
+        }
+    }
 }
 
 /// Severity level of a violation
@@ -43,10 +134,80 @@ pub enum ViolationType {
 pub enum Severity {
     /// Violation that prevents code from compiling
     Error,
-    /// Violation that should be fixed but doesn't break compilation
+    /// Violation that should be fixed but is reported without failing a run
+    Warning,
+    /// Violation that is reported but never fails a run
+    Info,
+}
+
+impl Severity {
+    /// Whether violations of this severity fail validation
+    ///
+    /// Only errors do; warnings and info findings are reported and leave
+    /// the exit code alone, as in `cargo` dev-command interception.
+    pub fn is_blocking(&self) -> bool {
+        matches!(self, Self::Error)
+    }
+}
+
+/// Level a rule is set to in the `[rules]` config table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+    /// Drop the rule's violations entirely
+    Off,
+    /// Report with [`Severity::Info`]
+    Info,
+    /// Report with [`Severity::Warning`]
     Warning,
+    /// Report with [`Severity::Error`]
+    Error,
 }
 
+impl RuleLevel {
+    /// The severity violations get at this level, or `None` when off
+    pub fn severity(self) -> Option<Severity> {
+        match self {
+            Self::Off => None,
+            Self::Info => Some(Severity::Info),
+            Self::Warning => Some(Severity::Warning),
+            Self::Error => Some(Severity::Error),
+        }
+    }
+}
+
+impl std::str::FromStr for RuleLevel {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        match s.to_lowercase().as_str() {
+            "off" => Ok(Self::Off),
+            "info" => Ok(Self::Info),
+            "warning" | "warn" => Ok(Self::Warning),
+            "error" => Ok(Self::Error),
+            _ => Err(crate::Error::config(format!(
+                "Invalid rule level '{}': expected off, info, warning or error",
+                s
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for RuleLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Off => "off",
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// File of violations about the toolchain rather than a project file
+pub const SYSTEM_FILE: &str = "<system>";
+
 /// A single standards violation
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Violation {
@@ -56,6 +217,9 @@ pub struct Violation {
     pub file: PathBuf,
     /// Line number (1-based for display)
     pub line: usize,
+    /// Column number (1-based; 0 when the violation applies to a whole line or file)
+    #[serde(default)]
+    pub column: usize,
     /// Human-readable message
     pub message: String,
     /// Severity of the violation
@@ -63,7 +227,12 @@ pub struct Violation {
 }
 
 impl Violation {
-    /// Create a new violation
+    /// Whether the violation is about the toolchain rather than a file
+    pub fn is_system(&self) -> bool {
+        self.file.as_os_str() == SYSTEM_FILE
+    }
+
+    /// Create a new violation without a column
     pub fn new(
         violation_type: ViolationType,
         file: PathBuf,
@@ -75,11 +244,27 @@ impl Violation {
             violation_type,
             file,
             line,
+            column: 0,
             message,
             severity,
         }
     }
 
+    /// Set the 1-based column of this violation
+    pub fn with_column(mut self, column: usize) -> Self {
+        self.column = column;
+        self
+    }
+
+    /// Location as `file:line` or `file:line:column` when the column is known
+    pub fn location(&self) -> String {
+        if self.column > 0 {
+            format!("{}:{}:{}", self.file.display(), self.line, self.column)
+        } else {
+            format!("{}:{}", self.file.display(), self.line)
+        }
+    }
+
     /// Returns true if this violation represents a locked setting (edition/version/config lock)
     pub fn is_locked_setting(&self) -> bool {
         matches!(
@@ -0,0 +1,222 @@
+//! Cargo workspace discovery for validation
+//!
+//! `cargo metadata` lists the packages of the workspace containing the
+//! project. Each violation is attributed to the member whose directory most
+//! closely contains its file, so nested members (a root package with crates
+//! below it) are told apart.
+
+use crate::{Error, Result};
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// A package of the workspace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceMember {
+    /// Package name
+    pub name: String,
+    /// Directory holding the package's `Cargo.toml`, in the same form as
+    /// the project path the workspace was loaded from
+    pub root: PathBuf,
+}
+
+/// The members of a cargo workspace
+#[derive(Debug, Clone, Default)]
+pub struct Workspace {
+    /// Workspace root directory
+    pub root: PathBuf,
+    /// Members, in `cargo metadata` order
+    pub members: Vec<WorkspaceMember>,
+}
+
+/// Which members to validate: `-p`, `--workspace` and `--exclude`
+#[derive(Debug, Clone, Default)]
+pub struct PackageSelection {
+    /// Validate only these packages (`-p`)
+    pub packages: Vec<String>,
+    /// Validate every member from the workspace root (`--workspace`)
+    pub workspace: bool,
+    /// Packages to leave out with `--workspace`
+    pub exclude: Vec<String>,
+}
+
+impl PackageSelection {
+    /// Whether the selection names packages, so validation must start from
+    /// the workspace root rather than the given path
+    pub fn is_workspace_wide(&self) -> bool {
+        self.workspace || !self.packages.is_empty()
+    }
+}
+
+/// The parts of `cargo metadata` output used here
+#[derive(Deserialize)]
+struct Metadata {
+    packages: Vec<MetadataPackage>,
+    workspace_members: Vec<String>,
+    workspace_root: PathBuf,
+}
+
+#[derive(Deserialize)]
+struct MetadataPackage {
+    id: String,
+    name: String,
+    manifest_path: PathBuf,
+}
+
+impl Workspace {
+    /// Read the workspace containing `project_path` with `cargo metadata`
+    ///
+    /// Member roots are expressed relative to `project_path` when they lie
+    /// below it, so they compare equal to the paths the validator reports.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `cargo metadata` cannot be run or fails, e.g.
+    /// because `project_path` is not inside a cargo project.
+    pub async fn load(project_path: &Path) -> Result<Self> {
+        let output = tokio::process::Command::new("cargo")
+            .args(["metadata", "--format-version", "1", "--no-deps"])
+            .current_dir(project_path)
+            .output()
+            .await
+            .map_err(|e| Error::process(format!("Failed to run cargo metadata: {}", e)))?;
+        if !output.status.success() {
+            return Err(Error::process(format!(
+                "cargo metadata failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        let metadata: Metadata = serde_json::from_slice(&output.stdout)
+            .map_err(|e| Error::parse(format!("Invalid cargo metadata output: {}", e)))?;
+        Ok(Self::from_metadata(metadata, project_path))
+    }
+
+    fn from_metadata(metadata: Metadata, project_path: &Path) -> Self {
+        let base = project_path
+            .canonicalize()
+            .unwrap_or_else(|_| project_path.to_path_buf());
+        let localize = |path: &Path| match path.strip_prefix(&base) {
+            Ok(rel) if rel.as_os_str().is_empty() => project_path.to_path_buf(),
+            Ok(rel) => project_path.join(rel),
+            Err(_) => path.to_path_buf(),
+        };
+
+        let members = metadata
+            .packages
+            .iter()
+            .filter(|p| metadata.workspace_members.contains(&p.id))
+            .filter_map(|p| {
+                Some(WorkspaceMember {
+                    name: p.name.clone(),
+                    root: localize(p.manifest_path.parent()?),
+                })
+            })
+            .collect();
+        Self {
+            root: localize(&metadata.workspace_root),
+            members,
+        }
+    }
+
+    /// Whether the workspace has more than one member
+    pub fn is_multi_member(&self) -> bool {
+        self.members.len() > 1
+    }
+
+    /// The member whose directory most closely contains `path`
+    pub fn member_for(&self, path: &Path) -> Option<&WorkspaceMember> {
+        self.members
+            .iter()
+            .filter(|m| path.starts_with(&m.root))
+            .max_by_key(|m| m.root.components().count())
+    }
+
+    /// Names of the members chosen by `selection`, or `None` for all
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `-p` or `--exclude` names a package that is not
+    /// a workspace member.
+    pub fn select(&self, selection: &PackageSelection) -> Result<Option<BTreeSet<String>>> {
+        for name in selection.packages.iter().chain(&selection.exclude) {
+            if !self.members.iter().any(|m| &m.name == name) {
+                return Err(Error::cli(format!(
+                    "Package '{}' is not a member of the workspace at {}",
+                    name,
+                    self.root.display()
+                )));
+            }
+        }
+
+        if !selection.packages.is_empty() {
+            return Ok(Some(selection.packages.iter().cloned().collect()));
+        }
+        if selection.exclude.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(
+            self.members
+                .iter()
+                .map(|m| m.name.clone())
+                .filter(|name| !selection.exclude.contains(name))
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    fn workspace() -> Workspace {
+        let metadata = serde_json::json!({
+            "packages": [
+                {"id": "app 0.1.0", "name": "app", "manifest_path": "/ws/Cargo.toml"},
+                {"id": "core 0.1.0", "name": "core", "manifest_path": "/ws/crates/core/Cargo.toml"},
+                {"id": "ffi 0.1.0", "name": "ffi", "manifest_path": "/ws/crates/ffi/Cargo.toml"},
+            ],
+            "workspace_members": ["app 0.1.0", "core 0.1.0", "ffi 0.1.0"],
+            "workspace_root": "/ws",
+        });
+        Workspace::from_metadata(serde_json::from_value(metadata).unwrap(), Path::new("/ws"))
+    }
+
+    #[test]
+    fn test_files_belong_to_the_innermost_member() {
+        let workspace = workspace();
+        let name = |path: &str| {
+            workspace
+                .member_for(Path::new(path))
+                .map(|m| m.name.as_str())
+        };
+
+        assert_eq!(name("/ws/src/main.rs"), Some("app"));
+        assert_eq!(name("/ws/crates/core/src/lib.rs"), Some("core"));
+        assert_eq!(name("/ws/crates/ffi/Cargo.toml"), Some("ffi"));
+        assert_eq!(name("/elsewhere/lib.rs"), None);
+    }
+
+    #[test]
+    fn test_package_selection() {
+        let workspace = workspace();
+        let select = |packages: &[&str], exclude: &[&str]| {
+            workspace.select(&PackageSelection {
+                packages: packages.iter().map(ToString::to_string).collect(),
+                workspace: !exclude.is_empty(),
+                exclude: exclude.iter().map(ToString::to_string).collect(),
+            })
+        };
+
+        assert_eq!(select(&[], &[]).unwrap(), None);
+        assert_eq!(
+            select(&["core"], &[]).unwrap(),
+            Some(BTreeSet::from(["core".to_string()]))
+        );
+        assert_eq!(
+            select(&[], &["ffi"]).unwrap(),
+            Some(BTreeSet::from(["app".to_string(), "core".to_string()]))
+        );
+        assert!(select(&["missing"], &[]).is_err());
+    }
+}